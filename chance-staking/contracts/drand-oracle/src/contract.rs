@@ -38,6 +38,8 @@ pub fn instantiate(
 
     let config = OracleConfig {
         admin: info.sender.clone(),
+        guardian: None,
+        pending_admin: None,
         operators,
         quicknet_pubkey: pubkey_bytes,
         chain_hash: msg.chain_hash,
@@ -61,6 +63,10 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
+    if let Some(operation) = msg.pausable_operation() {
+        execute::assert_not_paused(deps.storage, &operation)?;
+    }
+
     match msg {
         ExecuteMsg::SubmitBeacon {
             round,
@@ -69,7 +75,17 @@ pub fn execute(
         ExecuteMsg::UpdateOperators { add, remove } => {
             execute::update_operators(deps, env, info, add, remove)
         }
-        ExecuteMsg::UpdateAdmin { new_admin } => execute::update_admin(deps, env, info, new_admin),
+        ExecuteMsg::ProposeAdmin {
+            new_admin,
+            expires_in_seconds,
+        } => execute::propose_admin(deps, env, info, new_admin, expires_in_seconds),
+        ExecuteMsg::AcceptAdmin {} => execute::accept_admin(deps, env, info),
+        ExecuteMsg::CancelProposal {} => execute::cancel_proposal(deps, env, info),
+        ExecuteMsg::UpdateGuardian { guardian } => {
+            execute::update_guardian(deps, env, info, guardian)
+        }
+        ExecuteMsg::Pause { operations } => execute::pause(deps, env, info, operations),
+        ExecuteMsg::Unpause { operations } => execute::unpause(deps, env, info, operations),
     }
 }
 
@@ -79,6 +95,7 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::Config {} => query::query_config(deps),
         QueryMsg::Beacon { round } => query::query_beacon(deps, round),
         QueryMsg::LatestRound {} => query::query_latest_round(deps),
+        QueryMsg::PauseState {} => query::query_pause_state(deps),
     }
 }
 
@@ -200,6 +217,77 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_pause_submit_beacon() {
+        use crate::msg::PauseStateResponse;
+        use crate::state::PausableOperation;
+
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+
+        let admin = deps.api.addr_make("admin");
+        let guardian = deps.api.addr_make("guardian");
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            ExecuteMsg::UpdateGuardian {
+                guardian: Some(guardian.to_string()),
+            },
+        )
+        .unwrap();
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&guardian, &[]),
+            ExecuteMsg::Pause {
+                operations: vec![PausableOperation::SubmitBeacon],
+            },
+        )
+        .unwrap();
+        assert!(res
+            .events
+            .iter()
+            .any(|e| e.ty == "chance_operations_paused"));
+
+        let state: PauseStateResponse = cosmwasm_std::from_json(
+            query(deps.as_ref(), mock_env(), QueryMsg::PauseState {}).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(state.paused, vec![PausableOperation::SubmitBeacon]);
+
+        let msg = ExecuteMsg::SubmitBeacon {
+            round: 1000,
+            signature_hex: "b44679b9a59af2ec876b1a6b1ad52ea9b1615fc3982b19576350f93447cb1125e342b73a8dd2bacbe47e4b6b63ed5e39".to_string(),
+        };
+        let operator1 = deps.api.addr_make("operator1");
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&operator1, &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::OperationPaused { .. }));
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            ExecuteMsg::Unpause {
+                operations: vec![PausableOperation::SubmitBeacon],
+            },
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&operator1, &[]),
+            msg,
+        )
+        .unwrap();
+    }
+
     #[test]
     fn test_query_beacon() {
         let mut deps = mock_dependencies();
@@ -343,51 +431,83 @@ mod tests {
     }
 
     #[test]
-    fn test_update_admin() {
+    fn test_admin_handover() {
         let mut deps = mock_dependencies();
         setup_contract(deps.as_mut());
 
         let admin = deps.api.addr_make("admin");
         let new_admin = deps.api.addr_make("new_admin");
+        let propose = ExecuteMsg::ProposeAdmin {
+            new_admin: new_admin.to_string(),
+            expires_in_seconds: Some(3600),
+        };
 
-        // Non-admin cannot update admin
+        // Non-admin cannot propose
         let random = deps.api.addr_make("random");
         let info = message_info(&random, &[]);
+        let err = execute(deps.as_mut(), mock_env(), info, propose.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized { .. }));
+
+        // Admin proposes; control stays with the admin until accepted
+        let info = message_info(&admin, &[]);
+        execute(deps.as_mut(), mock_env(), info, propose.clone()).unwrap();
+        let config = CONFIG.load(deps.as_ref().storage).unwrap();
+        assert_eq!(config.admin, admin);
+        assert_eq!(config.pending_admin.unwrap().new_address, new_admin);
+
+        // Only the proposed admin can accept
+        let info = message_info(&random, &[]);
+        let err = execute(deps.as_mut(), mock_env(), info, ExecuteMsg::AcceptAdmin {}).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized { .. }));
+
+        // Expired proposals cannot be accepted
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(3600);
+        let info = message_info(&new_admin, &[]);
+        let err = execute(deps.as_mut(), env, info, ExecuteMsg::AcceptAdmin {}).unwrap_err();
+        assert!(matches!(err, ContractError::TransferExpired { .. }));
+
+        // Cancelled proposals cannot be accepted
+        let info = message_info(&admin, &[]);
+        execute(deps.as_mut(), mock_env(), info.clone(), propose.clone()).unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info.clone(),
+            ExecuteMsg::CancelProposal {},
+        )
+        .unwrap();
         let err = execute(
             deps.as_mut(),
             mock_env(),
-            info,
-            ExecuteMsg::UpdateAdmin {
-                new_admin: new_admin.to_string(),
-            },
+            message_info(&new_admin, &[]),
+            ExecuteMsg::AcceptAdmin {},
         )
         .unwrap_err();
-        assert!(matches!(err, ContractError::Unauthorized { .. }));
+        assert!(matches!(err, ContractError::NoPendingTransfer { .. }));
 
-        // Admin can update admin
-        let info = message_info(&admin, &[]);
+        // Propose again and accept
+        execute(deps.as_mut(), mock_env(), info, propose).unwrap();
         execute(
             deps.as_mut(),
             mock_env(),
-            info,
-            ExecuteMsg::UpdateAdmin {
-                new_admin: new_admin.to_string(),
-            },
+            message_info(&new_admin, &[]),
+            ExecuteMsg::AcceptAdmin {},
         )
         .unwrap();
-
         let config = CONFIG.load(deps.as_ref().storage).unwrap();
         assert_eq!(config.admin, new_admin);
+        assert!(config.pending_admin.is_none());
 
-        // Old admin can no longer update
-        let admin = deps.api.addr_make("admin");
+        // Old admin can no longer administer
         let info = message_info(&admin, &[]);
         let err = execute(
             deps.as_mut(),
             mock_env(),
             info,
-            ExecuteMsg::UpdateAdmin {
-                new_admin: admin.to_string(),
+            ExecuteMsg::UpdateOperators {
+                add: vec![],
+                remove: vec![],
             },
         )
         .unwrap_err();
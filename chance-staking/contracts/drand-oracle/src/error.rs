@@ -23,4 +23,13 @@ pub enum ContractError {
 
     #[error("invalid pubkey length: expected 96 bytes, got {got}")]
     InvalidPubkeyLength { got: usize },
+
+    #[error("{operation} is paused")]
+    OperationPaused { operation: String },
+
+    #[error("no pending {role} transfer")]
+    NoPendingTransfer { role: String },
+
+    #[error("{role} transfer proposal has expired")]
+    TransferExpired { role: String },
 }
@@ -1,7 +1,10 @@
-use cosmwasm_std::{DepsMut, Env, Event, MessageInfo, Response};
+use chance_staking_common::types::PendingTransfer;
+use cosmwasm_std::{DepsMut, Env, Event, MessageInfo, Response, Storage};
 
 use crate::error::ContractError;
-use crate::state::{StoredBeacon, BEACONS, CONFIG, LATEST_ROUND};
+use crate::state::{
+    PausableOperation, StoredBeacon, BEACONS, CONFIG, LATEST_ROUND, PAUSED_OPERATIONS,
+};
 use crate::verify::verify_quicknet_beacon;
 
 /// Submit a drand beacon. Only operators can call this.
@@ -107,26 +110,197 @@ pub fn update_operators(
         .add_attribute("added", add.join(",")))
 }
 
-/// L-01 FIX: Update admin address. Admin only.
-pub fn update_admin(
+/// Propose handing the admin role over to `new_admin`. The handover only takes
+/// effect once the new admin accepts it. Replaces any earlier proposal. Admin only.
+pub fn propose_admin(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     new_admin: String,
+    expires_in_seconds: Option<u64>,
 ) -> Result<Response, ContractError> {
     let mut config = CONFIG.load(deps.storage)?;
 
     if info.sender != config.admin {
         return Err(ContractError::Unauthorized {
-            reason: "only admin can update admin".to_string(),
+            reason: "only admin can propose a new admin".to_string(),
         });
     }
 
-    let new_admin_addr = deps.api.addr_validate(&new_admin)?;
-    config.admin = new_admin_addr.clone();
+    let pending = PendingTransfer {
+        new_address: deps.api.addr_validate(&new_admin)?,
+        expires_at: expires_in_seconds.map(|secs| env.block.time.plus_seconds(secs)),
+    };
+    config.pending_admin = Some(pending.clone());
     CONFIG.save(deps.storage, &config)?;
 
     Ok(Response::new()
-        .add_attribute("action", "update_admin")
-        .add_attribute("new_admin", new_admin_addr.to_string()))
+        .add_attribute("action", "propose_admin")
+        .add_attribute("new_admin", pending.new_address.to_string())
+        .add_attribute(
+            "expires_at",
+            pending
+                .expires_at
+                .map(|t| t.seconds())
+                .unwrap_or_default()
+                .to_string(),
+        ))
+}
+
+/// Accept a proposed admin transfer. Only the proposed admin can accept,
+/// and only before the proposal expires.
+pub fn accept_admin(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    let pending = config
+        .pending_admin
+        .take()
+        .ok_or(ContractError::NoPendingTransfer {
+            role: "Admin".to_string(),
+        })?;
+
+    if info.sender != pending.new_address {
+        return Err(ContractError::Unauthorized {
+            reason: "only the proposed admin can accept the transfer".to_string(),
+        });
+    }
+    if pending.is_expired(env.block.time) {
+        return Err(ContractError::TransferExpired {
+            role: "Admin".to_string(),
+        });
+    }
+
+    let previous = std::mem::replace(&mut config.admin, pending.new_address);
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "accept_admin")
+        .add_attribute("previous", previous.to_string())
+        .add_attribute("new_admin", info.sender.to_string()))
+}
+
+/// Withdraw a pending admin proposal. Admin only.
+pub fn cancel_proposal(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {
+            reason: "only admin can cancel an admin proposal".to_string(),
+        });
+    }
+
+    if config.pending_admin.take().is_none() {
+        return Err(ContractError::NoPendingTransfer {
+            role: "Admin".to_string(),
+        });
+    }
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attribute("action", "cancel_proposal"))
+}
+
+/// Reject `operation` while it is paused.
+pub fn assert_not_paused(
+    storage: &dyn Storage,
+    operation: &PausableOperation,
+) -> Result<(), ContractError> {
+    let paused = PAUSED_OPERATIONS.may_load(storage)?.unwrap_or_default();
+    if paused.contains(operation) {
+        return Err(ContractError::OperationPaused {
+            operation: format!("{:?}", operation),
+        });
+    }
+    Ok(())
+}
+
+/// Set or clear the guardian. Admin only.
+pub fn update_guardian(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    guardian: Option<String>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {
+            reason: "only admin can update guardian".to_string(),
+        });
+    }
+
+    config.guardian = guardian.map(|g| deps.api.addr_validate(&g)).transpose()?;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_guardian")
+        .add_attribute(
+            "guardian",
+            config.guardian.map(|g| g.to_string()).unwrap_or_default(),
+        ))
+}
+
+/// Pause operations during an incident. Guardian or admin.
+pub fn pause(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    operations: Vec<PausableOperation>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin && Some(&info.sender) != config.guardian.as_ref() {
+        return Err(ContractError::Unauthorized {
+            reason: "only guardian or admin can pause".to_string(),
+        });
+    }
+
+    let mut paused = PAUSED_OPERATIONS
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    for operation in &operations {
+        if !paused.contains(operation) {
+            paused.push(operation.clone());
+        }
+    }
+    PAUSED_OPERATIONS.save(deps.storage, &paused)?;
+
+    Ok(Response::new().add_attribute("action", "pause").add_event(
+        Event::new("chance_operations_paused")
+            .add_attribute("operations", format!("{:?}", operations))
+            .add_attribute("paused_by", info.sender.to_string()),
+    ))
+}
+
+/// Resume paused operations. Admin only.
+pub fn unpause(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    operations: Vec<PausableOperation>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {
+            reason: "only admin can unpause".to_string(),
+        });
+    }
+
+    let mut paused = PAUSED_OPERATIONS
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    paused.retain(|op| !operations.contains(op));
+    PAUSED_OPERATIONS.save(deps.storage, &paused)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "unpause")
+        .add_event(
+            Event::new("chance_operations_unpaused")
+                .add_attribute("operations", format!("{:?}", operations))
+                .add_attribute("unpaused_by", info.sender.to_string()),
+        ))
 }
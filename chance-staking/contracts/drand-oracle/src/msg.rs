@@ -1,10 +1,11 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Addr;
 
 // M-03 FIX: Add MigrateMsg for contract upgradability
 #[cw_serde]
 pub struct MigrateMsg {}
 
-use crate::state::{OracleConfig, StoredBeacon};
+use crate::state::{OracleConfig, PausableOperation, StoredBeacon};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -29,8 +30,33 @@ pub enum ExecuteMsg {
         add: Vec<String>,
         remove: Vec<String>,
     },
-    /// Update admin address (admin only).
-    UpdateAdmin { new_admin: String },
+    /// Propose a new admin (admin only). Takes effect once the new admin
+    /// sends AcceptAdmin.
+    ProposeAdmin {
+        new_admin: String,
+        /// Seconds after which the proposal can no longer be accepted
+        expires_in_seconds: Option<u64>,
+    },
+    /// Accept a pending admin proposal (proposed admin only).
+    AcceptAdmin {},
+    /// Withdraw a pending admin proposal (admin only).
+    CancelProposal {},
+    /// Set or clear the guardian (admin only).
+    UpdateGuardian { guardian: Option<String> },
+    /// Pause the given operations (guardian or admin).
+    Pause { operations: Vec<PausableOperation> },
+    /// Resume the given operations (admin only).
+    Unpause { operations: Vec<PausableOperation> },
+}
+
+impl ExecuteMsg {
+    /// The pausable operation this message belongs to, if any.
+    pub fn pausable_operation(&self) -> Option<PausableOperation> {
+        match self {
+            ExecuteMsg::SubmitBeacon { .. } => Some(PausableOperation::SubmitBeacon),
+            _ => None,
+        }
+    }
 }
 
 #[cw_serde]
@@ -44,4 +70,13 @@ pub enum QueryMsg {
 
     #[returns(u64)]
     LatestRound {},
+
+    #[returns(PauseStateResponse)]
+    PauseState {},
+}
+
+#[cw_serde]
+pub struct PauseStateResponse {
+    pub guardian: Option<Addr>,
+    pub paused: Vec<PausableOperation>,
 }
@@ -1,6 +1,7 @@
 use cosmwasm_std::{to_json_binary, Binary, Deps, StdResult};
 
-use crate::state::{BEACONS, CONFIG, LATEST_ROUND};
+use crate::msg::PauseStateResponse;
+use crate::state::{BEACONS, CONFIG, LATEST_ROUND, PAUSED_OPERATIONS};
 
 pub fn query_config(deps: Deps) -> StdResult<Binary> {
     let config = CONFIG.load(deps.storage)?;
@@ -16,3 +17,14 @@ pub fn query_latest_round(deps: Deps) -> StdResult<Binary> {
     let round = LATEST_ROUND.may_load(deps.storage)?.unwrap_or(0);
     to_json_binary(&round)
 }
+
+pub fn query_pause_state(deps: Deps) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let paused = PAUSED_OPERATIONS
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    to_json_binary(&PauseStateResponse {
+        guardian: config.guardian,
+        paused,
+    })
+}
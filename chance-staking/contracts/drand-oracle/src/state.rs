@@ -1,3 +1,4 @@
+use chance_staking_common::types::PendingTransfer;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Timestamp};
 use cw_storage_plus::{Item, Map};
@@ -5,10 +6,16 @@ use cw_storage_plus::{Item, Map};
 pub const CONFIG: Item<OracleConfig> = Item::new("config");
 pub const BEACONS: Map<u64, StoredBeacon> = Map::new("beacons");
 pub const LATEST_ROUND: Item<u64> = Item::new("latest_round");
+/// Operations currently halted by the guardian or admin.
+pub const PAUSED_OPERATIONS: Item<Vec<PausableOperation>> = Item::new("paused_operations");
 
 #[cw_serde]
 pub struct OracleConfig {
     pub admin: Addr,
+    /// Can pause operations during an incident. Only the admin can unpause.
+    pub guardian: Option<Addr>,
+    /// Proposed admin, waiting for AcceptAdmin
+    pub pending_admin: Option<PendingTransfer>,
     pub operators: Vec<Addr>,
     /// Quicknet public key, 96 bytes (G2 point)
     pub quicknet_pubkey: Vec<u8>,
@@ -20,6 +27,12 @@ pub struct OracleConfig {
     pub period_seconds: u64,
 }
 
+/// Operations the guardian can pause.
+#[cw_serde]
+pub enum PausableOperation {
+    SubmitBeacon,
+}
+
 #[cw_serde]
 pub struct StoredBeacon {
     pub round: u64,
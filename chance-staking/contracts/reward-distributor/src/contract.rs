@@ -1,5 +1,6 @@
+use chance_staking_common::types::Role;
 use cosmwasm_std::{
-    entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Uint128,
+    entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdResult, Uint128,
 };
 use cw2::{get_contract_version, set_contract_version};
 
@@ -27,14 +28,30 @@ pub fn instantiate(
     // V2-M-03 FIX: Validate reveal deadline bounds at instantiation
     execute::validate_reveal_deadline(msg.reveal_deadline_seconds)?;
 
+    let regular_prize_tiers = msg
+        .regular_prize_tiers
+        .unwrap_or_else(execute::single_prize_tier);
+    let big_prize_tiers = msg
+        .big_prize_tiers
+        .unwrap_or_else(execute::single_prize_tier);
+    execute::validate_prize_tiers(&regular_prize_tiers)?;
+    execute::validate_prize_tiers(&big_prize_tiers)?;
+
     let config = DistributorConfig {
         admin: info.sender.clone(),
         operator: deps.api.addr_validate(&msg.operator)?,
+        guardian: None,
+        pending_admin: None,
+        pending_operator: None,
         staking_hub: deps.api.addr_validate(&msg.staking_hub)?,
         drand_oracle: deps.api.addr_validate(&msg.drand_oracle)?,
         reveal_deadline_seconds: msg.reveal_deadline_seconds,
         epochs_between_regular: msg.epochs_between_regular,
         epochs_between_big: msg.epochs_between_big,
+        challenge_period_seconds: msg.challenge_period_seconds.unwrap_or(0),
+        timelock_seconds: msg.timelock_seconds.unwrap_or(0),
+        regular_prize_tiers,
+        big_prize_tiers,
     };
     CONFIG.save(deps.storage, &config)?;
 
@@ -62,6 +79,10 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
+    if let Some(operation) = msg.pausable_operation() {
+        execute::assert_not_paused(deps.storage, &operation)?;
+    }
+
     match msg {
         ExecuteMsg::FundRegularPool {} => execute::fund_regular_pool(deps, env, info),
         ExecuteMsg::FundBigPool {} => execute::fund_big_pool(deps, env, info),
@@ -98,10 +119,7 @@ pub fn execute(
         ExecuteMsg::RevealDraw {
             draw_id,
             operator_secret_hex,
-            winner_address,
-            winner_cumulative_start,
-            winner_cumulative_end,
-            merkle_proof,
+            winners,
         } => execute::reveal_draw(
             deps,
             env,
@@ -109,31 +127,78 @@ pub fn execute(
             RevealDrawParams {
                 draw_id,
                 operator_secret_hex,
-                winner_address,
-                winner_cumulative_start,
-                winner_cumulative_end,
-                merkle_proof,
+                winners,
             },
         ),
         ExecuteMsg::ExpireDraw { draw_id } => execute::expire_draw(deps, env, info, draw_id),
+        ExecuteMsg::AddBonusPrize {
+            draw_type,
+            target_epoch,
+        } => execute::add_bonus_prize(deps, env, info, draw_type, target_epoch),
+        ExecuteMsg::ClaimBonusPrize { denom } => execute::claim_bonus_prize(deps, env, info, denom),
+        ExecuteMsg::UpdateBonusDenoms { add, remove } => {
+            execute::update_bonus_denoms(deps, env, info, add, remove)
+        }
+        ExecuteMsg::ChallengeSnapshot { epoch, challenge } => {
+            execute::challenge_snapshot(deps, env, info, epoch, challenge)
+        }
+        ExecuteMsg::AnswerDispute { epoch, leaf } => {
+            execute::answer_dispute(deps, env, info, epoch, leaf)
+        }
+        ExecuteMsg::DismissDispute { epoch, disputer } => {
+            execute::dismiss_dispute(deps, env, info, epoch, disputer)
+        }
+        ExecuteMsg::InvalidateSnapshot { epoch } => {
+            execute::invalidate_snapshot(deps, env, info, epoch)
+        }
         ExecuteMsg::UpdateConfig {
-            operator,
             staking_hub,
             reveal_deadline_seconds,
             epochs_between_regular,
             epochs_between_big,
+            challenge_period_seconds,
+            regular_prize_tiers,
+            big_prize_tiers,
         } => execute::update_config(
             deps,
             env,
             info,
             UpdateConfigParams {
-                operator,
                 staking_hub,
                 reveal_deadline_seconds,
                 epochs_between_regular,
                 epochs_between_big,
+                challenge_period_seconds,
+                regular_prize_tiers,
+                big_prize_tiers,
             },
         ),
+        ExecuteMsg::ProposeAdmin {
+            new_admin,
+            expires_in_seconds,
+        } => execute::propose_transfer(deps, env, info, Role::Admin, new_admin, expires_in_seconds),
+        ExecuteMsg::AcceptAdmin {} => execute::accept_transfer(deps, env, info, Role::Admin),
+        ExecuteMsg::ProposeOperator {
+            new_operator,
+            expires_in_seconds,
+        } => execute::propose_transfer(
+            deps,
+            env,
+            info,
+            Role::Operator,
+            new_operator,
+            expires_in_seconds,
+        ),
+        ExecuteMsg::AcceptOperator {} => execute::accept_transfer(deps, env, info, Role::Operator),
+        ExecuteMsg::CancelProposal { role } => execute::cancel_proposal(deps, env, info, role),
+        ExecuteMsg::ScheduleChange { change } => execute::schedule_change(deps, env, info, change),
+        ExecuteMsg::ExecuteChange { id } => execute::execute_change(deps, env, info, id),
+        ExecuteMsg::CancelChange { id } => execute::cancel_change(deps, env, info, id),
+        ExecuteMsg::UpdateGuardian { guardian } => {
+            execute::update_guardian(deps, env, info, guardian)
+        }
+        ExecuteMsg::Pause { operations } => execute::pause(deps, env, info, operations),
+        ExecuteMsg::Unpause { operations } => execute::unpause(deps, env, info, operations),
     }
 }
 
@@ -147,6 +212,15 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             query::query_draw_history(deps, start_after, limit)
         }
         QueryMsg::PoolBalances {} => query::query_pool_balances(deps),
+        QueryMsg::BonusPrizes {
+            draw_type,
+            start_after,
+            limit,
+        } => query::query_bonus_prizes(deps, draw_type, start_after, limit),
+        QueryMsg::BonusDenoms {} => query::query_bonus_denoms(deps),
+        QueryMsg::UnclaimedBonusPrizes { address } => {
+            query::query_unclaimed_bonus_prizes(deps, address)
+        }
         QueryMsg::UserWins {
             address,
             start_after,
@@ -172,6 +246,24 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             cumulative_end,
         ),
         QueryMsg::Snapshot { epoch } => query::query_snapshot(deps, epoch),
+        QueryMsg::PauseState {} => query::query_pause_state(deps),
+        QueryMsg::PendingChanges { start_after, limit } => {
+            query::query_pending_changes(deps, start_after, limit)
+        }
+        QueryMsg::SnapshotDisputes {
+            epoch,
+            start_after,
+            limit,
+        } => query::query_snapshot_disputes(deps, epoch, start_after, limit),
+        QueryMsg::ExpectedValue { address } => query::query_expected_value(deps, address),
+    }
+}
+
+#[entry_point]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        execute::BONUS_PAYOUT_REPLY_ID => execute::bonus_payout_reply(deps, msg),
+        id => Err(ContractError::UnknownReplyId { id }),
     }
 }
 
@@ -187,6 +279,11 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, C
 
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
+    // Rewrite the config with the defaults of fields added since it was stored.
+    // Snapshots and draws pick up their defaults when next loaded.
+    let config = CONFIG.load(deps.storage)?;
+    CONFIG.save(deps.storage, &config)?;
+
     Ok(Response::new()
         .add_attribute("action", "migrate")
         .add_attribute("from_version", stored.version)
@@ -212,6 +309,10 @@ mod tests {
             reveal_deadline_seconds: 3600,
             epochs_between_regular: 1,
             epochs_between_big: 7,
+            challenge_period_seconds: None,
+            regular_prize_tiers: None,
+            big_prize_tiers: None,
+            timelock_seconds: None,
         }
     }
 
@@ -326,6 +427,108 @@ mod tests {
         assert!(matches!(err, ContractError::NoSnapshot));
     }
 
+    #[test]
+    fn test_pause_commit_draw() {
+        use crate::state::PausableOperation;
+
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+
+        let admin = deps.api.addr_make("admin");
+        let guardian = deps.api.addr_make("guardian");
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            ExecuteMsg::UpdateGuardian {
+                guardian: Some(guardian.to_string()),
+            },
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&guardian, &[]),
+            ExecuteMsg::Pause {
+                operations: vec![PausableOperation::CommitDraw],
+            },
+        )
+        .unwrap();
+
+        // Funding stays available while draws are paused
+        let staking_hub = deps.api.addr_make("staking_hub");
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&staking_hub, &coins(50_000_000, "inj")),
+            ExecuteMsg::FundRegularPool {},
+        )
+        .unwrap();
+
+        let commit: [u8; 32] = Sha256::digest(b"my_secret").into();
+        let commit_msg = ExecuteMsg::CommitDraw {
+            draw_type: DrawType::Regular,
+            operator_commit: hex::encode(commit),
+            target_drand_round: 1000,
+            epoch: 1,
+        };
+        let operator = deps.api.addr_make("operator");
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&operator, &[]),
+            commit_msg.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::OperationPaused { .. }));
+
+        // Bonus prizes feed the next commit and are paused with it
+        let sponsor = deps.api.addr_make("sponsor");
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&sponsor, &coins(100, "uusdt")),
+            ExecuteMsg::AddBonusPrize {
+                draw_type: DrawType::Regular,
+                target_epoch: None,
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::OperationPaused { .. }));
+
+        // Snapshots can still be challenged while draws are paused
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&sponsor, &[]),
+            ExecuteMsg::ChallengeSnapshot {
+                epoch: 1,
+                challenge: crate::msg::SnapshotChallenge::MissingLeaf {},
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NoSnapshot));
+
+        // Once unpaused the commit reaches the usual checks again
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            ExecuteMsg::Unpause {
+                operations: vec![PausableOperation::CommitDraw],
+            },
+        )
+        .unwrap();
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&operator, &[]),
+            commit_msg,
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NoSnapshot));
+    }
+
     #[test]
     fn test_commit_draw() {
         let mut deps = mock_dependencies();
@@ -389,6 +592,89 @@ mod tests {
         assert_eq!(state.next_draw_id, 1);
     }
 
+    #[test]
+    fn test_migrate_defaults() {
+        use crate::execute::single_prize_tier;
+        use crate::state::{CONFIG, SNAPSHOTS};
+        use cosmwasm_std::Storage;
+
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+
+        let staking_hub = deps.api.addr_make("staking_hub");
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&staking_hub, &coins(50_000_000, "inj")),
+            ExecuteMsg::FundRegularPool {},
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&staking_hub, &[]),
+            ExecuteMsg::SetSnapshot {
+                epoch: 1,
+                merkle_root: "abcd1234".to_string(),
+                total_weight: Uint128::from(1000u128),
+                num_holders: 3,
+            },
+        )
+        .unwrap();
+        let commit: [u8; 32] = Sha256::digest(b"my_secret").into();
+        let operator = deps.api.addr_make("operator");
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&operator, &[]),
+            ExecuteMsg::CommitDraw {
+                draw_type: DrawType::Regular,
+                operator_commit: hex::encode(commit),
+                target_drand_round: 1000,
+                epoch: 1,
+            },
+        )
+        .unwrap();
+
+        // Store config, snapshot and draw as the first deployment did
+        let mut strip = |key: &[u8], fields: &[&str]| {
+            let mut stored: serde_json::Value =
+                serde_json::from_slice(&deps.storage.get(key).unwrap()).unwrap();
+            for field in fields {
+                stored.as_object_mut().unwrap().remove(*field);
+            }
+            deps.storage.set(key, &serde_json::to_vec(&stored).unwrap());
+        };
+        strip(
+            b"config",
+            &[
+                "guardian",
+                "pending_admin",
+                "pending_operator",
+                "challenge_period_seconds",
+                "timelock_seconds",
+                "regular_prize_tiers",
+                "big_prize_tiers",
+            ],
+        );
+        strip(&SNAPSHOTS.key(1), &["challenge_deadline", "invalidated"]);
+        strip(&DRAWS.key(0), &["bonus_prizes", "prize_tiers", "winners"]);
+
+        migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+
+        let config = CONFIG.load(deps.as_ref().storage).unwrap();
+        assert_eq!(config.challenge_period_seconds, 0);
+        assert_eq!(config.timelock_seconds, 0);
+        assert_eq!(config.regular_prize_tiers, single_prize_tier());
+        assert_eq!(config.big_prize_tiers, single_prize_tier());
+        let snapshot = SNAPSHOTS.load(deps.as_ref().storage, 1).unwrap();
+        assert!(!snapshot.invalidated);
+        let draw = DRAWS.load(deps.as_ref().storage, 0).unwrap();
+        assert!(draw.bonus_prizes.is_empty());
+        assert_eq!(draw.prize_tiers, single_prize_tier());
+        assert!(draw.winners.is_empty());
+    }
+
     #[test]
     fn test_commit_draw_empty_pool() {
         let mut deps = mock_dependencies();
@@ -567,10 +853,12 @@ mod tests {
             ExecuteMsg::RevealDraw {
                 draw_id: 0,
                 operator_secret_hex: hex::encode(b"wrong_secret"),
-                winner_address: "inj1winner".to_string(),
-                winner_cumulative_start: Uint128::zero(),
-                winner_cumulative_end: Uint128::from(100u128),
-                merkle_proof: vec![],
+                winners: vec![crate::msg::SnapshotLeaf {
+                    address: "inj1winner".to_string(),
+                    cumulative_start: Uint128::zero(),
+                    cumulative_end: Uint128::from(100u128),
+                    merkle_proof: vec![],
+                }],
             },
         )
         .unwrap_err();
@@ -636,10 +924,12 @@ mod tests {
             ExecuteMsg::RevealDraw {
                 draw_id: 0,
                 operator_secret_hex: hex::encode(secret),
-                winner_address: "inj1winner".to_string(),
-                winner_cumulative_start: Uint128::zero(),
-                winner_cumulative_end: Uint128::from(100u128),
-                merkle_proof: vec![],
+                winners: vec![crate::msg::SnapshotLeaf {
+                    address: "inj1winner".to_string(),
+                    cumulative_start: Uint128::zero(),
+                    cumulative_end: Uint128::from(100u128),
+                    merkle_proof: vec![],
+                }],
             },
         )
         .unwrap_err();
@@ -654,7 +944,6 @@ mod tests {
         setup_contract(deps.as_mut());
 
         let admin = deps.api.addr_make("admin");
-        let new_operator = deps.api.addr_make("new_operator");
         let new_hub = deps.api.addr_make("new_hub");
 
         // Non-admin cannot update config
@@ -665,38 +954,198 @@ mod tests {
             mock_env(),
             info,
             ExecuteMsg::UpdateConfig {
-                operator: Some(new_operator.to_string()),
-                staking_hub: None,
+                staking_hub: Some(new_hub.to_string()),
                 reveal_deadline_seconds: None,
                 epochs_between_regular: None,
                 epochs_between_big: None,
+                challenge_period_seconds: None,
+                regular_prize_tiers: None,
+                big_prize_tiers: None,
             },
         )
         .unwrap_err();
         assert!(matches!(err, ContractError::Unauthorized { .. }));
 
-        // Admin can update operator and staking_hub
+        // Admin can update staking_hub and the draw parameters
         let info = message_info(&admin, &[]);
         execute(
             deps.as_mut(),
             mock_env(),
             info,
             ExecuteMsg::UpdateConfig {
-                operator: Some(new_operator.to_string()),
                 staking_hub: Some(new_hub.to_string()),
                 reveal_deadline_seconds: Some(7200),
                 epochs_between_regular: Some(2),
                 epochs_between_big: Some(14),
+                challenge_period_seconds: None,
+                regular_prize_tiers: None,
+                big_prize_tiers: None,
             },
         )
         .unwrap();
 
         let config = CONFIG.load(deps.as_ref().storage).unwrap();
-        assert_eq!(config.operator, new_operator);
         assert_eq!(config.staking_hub, new_hub);
         assert_eq!(config.reveal_deadline_seconds, 7200);
         assert_eq!(config.epochs_between_regular, 2);
         assert_eq!(config.epochs_between_big, 14);
+
+        // An operator sent along with UpdateConfig is ignored: only the
+        // ProposeOperator/AcceptOperator handover changes it
+        let new_operator = deps.api.addr_make("new_operator");
+        let msg: ExecuteMsg = cosmwasm_std::from_json(format!(
+            r#"{{"update_config":{{"operator":"{}","staking_hub":null,"reveal_deadline_seconds":null,"epochs_between_regular":null,"epochs_between_big":null,"challenge_period_seconds":null,"regular_prize_tiers":null,"big_prize_tiers":null}}}}"#,
+            new_operator
+        ))
+        .unwrap();
+        execute(deps.as_mut(), mock_env(), message_info(&admin, &[]), msg).unwrap();
+        let config = CONFIG.load(deps.as_ref().storage).unwrap();
+        assert_eq!(config.operator, deps.api.addr_make("operator"));
+    }
+
+    #[test]
+    fn test_timelocked_param_change() {
+        use crate::msg::ParamChange;
+
+        let mut deps = mock_dependencies();
+        let admin = deps.api.addr_make("admin");
+        let mut msg = default_instantiate_msg();
+        msg.timelock_seconds = Some(3600);
+        instantiate(deps.as_mut(), mock_env(), message_info(&admin, &[]), msg).unwrap();
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            ExecuteMsg::UpdateConfig {
+                staking_hub: None,
+                reveal_deadline_seconds: None,
+                epochs_between_regular: None,
+                epochs_between_big: Some(14),
+                challenge_period_seconds: None,
+                regular_prize_tiers: None,
+                big_prize_tiers: None,
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::TimelockRequired));
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            ExecuteMsg::ScheduleChange {
+                change: ParamChange::UpdateConfig {
+                    reveal_deadline_seconds: None,
+                    epochs_between_regular: None,
+                    epochs_between_big: Some(14),
+                    challenge_period_seconds: None,
+                    regular_prize_tiers: None,
+                    big_prize_tiers: None,
+                },
+            },
+        )
+        .unwrap();
+
+        let anyone = deps.api.addr_make("anyone");
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&anyone, &[]),
+            ExecuteMsg::ExecuteChange { id: 0 },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::ChangeNotReady { id: 0, .. }));
+
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(3600);
+        execute(
+            deps.as_mut(),
+            env,
+            message_info(&anyone, &[]),
+            ExecuteMsg::ExecuteChange { id: 0 },
+        )
+        .unwrap();
+
+        let config = CONFIG.load(deps.as_ref().storage).unwrap();
+        assert_eq!(config.epochs_between_big, 14);
+    }
+
+    #[test]
+    fn test_admin_handover() {
+        use chance_staking_common::types::Role;
+
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+
+        let admin = deps.api.addr_make("admin");
+        let new_admin = deps.api.addr_make("new_admin");
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            ExecuteMsg::ProposeAdmin {
+                new_admin: new_admin.to_string(),
+                expires_in_seconds: Some(600),
+            },
+        )
+        .unwrap();
+        let config = CONFIG.load(deps.as_ref().storage).unwrap();
+        let pending = config.pending_admin.unwrap();
+        assert_eq!(pending.new_address, new_admin);
+        assert_eq!(
+            pending.expires_at,
+            Some(mock_env().block.time.plus_seconds(600))
+        );
+
+        // The admin can withdraw the proposal
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            ExecuteMsg::CancelProposal { role: Role::Admin },
+        )
+        .unwrap();
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&new_admin, &[]),
+            ExecuteMsg::AcceptAdmin {},
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NoPendingTransfer { .. }));
+
+        // Propose again and accept
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            ExecuteMsg::ProposeAdmin {
+                new_admin: new_admin.to_string(),
+                expires_in_seconds: None,
+            },
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&new_admin, &[]),
+            ExecuteMsg::AcceptAdmin {},
+        )
+        .unwrap();
+        let config = CONFIG.load(deps.as_ref().storage).unwrap();
+        assert_eq!(config.admin, new_admin);
+
+        // The old admin is locked out
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            ExecuteMsg::UpdateGuardian { guardian: None },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized { .. }));
     }
 
     #[test]
@@ -714,6 +1163,10 @@ mod tests {
             reveal_deadline_seconds: 10, // Too low (min 300)
             epochs_between_regular: 1,
             epochs_between_big: 7,
+            challenge_period_seconds: None,
+            regular_prize_tiers: None,
+            big_prize_tiers: None,
+            timelock_seconds: None,
         };
         let info = message_info(&admin, &[]);
         let err = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap_err();
@@ -731,6 +1184,10 @@ mod tests {
             reveal_deadline_seconds: 100_000, // Too high (max 86400)
             epochs_between_regular: 1,
             epochs_between_big: 7,
+            challenge_period_seconds: None,
+            regular_prize_tiers: None,
+            big_prize_tiers: None,
+            timelock_seconds: None,
         };
         let admin = mock_api.addr_make("admin");
         let info = message_info(&admin, &[]);
@@ -752,11 +1209,13 @@ mod tests {
             mock_env(),
             info,
             ExecuteMsg::UpdateConfig {
-                operator: None,
                 staking_hub: None,
                 reveal_deadline_seconds: Some(0),
                 epochs_between_regular: None,
                 epochs_between_big: None,
+                challenge_period_seconds: None,
+                regular_prize_tiers: None,
+                big_prize_tiers: None,
             },
         )
         .unwrap_err();
@@ -766,4 +1225,239 @@ mod tests {
             err
         );
     }
+
+    #[test]
+    fn test_bonus_prize() {
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+
+        let sponsor = deps.api.addr_make("sponsor");
+        let bonus = vec![
+            cosmwasm_std::Coin::new(500u128, "uatom"),
+            cosmwasm_std::Coin::new(1_000u128, "peggy0xusdt"),
+        ];
+
+        // Only the admin sets the accepted denoms and their minimums
+        let update_denoms = ExecuteMsg::UpdateBonusDenoms {
+            add: vec![
+                cosmwasm_std::Coin::new(100u128, "uatom"),
+                cosmwasm_std::Coin::new(1_000u128, "peggy0xusdt"),
+            ],
+            remove: vec![],
+        };
+        let info = message_info(&sponsor, &[]);
+        let err = execute(deps.as_mut(), mock_env(), info, update_denoms.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized { .. }));
+        let admin = deps.api.addr_make("admin");
+        let info = message_info(&admin, &[]);
+        execute(deps.as_mut(), mock_env(), info, update_denoms).unwrap();
+        let denoms: Vec<cosmwasm_std::Coin> = cosmwasm_std::from_json(
+            query(deps.as_ref(), mock_env(), QueryMsg::BonusDenoms {}).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(denoms.len(), 2);
+
+        // Denoms off the list and amounts below the minimum are rejected
+        for funds in [coins(1_000, "ufake"), coins(999, "peggy0xusdt")] {
+            let info = message_info(&sponsor, &funds);
+            let err = execute(
+                deps.as_mut(),
+                mock_env(),
+                info,
+                ExecuteMsg::AddBonusPrize {
+                    draw_type: DrawType::Regular,
+                    target_epoch: Some(1),
+                },
+            )
+            .unwrap_err();
+            assert!(matches!(err, ContractError::InvalidBonusPrize { .. }));
+        }
+
+        // INJ, empty funds and far-future epochs are rejected
+        let info = message_info(&sponsor, &coins(1_000, "inj"));
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::AddBonusPrize {
+                draw_type: DrawType::Regular,
+                target_epoch: Some(1),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidBonusPrize { .. }));
+
+        let info = message_info(&sponsor, &[]);
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::AddBonusPrize {
+                draw_type: DrawType::Regular,
+                target_epoch: Some(1),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NoFundsSent));
+
+        let info = message_info(&sponsor, &bonus);
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::AddBonusPrize {
+                draw_type: DrawType::Regular,
+                target_epoch: Some(100),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidBonusPrize { .. }));
+
+        // Escrow for epoch 1, twice to exercise merging
+        for _ in 0..2 {
+            let info = message_info(&sponsor, &bonus);
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                info,
+                ExecuteMsg::AddBonusPrize {
+                    draw_type: DrawType::Regular,
+                    target_epoch: Some(1),
+                },
+            )
+            .unwrap();
+        }
+        let expected = vec![
+            cosmwasm_std::Coin::new(2_000u128, "peggy0xusdt"),
+            cosmwasm_std::Coin::new(1_000u128, "uatom"),
+        ];
+        let balances: crate::msg::PoolBalancesResponse = cosmwasm_std::from_json(
+            query(deps.as_ref(), mock_env(), QueryMsg::PoolBalances {}).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(balances.regular_bonus, expected);
+        assert!(balances.big_bonus.is_empty());
+
+        // Fund, snapshot and commit: the bonus is attached to the draw
+        let staking_hub = deps.api.addr_make("staking_hub");
+        let info = message_info(&staking_hub, &coins(50_000_000, "inj"));
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::FundRegularPool {},
+        )
+        .unwrap();
+        let info = message_info(&staking_hub, &[]);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::SetSnapshot {
+                epoch: 1,
+                merkle_root: "abcd1234".to_string(),
+                total_weight: Uint128::from(1000u128),
+                num_holders: 3,
+            },
+        )
+        .unwrap();
+
+        let commit: [u8; 32] = Sha256::digest(b"my_secret").into();
+        let operator = deps.api.addr_make("operator");
+        let info = message_info(&operator, &[]);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::CommitDraw {
+                draw_type: DrawType::Regular,
+                operator_commit: hex::encode(commit),
+                target_drand_round: 1000,
+                epoch: 1,
+            },
+        )
+        .unwrap();
+
+        let draw = DRAWS.load(deps.as_ref().storage, 0).unwrap();
+        assert_eq!(draw.bonus_prizes, expected);
+        assert!(crate::state::BONUS_PRIZES
+            .may_load(deps.as_ref().storage, ("regular", 1))
+            .unwrap()
+            .is_none());
+
+        // Expiry rolls the bonus over to the pool's next draw
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(env.block.time.seconds() + 7200);
+        let anyone = deps.api.addr_make("anyone");
+        let info = message_info(&anyone, &[]);
+        execute(
+            deps.as_mut(),
+            env,
+            info,
+            ExecuteMsg::ExpireDraw { draw_id: 0 },
+        )
+        .unwrap();
+
+        let balances: crate::msg::PoolBalancesResponse = cosmwasm_std::from_json(
+            query(deps.as_ref(), mock_env(), QueryMsg::PoolBalances {}).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(balances.regular_bonus, expected);
+
+        // A bonus payout that fails at reveal is kept for the winner, not rolled over
+        let winner = deps.api.addr_make("winner");
+        let payout = crate::state::BonusPayout {
+            draw_id: 0,
+            winner: winner.clone(),
+            coin: cosmwasm_std::Coin::new(1_000u128, "uatom"),
+        };
+        #[allow(deprecated)]
+        let failed_payout = cosmwasm_std::Reply {
+            id: crate::execute::BONUS_PAYOUT_REPLY_ID,
+            payload: cosmwasm_std::to_json_binary(&payout).unwrap(),
+            gas_used: 0,
+            result: cosmwasm_std::SubMsgResult::Err("send blocked".to_string()),
+        };
+        let res = reply(deps.as_mut(), mock_env(), failed_payout).unwrap();
+        assert!(res
+            .events
+            .iter()
+            .any(|e| e.ty == "chance_bonus_prize_unpaid"));
+        let balances: crate::msg::PoolBalancesResponse = cosmwasm_std::from_json(
+            query(deps.as_ref(), mock_env(), QueryMsg::PoolBalances {}).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(balances.regular_bonus, expected);
+        let unclaimed: Vec<cosmwasm_std::Coin> = cosmwasm_std::from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::UnclaimedBonusPrizes {
+                    address: winner.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(unclaimed, vec![payout.coin.clone()]);
+
+        let claim = ExecuteMsg::ClaimBonusPrize {
+            denom: "uatom".to_string(),
+        };
+        let info = message_info(&sponsor, &[]);
+        let err = execute(deps.as_mut(), mock_env(), info, claim.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::NoUnclaimedBonusPrize { .. }));
+        let info = message_info(&winner, &[]);
+        let res = execute(deps.as_mut(), mock_env(), info, claim.clone()).unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            cosmwasm_std::CosmosMsg::Bank(cosmwasm_std::BankMsg::Send {
+                to_address: winner.to_string(),
+                amount: vec![payout.coin],
+            })
+        );
+        let info = message_info(&winner, &[]);
+        let err = execute(deps.as_mut(), mock_env(), info, claim).unwrap_err();
+        assert!(matches!(err, ContractError::NoUnclaimedBonusPrize { .. }));
+    }
 }
@@ -87,4 +87,65 @@ pub enum ContractError {
     // V2-M-03 FIX: Invalid reveal deadline bounds
     #[error("reveal_deadline_seconds must be between {min} and {max}, got {value}")]
     InvalidRevealDeadline { value: u64, min: u64, max: u64 },
+
+    #[error("{operation} is paused")]
+    OperationPaused { operation: String },
+
+    #[error("no pending {role} transfer")]
+    NoPendingTransfer { role: String },
+
+    #[error("{role} transfer proposal has expired")]
+    TransferExpired { role: String },
+
+    #[error("a timelock is set: schedule this change with ScheduleChange")]
+    TimelockRequired,
+
+    #[error("scheduled change {id} not found")]
+    ChangeNotFound { id: u64 },
+
+    #[error("scheduled change {id} is not executable until {executable_at}")]
+    ChangeNotReady { id: u64, executable_at: u64 },
+
+    #[error("snapshot for epoch {epoch} was invalidated by a fraud proof")]
+    SnapshotInvalidated { epoch: u64 },
+
+    #[error("snapshot for epoch {epoch} can be challenged until {ends_at}")]
+    ChallengeWindowOpen { epoch: u64, ends_at: u64 },
+
+    #[error("challenge window for snapshot {epoch} has closed")]
+    ChallengeWindowClosed { epoch: u64 },
+
+    #[error("snapshot for epoch {epoch} has unanswered disputes")]
+    SnapshotDisputed { epoch: u64 },
+
+    #[error("challenge rejected: {reason}")]
+    ChallengeRejected { reason: String },
+
+    #[error("winner {address} is excluded from draws")]
+    WinnerExcluded { address: String },
+
+    #[error("no open dispute from {disputer} on snapshot {epoch}")]
+    DisputeNotFound { epoch: u64, disputer: String },
+
+    #[error("invalid bonus prize: {reason}")]
+    InvalidBonusPrize { reason: String },
+
+    #[error("no unclaimed {denom} bonus prize for {address}")]
+    NoUnclaimedBonusPrize { address: String, denom: String },
+
+    #[error("unknown reply id: {id}")]
+    UnknownReplyId { id: u64 },
+
+    #[error("invalid prize tiers: {reason}")]
+    InvalidPrizeTiers { reason: String },
+
+    #[error("draw {draw_id} has {expected} prizes, got {provided} winners")]
+    WinnerCountMismatch {
+        draw_id: u64,
+        expected: u32,
+        provided: u32,
+    },
+
+    #[error("{unused} winners left over after drawing every prize of draw {draw_id}")]
+    UnusedWinners { draw_id: u64, unused: u32 },
 }
@@ -1,25 +1,49 @@
 use chance_staking_common::merkle::{compute_leaf_hash, verify_merkle_proof};
-use chance_staking_common::types::{DrawStatus, DrawType};
+use chance_staking_common::types::{DrawStatus, DrawType, PendingTransfer, Role};
 use cosmwasm_std::{
-    coins, to_json_binary, BankMsg, DepsMut, Env, Event, MessageInfo, QueryRequest, Response,
-    Timestamp, Uint128, WasmQuery,
+    coins, from_json, to_json_binary, to_json_string, Addr, BankMsg, Coin, DepsMut, Env, Event,
+    MessageInfo, Order, QuerierWrapper, QueryRequest, Reply, Response, StdResult, Storage, SubMsg,
+    SubMsgResult, Timestamp, Uint128, WasmQuery,
 };
+use cw_storage_plus::Bound;
 use sha2::{Digest, Sha256};
 
 use crate::error::ContractError;
 use crate::msg::{
-    CommitDrawParams, OracleQueryMsg, RevealDrawParams, StakerInfoResponse,
-    StakingHubConfigResponse, StakingHubQueryMsg, UpdateConfigParams,
+    CommitDrawParams, OracleQueryMsg, ParamChange, RevealDrawParams, SnapshotChallenge,
+    SnapshotLeaf, StakerInfoResponse, StakingHubConfigResponse, StakingHubQueryMsg, TwabResponse,
+    UpdateConfigParams,
 };
 use crate::state::{
-    Draw, Snapshot, CONFIG, DRAWS, DRAW_STATE, LATEST_SNAPSHOT_EPOCH, SNAPSHOTS, USER_TOTAL_WON,
-    USER_WINS, USER_WIN_COUNT,
+    BonusPayout, DistributorConfig, Draw, PausableOperation, PendingChange, PrizeTier, PrizeWinner,
+    Snapshot, SnapshotDispute, BONUS_DENOMS, BONUS_PRIZES, CONFIG, DRAWS, DRAW_STATE,
+    LATEST_SNAPSHOT_EPOCH, NEXT_CHANGE_ID, PAUSED_OPERATIONS, PENDING_CHANGES, SNAPSHOTS,
+    SNAPSHOT_DISPUTES, UNCLAIMED_BONUS_PRIZES, USER_TOTAL_WON, USER_WINS, USER_WIN_COUNT,
 };
 
 // V2-M-03 FIX: Bounds for reveal_deadline_seconds
 pub const MIN_REVEAL_DEADLINE_SECS: u64 = 300; // 5 minutes
 pub const MAX_REVEAL_DEADLINE_SECS: u64 = 86400; // 24 hours
 
+/// Furthest ahead of the latest snapshot a bonus prize can be targeted, in epochs.
+/// Keeps the buckets CommitDraw sweeps bounded.
+pub const MAX_BONUS_EPOCHS_AHEAD: u64 = 52;
+
+/// Most denoms one bonus bucket can hold, so paying a winner stays cheap.
+pub const MAX_BONUS_DENOMS: usize = 10;
+
+/// Reply id of the submessages paying bonus prizes, one per denom. Only failed
+/// payouts reply.
+pub const BONUS_PAYOUT_REPLY_ID: u64 = 1;
+
+/// Most prizes one draw can pay. Each prize costs a merkle proof and a staking hub
+/// query at reveal time.
+pub const MAX_PRIZES_PER_DRAW: u32 = 50;
+
+/// Most times one prize's ticket is redrawn after landing on an ineligible holder.
+/// A prize still without an eligible winner after that goes back to its pool.
+pub const MAX_PRIZE_REDRAWS: u32 = 10;
+
 /// Validate reveal_deadline_seconds is within acceptable bounds.
 pub fn validate_reveal_deadline(value: u64) -> Result<(), ContractError> {
     if !(MIN_REVEAL_DEADLINE_SECS..=MAX_REVEAL_DEADLINE_SECS).contains(&value) {
@@ -32,6 +56,70 @@ pub fn validate_reveal_deadline(value: u64) -> Result<(), ContractError> {
     Ok(())
 }
 
+/// The default prize table: one prize of the whole reward.
+pub fn single_prize_tier() -> Vec<PrizeTier> {
+    vec![PrizeTier {
+        count: 1,
+        share_bps: 10_000,
+    }]
+}
+
+/// Validate a prize table: every tier pays at least one non-zero prize, the table
+/// pays at most MAX_PRIZES_PER_DRAW prizes, and the shares add up to 10000 bps.
+pub fn validate_prize_tiers(tiers: &[PrizeTier]) -> Result<(), ContractError> {
+    if tiers.iter().any(|t| t.count == 0 || t.share_bps == 0) {
+        return Err(ContractError::InvalidPrizeTiers {
+            reason: "every tier needs a non-zero count and share".to_string(),
+        });
+    }
+    let num_prizes: u64 = tiers.iter().map(|t| t.count as u64).sum();
+    if num_prizes == 0 || num_prizes > MAX_PRIZES_PER_DRAW as u64 {
+        return Err(ContractError::InvalidPrizeTiers {
+            reason: format!(
+                "{} prizes, must be between 1 and {}",
+                num_prizes, MAX_PRIZES_PER_DRAW
+            ),
+        });
+    }
+    let total_bps: u64 = tiers
+        .iter()
+        .map(|t| t.count as u64 * t.share_bps as u64)
+        .sum();
+    if total_bps != 10_000 {
+        return Err(ContractError::InvalidPrizeTiers {
+            reason: format!("shares add up to {} bps, must be 10000", total_bps),
+        });
+    }
+    Ok(())
+}
+
+/// Winning ticket of the `slot`-th prize of a draw, counting across all tiers.
+/// The first prize takes uint128(final[0..16]) like single-prize draws; later prizes
+/// take uint128(sha256(final || slot as big-endian u32)[0..16]). The `redraw`-th
+/// redraw of a prize whose ticket landed on an ineligible holder takes
+/// uint128(sha256(final || slot || redraw, both big-endian u32)[0..16]).
+pub fn prize_ticket(
+    final_randomness: &[u8; 32],
+    slot: u32,
+    redraw: u32,
+    total_weight: u128,
+) -> u128 {
+    let mut ticket_bytes = [0u8; 16];
+    if slot == 0 && redraw == 0 {
+        ticket_bytes.copy_from_slice(&final_randomness[0..16]);
+    } else {
+        let mut hasher = Sha256::new()
+            .chain_update(final_randomness)
+            .chain_update(slot.to_be_bytes());
+        if redraw > 0 {
+            hasher.update(redraw.to_be_bytes());
+        }
+        let derived: [u8; 32] = hasher.finalize().into();
+        ticket_bytes.copy_from_slice(&derived[0..16]);
+    }
+    u128::from_be_bytes(ticket_bytes) % total_weight
+}
+
 /// Fund the regular draw pool. Only staking hub can call.
 pub fn fund_regular_pool(
     deps: DepsMut,
@@ -127,9 +215,19 @@ pub fn set_snapshot(
         });
     }
 
-    // M-01 FIX: Prevent overwriting existing snapshots
-    if SNAPSHOTS.has(deps.storage, epoch) {
-        return Err(ContractError::SnapshotAlreadyExists { epoch });
+    // M-01 FIX: Prevent overwriting existing snapshots. An invalidated one can be
+    // replaced by the hub's retaken snapshot, which starts a fresh challenge window.
+    if let Some(existing) = SNAPSHOTS.may_load(deps.storage, epoch)? {
+        if !existing.invalidated {
+            return Err(ContractError::SnapshotAlreadyExists { epoch });
+        }
+        let disputers: Vec<Addr> = SNAPSHOT_DISPUTES
+            .prefix(epoch)
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()?;
+        for disputer in &disputers {
+            SNAPSHOT_DISPUTES.remove(deps.storage, (epoch, disputer));
+        }
     }
 
     let snapshot = Snapshot {
@@ -138,6 +236,8 @@ pub fn set_snapshot(
         total_weight,
         num_holders,
         submitted_at: env.block.time,
+        challenge_deadline: env.block.time.plus_seconds(config.challenge_period_seconds),
+        invalidated: false,
     };
     SNAPSHOTS.save(deps.storage, epoch, &snapshot)?;
 
@@ -152,10 +252,192 @@ pub fn set_snapshot(
             Event::new("chance_snapshot_set")
                 .add_attribute("epoch", epoch.to_string())
                 .add_attribute("total_weight", total_weight.to_string())
-                .add_attribute("num_holders", num_holders.to_string()),
+                .add_attribute("num_holders", num_holders.to_string())
+                .add_attribute(
+                    "challenge_deadline",
+                    snapshot.challenge_deadline.seconds().to_string(),
+                ),
+        ))
+}
+
+/// Escrow a bonus prize for a future draw. Anyone can call.
+/// Accepts the denoms on the bonus denom list, at or above their minimum amounts.
+/// INJ goes to the pools through the hub instead.
+pub fn add_bonus_prize(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    draw_type: DrawType,
+    target_epoch: Option<u64>,
+) -> Result<Response, ContractError> {
+    if info.funds.is_empty() {
+        return Err(ContractError::NoFundsSent);
+    }
+    if info.funds.iter().any(|c| c.denom == "inj") {
+        return Err(ContractError::InvalidBonusPrize {
+            reason: "INJ prizes go through the staking hub's Donate".to_string(),
+        });
+    }
+    for coin in &info.funds {
+        let min_amount = BONUS_DENOMS
+            .may_load(deps.storage, &coin.denom)?
+            .ok_or_else(|| ContractError::InvalidBonusPrize {
+                reason: format!("{} is not accepted as a bonus prize", coin.denom),
+            })?;
+        if coin.amount < min_amount {
+            return Err(ContractError::InvalidBonusPrize {
+                reason: format!("at least {}{} per bonus prize", min_amount, coin.denom),
+            });
+        }
+    }
+
+    let latest_epoch = LATEST_SNAPSHOT_EPOCH.may_load(deps.storage)?.unwrap_or(0);
+    let target_epoch = target_epoch.unwrap_or(latest_epoch);
+    if target_epoch > latest_epoch + MAX_BONUS_EPOCHS_AHEAD {
+        return Err(ContractError::InvalidBonusPrize {
+            reason: format!(
+                "target epoch {} is more than {} epochs after the latest snapshot {}",
+                target_epoch, MAX_BONUS_EPOCHS_AHEAD, latest_epoch
+            ),
+        });
+    }
+
+    let pool = pool_name(&draw_type);
+    let mut bucket = BONUS_PRIZES
+        .may_load(deps.storage, (pool, target_epoch))?
+        .unwrap_or_default();
+    merge_coins(&mut bucket, &info.funds);
+    if bucket.len() > MAX_BONUS_DENOMS {
+        return Err(ContractError::InvalidBonusPrize {
+            reason: format!("at most {} denoms per bonus prize", MAX_BONUS_DENOMS),
+        });
+    }
+    BONUS_PRIZES.save(deps.storage, (pool, target_epoch), &bucket)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "add_bonus_prize")
+        .add_attribute("sponsor", info.sender.to_string())
+        .add_event(
+            Event::new("chance_bonus_prize_added")
+                .add_attribute("sponsor", info.sender.to_string())
+                .add_attribute("draw_type", pool)
+                .add_attribute("target_epoch", target_epoch.to_string())
+                .add_attribute("funds", coins_to_string(&info.funds)),
         ))
 }
 
+/// Claim a bonus prize whose payout failed at reveal. Winner only.
+pub fn claim_bonus_prize(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    denom: String,
+) -> Result<Response, ContractError> {
+    let amount = UNCLAIMED_BONUS_PRIZES
+        .may_load(deps.storage, (&info.sender, &denom))?
+        .ok_or_else(|| ContractError::NoUnclaimedBonusPrize {
+            address: info.sender.to_string(),
+            denom: denom.clone(),
+        })?;
+    UNCLAIMED_BONUS_PRIZES.remove(deps.storage, (&info.sender, &denom));
+
+    let coin = Coin { denom, amount };
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![coin.clone()],
+        })
+        .add_attribute("action", "claim_bonus_prize")
+        .add_event(
+            Event::new("chance_bonus_prize_claimed")
+                .add_attribute("winner", info.sender.to_string())
+                .add_attribute("funds", coin.to_string()),
+        ))
+}
+
+/// Add denoms to or remove them from the bonus denom list. Admin only.
+pub fn update_bonus_denoms(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    add: Vec<Coin>,
+    remove: Vec<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {
+            reason: "only admin can update bonus denoms".to_string(),
+        });
+    }
+
+    for coin in &add {
+        if coin.denom.is_empty() || coin.denom == "inj" {
+            return Err(ContractError::InvalidBonusPrize {
+                reason: format!("{:?} can't be a bonus denom", coin.denom),
+            });
+        }
+        BONUS_DENOMS.save(deps.storage, &coin.denom, &coin.amount)?;
+    }
+    for denom in &remove {
+        BONUS_DENOMS.remove(deps.storage, denom);
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "update_bonus_denoms")
+        .add_attribute("added", coins_to_string(&add))
+        .add_attribute("removed", remove.join(",")))
+}
+
+/// Reply to a failed bonus payout: keep the coin for the winner to claim instead of
+/// failing the reveal. It is never put back into the bonus buckets.
+pub fn bonus_payout_reply(deps: DepsMut, msg: Reply) -> Result<Response, ContractError> {
+    let payout: BonusPayout = from_json(&msg.payload)?;
+    let error = match msg.result {
+        SubMsgResult::Err(error) => error,
+        SubMsgResult::Ok(_) => return Ok(Response::new()),
+    };
+
+    let key = (&payout.winner, payout.coin.denom.as_str());
+    let unclaimed = UNCLAIMED_BONUS_PRIZES
+        .may_load(deps.storage, key)?
+        .unwrap_or_default();
+    UNCLAIMED_BONUS_PRIZES.save(deps.storage, key, &(unclaimed + payout.coin.amount))?;
+
+    Ok(Response::new().add_event(
+        Event::new("chance_bonus_prize_unpaid")
+            .add_attribute("draw_id", payout.draw_id.to_string())
+            .add_attribute("winner", payout.winner.to_string())
+            .add_attribute("funds", payout.coin.to_string())
+            .add_attribute("error", error),
+    ))
+}
+
+/// Pool name of a draw type, as used in events and bonus prize keys.
+pub fn pool_name(draw_type: &DrawType) -> &'static str {
+    match draw_type {
+        DrawType::Regular => "regular",
+        DrawType::Big => "big",
+    }
+}
+
+/// Add `coins` into `total`, merging by denom and keeping `total` sorted by denom.
+pub fn merge_coins(total: &mut Vec<Coin>, coins: &[Coin]) {
+    for coin in coins.iter().filter(|c| !c.amount.is_zero()) {
+        match total.binary_search_by(|c| c.denom.as_str().cmp(&coin.denom)) {
+            Ok(i) => total[i].amount += coin.amount,
+            Err(i) => total.insert(i, coin.clone()),
+        }
+    }
+}
+
+fn coins_to_string(coins: &[Coin]) -> String {
+    coins
+        .iter()
+        .map(|c| c.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
 /// Commit to a draw. Operator only.
 /// Reward amount is the full pool balance at commit time.
 /// Epoch spacing is enforced per draw type.
@@ -199,6 +481,25 @@ pub fn commit_draw(
         return Err(ContractError::ZeroWeight);
     }
 
+    // The snapshot must have survived its challenge window unchallenged
+    if snapshot.invalidated {
+        return Err(ContractError::SnapshotInvalidated { epoch });
+    }
+    if env.block.time < snapshot.challenge_deadline {
+        return Err(ContractError::ChallengeWindowOpen {
+            epoch,
+            ends_at: snapshot.challenge_deadline.seconds(),
+        });
+    }
+    if SNAPSHOT_DISPUTES
+        .prefix(epoch)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .next()
+        .is_some()
+    {
+        return Err(ContractError::SnapshotDisputed { epoch });
+    }
+
     let mut state = DRAW_STATE.load(deps.storage)?;
 
     // Enforce epoch spacing and take full pool balance as reward
@@ -250,6 +551,23 @@ pub fn commit_draw(
     let draw_id = state.next_draw_id;
     state.next_draw_id += 1;
 
+    // Sweep in the bonus prizes targeted at this epoch or earlier
+    let pool = pool_name(&draw_type);
+    let due: Vec<(u64, Vec<Coin>)> = BONUS_PRIZES
+        .prefix(pool)
+        .range(
+            deps.storage,
+            None,
+            Some(Bound::inclusive(epoch)),
+            Order::Ascending,
+        )
+        .collect::<StdResult<_>>()?;
+    let mut bonus_prizes = vec![];
+    for (target_epoch, funds) in due {
+        merge_coins(&mut bonus_prizes, &funds);
+        BONUS_PRIZES.remove(deps.storage, (pool, target_epoch));
+    }
+
     let reveal_deadline =
         Timestamp::from_seconds(env.block.time.seconds() + config.reveal_deadline_seconds);
 
@@ -270,6 +588,12 @@ pub fn commit_draw(
         reveal_deadline,
         merkle_root: None,
         total_weight: None,
+        bonus_prizes,
+        prize_tiers: match draw_type {
+            DrawType::Regular => config.regular_prize_tiers.clone(),
+            DrawType::Big => config.big_prize_tiers.clone(),
+        },
+        winners: vec![],
     };
 
     DRAWS.save(deps.storage, draw_id, &draw)?;
@@ -288,6 +612,15 @@ pub fn commit_draw(
                 .add_attribute("draw_id", draw_id.to_string())
                 .add_attribute("draw_type", draw_type_str)
                 .add_attribute("reward_amount", reward_amount.to_string())
+                .add_attribute("bonus_prizes", coins_to_string(&draw.bonus_prizes))
+                .add_attribute(
+                    "num_prizes",
+                    draw.prize_tiers
+                        .iter()
+                        .map(|t| t.count)
+                        .sum::<u32>()
+                        .to_string(),
+                )
                 .add_attribute("target_drand_round", target_drand_round.to_string())
                 .add_attribute("reveal_deadline", reveal_deadline.seconds().to_string())
                 .add_attribute("epoch", epoch.to_string()),
@@ -300,10 +633,12 @@ pub fn commit_draw(
 /// 1. Verify commit pre-image: sha256(secret) == commit
 /// 2. Query drand oracle for beacon randomness
 /// 3. XOR randomness: final = drand_randomness XOR sha256(secret)
-/// 4. Compute winning_ticket = uint128(final[0..16]) % total_weight
-/// 5. Verify merkle proof that winner's range contains winning_ticket
-/// 6. Verify winner meets min_epochs eligibility (queries staking hub)
-/// 7. Send reward to winner
+/// 4. Derive one winning ticket per prize from the final randomness (see `prize_ticket`)
+/// 5. Verify each winner's range contains its prize's ticket, with a merkle proof
+/// 6. Check each winner isn't excluded and meets min_epochs eligibility (queries staking
+///    hub). An ineligible winner redraws the prize's ticket, and the next leaf must hold
+///    the redrawn ticket. After MAX_PRIZE_REDRAWS the prize goes back to its pool.
+/// 7. Send every prize to its winner, bonus prizes to the first prize winner
 /// 8. Update USER_WINS and USER_TOTAL_WON
 pub fn reveal_draw(
     deps: DepsMut,
@@ -314,10 +649,7 @@ pub fn reveal_draw(
     let RevealDrawParams {
         draw_id,
         operator_secret_hex,
-        winner_address,
-        winner_cumulative_start,
-        winner_cumulative_end,
-        merkle_proof,
+        winners,
     } = params;
 
     let config = CONFIG.load(deps.storage)?;
@@ -344,6 +676,16 @@ pub fn reveal_draw(
         });
     }
 
+    // One winner per prize of the draw's table, plus one per redraw
+    let num_prizes: u32 = draw.prize_tiers.iter().map(|t| t.count).sum();
+    if winners.len() < num_prizes as usize {
+        return Err(ContractError::WinnerCountMismatch {
+            draw_id,
+            expected: num_prizes,
+            provided: winners.len() as u32,
+        });
+    }
+
     // 1. Verify commit pre-image
     let operator_secret =
         hex::decode(&operator_secret_hex).map_err(|_| ContractError::InvalidHex {
@@ -382,82 +724,134 @@ pub fn reveal_draw(
         final_randomness[i] = drand_randomness[i] ^ secret_hash[i];
     }
 
-    // 4. Get snapshot for this draw's epoch
+    // 4. Get snapshot for this draw's epoch. It must still be the one the draw was
+    // committed on, not invalidated or replaced since.
     let snapshot = SNAPSHOTS
         .may_load(deps.storage, draw.epoch)?
         .ok_or(ContractError::NoSnapshot)?;
-
-    let total_weight = snapshot.total_weight;
-
-    // Compute winning_ticket = uint128(final_randomness[0..16]) % total_weight
-    let mut ticket_bytes = [0u8; 16];
-    ticket_bytes.copy_from_slice(&final_randomness[0..16]);
-    let ticket_raw = u128::from_be_bytes(ticket_bytes);
-    let winning_ticket = ticket_raw % total_weight.u128();
-
-    // 5. Verify winning ticket is in winner's range
-    if winning_ticket < winner_cumulative_start.u128()
-        || winning_ticket >= winner_cumulative_end.u128()
-    {
-        return Err(ContractError::WinningTicketOutOfRange {
-            ticket: winning_ticket,
-            start: winner_cumulative_start.u128(),
-            end: winner_cumulative_end.u128(),
-        });
+    if snapshot.invalidated || snapshot.submitted_at > draw.created_at {
+        return Err(ContractError::SnapshotInvalidated { epoch: draw.epoch });
     }
 
-    // 6. Verify merkle proof
-    let leaf_hash = compute_leaf_hash(
-        &winner_address,
-        winner_cumulative_start.u128(),
-        winner_cumulative_end.u128(),
-    );
-    if !verify_merkle_proof(&snapshot.merkle_root, &merkle_proof, &leaf_hash) {
-        return Err(ContractError::InvalidMerkleProof);
-    }
-
-    // 7. Verify winner meets min_epochs eligibility for this draw type
-    let hub_config_query = QueryRequest::Wasm(WasmQuery::Smart {
-        contract_addr: config.staking_hub.to_string(),
-        msg: to_json_binary(&StakingHubQueryMsg::Config {})?,
-    });
-    let hub_config: StakingHubConfigResponse = deps.querier.query(&hub_config_query)?;
-
-    let staker_info_query = QueryRequest::Wasm(WasmQuery::Smart {
-        contract_addr: config.staking_hub.to_string(),
-        msg: to_json_binary(&StakingHubQueryMsg::StakerInfo {
-            address: winner_address.clone(),
-        })?,
-    });
-    let staker_info: StakerInfoResponse = deps.querier.query(&staker_info_query)?;
+    let total_weight = snapshot.total_weight;
 
+    let hub_config: StakingHubConfigResponse = deps
+        .querier
+        .query_wasm_smart(&config.staking_hub, &StakingHubQueryMsg::Config {})?;
     let min_epochs = match draw.draw_type {
         DrawType::Regular => hub_config.min_epochs_regular,
         DrawType::Big => hub_config.min_epochs_big,
     };
 
-    let epochs_staked = match staker_info.stake_epoch {
-        Some(stake_epoch) if draw.epoch >= stake_epoch => draw.epoch - stake_epoch,
-        _ => 0,
-    };
+    // Prize slots in table order: (tier index, share of the reward)
+    let slots =
+        draw.prize_tiers.iter().enumerate().flat_map(|(tier, t)| {
+            std::iter::repeat_n((tier as u32, t.share_bps), t.count as usize)
+        });
 
-    if epochs_staked < min_epochs {
-        let draw_type_str = match draw.draw_type {
-            DrawType::Regular => "regular",
-            DrawType::Big => "big",
-        };
-        return Err(ContractError::WinnerNotEligible {
-            address: winner_address,
-            draw_type: draw_type_str.to_string(),
-            epochs_staked,
-            min_epochs,
+    let provided = winners.len() as u32;
+    let mut leaves = winners.into_iter();
+    let mut prizes: Vec<PrizeWinner> = Vec::with_capacity(num_prizes as usize);
+    let mut redraw_events: Vec<Event> = vec![];
+    let mut forfeited = Uint128::zero();
+    for (slot, (tier, share_bps)) in slots.enumerate() {
+        let amount = draw
+            .reward_amount
+            .multiply_ratio(share_bps as u128, 10_000u128);
+        let mut winner = None;
+        let mut ineligible = None;
+        for redraw in 0..=MAX_PRIZE_REDRAWS {
+            let Some(leaf) = leaves.next() else {
+                // Ran out of leaves: either too few winners, or no leaf for a redraw
+                return Err(ineligible.unwrap_or(ContractError::WinnerCountMismatch {
+                    draw_id,
+                    expected: num_prizes,
+                    provided,
+                }));
+            };
+
+            // 5. Verify the prize's winning ticket is in the winner's range
+            let winning_ticket =
+                prize_ticket(&final_randomness, slot as u32, redraw, total_weight.u128());
+            if winning_ticket < leaf.cumulative_start.u128()
+                || winning_ticket >= leaf.cumulative_end.u128()
+            {
+                return Err(ContractError::WinningTicketOutOfRange {
+                    ticket: winning_ticket,
+                    start: leaf.cumulative_start.u128(),
+                    end: leaf.cumulative_end.u128(),
+                });
+            }
+            verify_snapshot_leaf(&snapshot, &leaf)?;
+
+            // 6. An excluded or not yet eligible winner redraws the prize's ticket
+            match assert_winner_eligible(
+                &deps.querier,
+                &config.staking_hub,
+                &draw,
+                min_epochs,
+                &leaf.address,
+            ) {
+                Ok(()) => {
+                    winner = Some(PrizeWinner {
+                        tier,
+                        address: deps.api.addr_validate(&leaf.address)?,
+                        amount,
+                        winning_ticket: Uint128::from(winning_ticket),
+                    });
+                    break;
+                }
+                Err(
+                    err @ (ContractError::WinnerExcluded { .. }
+                    | ContractError::WinnerNotEligible { .. }),
+                ) => {
+                    redraw_events.push(
+                        Event::new("chance_prize_redrawn")
+                            .add_attribute("draw_id", draw_id.to_string())
+                            .add_attribute("tier", tier.to_string())
+                            .add_attribute("slot", slot.to_string())
+                            .add_attribute("redraw", redraw.to_string())
+                            .add_attribute("ineligible_holder", leaf.address)
+                            .add_attribute("winning_ticket", winning_ticket.to_string()),
+                    );
+                    ineligible = Some(err);
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        match winner {
+            Some(winner) => prizes.push(winner),
+            None => {
+                forfeited += amount;
+                redraw_events.push(
+                    Event::new("chance_prize_forfeited")
+                        .add_attribute("draw_id", draw_id.to_string())
+                        .add_attribute("tier", tier.to_string())
+                        .add_attribute("slot", slot.to_string())
+                        .add_attribute("amount", amount.to_string()),
+                );
+            }
+        }
+    }
+    if leaves.len() > 0 {
+        return Err(ContractError::UnusedWinners {
+            draw_id,
+            unused: leaves.len() as u32,
         });
     }
 
-    // 8. Send reward to winner
-    let winner_addr = deps.api.addr_validate(&winner_address)?;
+    // Rounding dust goes to the first prize paid
+    let split = prizes.iter().map(|p| p.amount).sum::<Uint128>() + forfeited;
+    let dust = draw.reward_amount - split;
+    match prizes.first_mut() {
+        Some(first) => first.amount += dust,
+        None => forfeited += dust,
+    }
+    let paid = draw.reward_amount - forfeited;
 
-    // 8a. L-05 FIX: Verify contract has sufficient balance before sending
+    // 7. Send prizes to winners
+    // 7a. L-05 FIX: Verify contract has sufficient balance before sending
     let contract_balance = deps
         .querier
         .query_balance(&env.contract.address, "inj")?
@@ -469,69 +863,176 @@ pub fn reveal_draw(
         });
     }
 
-    let send_msg = BankMsg::Send {
-        to_address: winner_address.clone(),
-        amount: coins(draw.reward_amount.u128(), "inj"),
+    let send_msgs: Vec<BankMsg> = prizes
+        .iter()
+        .filter(|p| !p.amount.is_zero())
+        .map(|p| BankMsg::Send {
+            to_address: p.address.to_string(),
+            amount: coins(p.amount.u128(), "inj"),
+        })
+        .collect();
+    let first_winner = prizes.first().map(|p| p.address.clone());
+    // One payout per denom, so a token whose transfer fails (e.g. a rejecting
+    // send hook) neither blocks the reveal nor the other bonus prizes
+    let bonus_msgs = match &first_winner {
+        Some(first_winner) => draw
+            .bonus_prizes
+            .iter()
+            .map(|coin| {
+                let payout = BonusPayout {
+                    draw_id,
+                    winner: first_winner.clone(),
+                    coin: coin.clone(),
+                };
+                Ok(SubMsg::reply_on_error(
+                    BankMsg::Send {
+                        to_address: first_winner.to_string(),
+                        amount: vec![coin.clone()],
+                    },
+                    BONUS_PAYOUT_REPLY_ID,
+                )
+                .with_payload(to_json_binary(&payout)?))
+            })
+            .collect::<StdResult<Vec<_>>>()?,
+        None => vec![],
     };
 
-    // 9. Update draw state
+    // Update draw state
     draw.status = DrawStatus::Revealed;
     draw.drand_randomness = Some(drand_randomness.clone());
     draw.operator_secret = Some(operator_secret);
     draw.final_randomness = Some(final_randomness.to_vec());
-    draw.winner = Some(winner_addr.clone());
+    draw.winner = first_winner.clone();
+    draw.winners = prizes;
     draw.revealed_at = Some(env.block.time);
     draw.merkle_root = Some(snapshot.merkle_root.clone());
     draw.total_weight = Some(total_weight);
     DRAWS.save(deps.storage, draw_id, &draw)?;
 
-    // 10. Update draw state totals
+    // Update draw state totals. Forfeited prizes go back to the draw's pool.
     let mut state = DRAW_STATE.load(deps.storage)?;
     state.total_draws_completed += 1;
-    state.total_rewards_distributed += draw.reward_amount;
+    state.total_rewards_distributed += paid;
+    match draw.draw_type {
+        DrawType::Regular => state.regular_pool_balance += forfeited,
+        DrawType::Big => state.big_pool_balance += forfeited,
+    }
     DRAW_STATE.save(deps.storage, &state)?;
 
-    // 11. Update per-user win tracking (O(1) write per win, no unbounded Vec)
-    USER_WINS.save(deps.storage, (&winner_addr, draw_id), &())?;
-    let win_count = USER_WIN_COUNT
-        .may_load(deps.storage, &winner_addr)?
-        .unwrap_or(0);
-    USER_WIN_COUNT.save(deps.storage, &winner_addr, &(win_count + 1))?;
+    // With no prize paid, bonus prizes roll over like on an expired draw
+    if first_winner.is_none() {
+        roll_over_bonus_prizes(deps.storage, &draw)?;
+    }
 
-    let user_total = USER_TOTAL_WON
-        .may_load(deps.storage, &winner_addr)?
-        .unwrap_or(Uint128::zero());
-    USER_TOTAL_WON.save(
-        deps.storage,
-        &winner_addr,
-        &(user_total + draw.reward_amount),
-    )?;
+    // 8. Update per-user win tracking (O(1) write per win, no unbounded Vec).
+    // A holder winning several prizes of one draw counts one win.
+    for prize in &draw.winners {
+        if !USER_WINS.has(deps.storage, (&prize.address, draw_id)) {
+            USER_WINS.save(deps.storage, (&prize.address, draw_id), &())?;
+            let win_count = USER_WIN_COUNT
+                .may_load(deps.storage, &prize.address)?
+                .unwrap_or(0);
+            USER_WIN_COUNT.save(deps.storage, &prize.address, &(win_count + 1))?;
+        }
 
-    let draw_type_str = match draw.draw_type {
-        DrawType::Regular => "regular",
-        DrawType::Big => "big",
-    };
+        let user_total = USER_TOTAL_WON
+            .may_load(deps.storage, &prize.address)?
+            .unwrap_or(Uint128::zero());
+        USER_TOTAL_WON.save(deps.storage, &prize.address, &(user_total + prize.amount))?;
+    }
+
+    let draw_type_str = pool_name(&draw.draw_type);
+    let prize_events = draw.winners.iter().map(|p| {
+        Event::new("chance_prize_won")
+            .add_attribute("draw_id", draw_id.to_string())
+            .add_attribute("tier", p.tier.to_string())
+            .add_attribute("winner", p.address.to_string())
+            .add_attribute("amount", p.amount.to_string())
+            .add_attribute("winning_ticket", p.winning_ticket.to_string())
+    });
 
+    let first_winner = first_winner.map(|w| w.to_string()).unwrap_or_default();
     Ok(Response::new()
-        .add_message(send_msg)
+        .add_messages(send_msgs)
+        .add_submessages(bonus_msgs)
         .add_attribute("action", "reveal_draw")
         .add_attribute("draw_id", draw_id.to_string())
-        .add_attribute("winner", winner_address.clone())
+        .add_attribute("winner", first_winner.clone())
         .add_attribute("reward_amount", draw.reward_amount.to_string())
         .add_event(
             Event::new("chance_draw_result")
                 .add_attribute("draw_id", draw_id.to_string())
                 .add_attribute("draw_type", draw_type_str)
                 .add_attribute("epoch", draw.epoch.to_string())
-                .add_attribute("winner", winner_address)
+                .add_attribute("winner", first_winner)
+                .add_attribute("num_winners", draw.winners.len().to_string())
                 .add_attribute("reward_amount", draw.reward_amount.to_string())
+                .add_attribute("forfeited_amount", forfeited.to_string())
                 .add_attribute("reward_denom", "inj")
-                .add_attribute("winning_ticket", winning_ticket.to_string())
+                .add_attribute("bonus_prizes", coins_to_string(&draw.bonus_prizes))
+                .add_attribute(
+                    "winning_ticket",
+                    draw.winners
+                        .first()
+                        .map(|p| p.winning_ticket.to_string())
+                        .unwrap_or_default(),
+                )
                 .add_attribute("total_weight", total_weight.to_string())
                 .add_attribute("final_randomness", hex::encode(final_randomness))
                 .add_attribute("drand_round", draw.target_drand_round.to_string())
                 .add_attribute("timestamp", env.block.time.seconds().to_string()),
-        ))
+        )
+        .add_events(redraw_events)
+        .add_events(prize_events))
+}
+
+/// Reject a prize winner that is excluded from draws or hasn't been staked for
+/// `min_epochs` at the draw's epoch.
+fn assert_winner_eligible(
+    querier: &QuerierWrapper,
+    staking_hub: &Addr,
+    draw: &Draw,
+    min_epochs: u64,
+    address: &str,
+) -> Result<(), ContractError> {
+    let staker_info: StakerInfoResponse = querier.query_wasm_smart(
+        staking_hub,
+        &StakingHubQueryMsg::StakerInfo {
+            address: address.to_string(),
+        },
+    )?;
+
+    if staker_info.excluded {
+        return Err(ContractError::WinnerExcluded {
+            address: address.to_string(),
+        });
+    }
+
+    let epochs_staked = match staker_info.stake_epoch {
+        Some(stake_epoch) if draw.epoch >= stake_epoch => draw.epoch - stake_epoch,
+        _ => 0,
+    };
+
+    if epochs_staked < min_epochs {
+        return Err(ContractError::WinnerNotEligible {
+            address: address.to_string(),
+            draw_type: pool_name(&draw.draw_type).to_string(),
+            epochs_staked,
+            min_epochs,
+        });
+    }
+    Ok(())
+}
+
+/// Roll a draw's bonus prizes over to the next draw of the same pool.
+fn roll_over_bonus_prizes(storage: &mut dyn Storage, draw: &Draw) -> StdResult<()> {
+    if draw.bonus_prizes.is_empty() {
+        return Ok(());
+    }
+    let key = (pool_name(&draw.draw_type), draw.epoch);
+    let mut bucket = BONUS_PRIZES.may_load(storage, key)?.unwrap_or_default();
+    merge_coins(&mut bucket, &draw.bonus_prizes);
+    BONUS_PRIZES.save(storage, key, &bucket)
 }
 
 /// Expire a draw that wasn't revealed in time. Anyone can call.
@@ -569,6 +1070,8 @@ pub fn expire_draw(
     }
     DRAW_STATE.save(deps.storage, &state)?;
 
+    roll_over_bonus_prizes(deps.storage, &draw)?;
+
     draw.status = DrawStatus::Expired;
     DRAWS.save(deps.storage, draw_id, &draw)?;
 
@@ -584,10 +1087,327 @@ pub fn expire_draw(
             Event::new("chance_draw_expired")
                 .add_attribute("draw_id", draw_id.to_string())
                 .add_attribute("returned_amount", draw.reward_amount.to_string())
+                .add_attribute("returned_bonus", coins_to_string(&draw.bonus_prizes))
                 .add_attribute("pool", pool_str),
         ))
 }
 
+/// Submit a fraud proof against a snapshot. Anyone can call, but only until the
+/// snapshot's challenge deadline.
+///
+/// OverlappingLeaves and UnderweightLeaf are checked on the spot and invalidate the
+/// snapshot when they hold. A missing leaf cannot be proven directly, so MissingLeaf
+/// opens a dispute instead: it blocks CommitDraw until someone answers it with the
+/// disputer's leaf, or the admin dismisses it or invalidates the snapshot.
+pub fn challenge_snapshot(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    epoch: u64,
+    challenge: SnapshotChallenge,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut snapshot = SNAPSHOTS
+        .may_load(deps.storage, epoch)?
+        .ok_or(ContractError::NoSnapshot)?;
+
+    if snapshot.invalidated {
+        return Err(ContractError::SnapshotInvalidated { epoch });
+    }
+    if env.block.time >= snapshot.challenge_deadline {
+        return Err(ContractError::ChallengeWindowClosed { epoch });
+    }
+
+    let reason = match challenge {
+        SnapshotChallenge::OverlappingLeaves { first, second } => {
+            verify_snapshot_leaf(&snapshot, &first)?;
+            verify_snapshot_leaf(&snapshot, &second)?;
+            if first == second {
+                return Err(ContractError::ChallengeRejected {
+                    reason: "leaves are identical".to_string(),
+                });
+            }
+            let overlaps = first.cumulative_start < second.cumulative_end
+                && second.cumulative_start < first.cumulative_end;
+            if !overlaps {
+                return Err(ContractError::ChallengeRejected {
+                    reason: "leaf ranges do not overlap".to_string(),
+                });
+            }
+            "overlapping_leaves"
+        }
+        SnapshotChallenge::UnderweightLeaf { leaf } => {
+            verify_snapshot_leaf(&snapshot, &leaf)?;
+            let min_balance = snapshot_min_balance(
+                deps.storage,
+                &deps.querier,
+                &config.staking_hub,
+                &snapshot,
+                &leaf.address,
+            )?;
+            if leaf_weight(&leaf) >= min_balance {
+                return Err(ContractError::ChallengeRejected {
+                    reason: format!(
+                        "leaf weight {} is not below minimum balance {}",
+                        leaf_weight(&leaf),
+                        min_balance
+                    ),
+                });
+            }
+            "underweight_leaf"
+        }
+        SnapshotChallenge::MissingLeaf {} => {
+            if SNAPSHOT_DISPUTES.has(deps.storage, (epoch, &info.sender)) {
+                return Err(ContractError::ChallengeRejected {
+                    reason: "dispute already open".to_string(),
+                });
+            }
+            // Excluded and not-yet-eligible holders are left out of the tree on purpose
+            let hub_config: StakingHubConfigResponse = deps
+                .querier
+                .query_wasm_smart(&config.staking_hub, &StakingHubQueryMsg::Config {})?;
+            let staker_info: StakerInfoResponse = deps.querier.query_wasm_smart(
+                &config.staking_hub,
+                &StakingHubQueryMsg::StakerInfo {
+                    address: info.sender.to_string(),
+                },
+            )?;
+            if staker_info.excluded {
+                return Err(ContractError::ChallengeRejected {
+                    reason: "sender is excluded from snapshots".to_string(),
+                });
+            }
+            let epochs_staked = staker_info
+                .stake_epoch
+                .map(|stake_epoch| epoch.saturating_sub(stake_epoch))
+                .unwrap_or(0);
+            if epochs_staked < hub_config.min_epochs_regular {
+                return Err(ContractError::ChallengeRejected {
+                    reason: format!(
+                        "sender staked {} epochs, snapshots need {}",
+                        epochs_staked, hub_config.min_epochs_regular
+                    ),
+                });
+            }
+            let min_balance = snapshot_min_balance(
+                deps.storage,
+                &deps.querier,
+                &config.staking_hub,
+                &snapshot,
+                info.sender.as_str(),
+            )?;
+            if min_balance.is_zero() {
+                return Err(ContractError::ChallengeRejected {
+                    reason: "no csINJ held over the snapshot window".to_string(),
+                });
+            }
+            let dispute = SnapshotDispute {
+                epoch,
+                disputer: info.sender.clone(),
+                min_balance,
+                opened_at: env.block.time,
+            };
+            SNAPSHOT_DISPUTES.save(deps.storage, (epoch, &info.sender), &dispute)?;
+
+            return Ok(Response::new()
+                .add_attribute("action", "challenge_snapshot")
+                .add_attribute("epoch", epoch.to_string())
+                .add_event(
+                    Event::new("chance_snapshot_disputed")
+                        .add_attribute("epoch", epoch.to_string())
+                        .add_attribute("disputer", info.sender.to_string())
+                        .add_attribute("min_balance", min_balance.to_string()),
+                ));
+        }
+    };
+
+    snapshot.invalidated = true;
+    SNAPSHOTS.save(deps.storage, epoch, &snapshot)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "challenge_snapshot")
+        .add_attribute("epoch", epoch.to_string())
+        .add_event(
+            Event::new("chance_snapshot_invalidated")
+                .add_attribute("epoch", epoch.to_string())
+                .add_attribute("reason", reason)
+                .add_attribute("challenger", info.sender.to_string()),
+        ))
+}
+
+/// Close a MissingLeaf dispute by proving the disputer's leaf is in the snapshot
+/// with at least the disputed weight. Anyone can call, also after the challenge
+/// window has closed.
+pub fn answer_dispute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    epoch: u64,
+    leaf: SnapshotLeaf,
+) -> Result<Response, ContractError> {
+    let snapshot = SNAPSHOTS
+        .may_load(deps.storage, epoch)?
+        .ok_or(ContractError::NoSnapshot)?;
+
+    let disputer = deps.api.addr_validate(&leaf.address)?;
+    let dispute = SNAPSHOT_DISPUTES
+        .may_load(deps.storage, (epoch, &disputer))?
+        .ok_or(ContractError::DisputeNotFound {
+            epoch,
+            disputer: leaf.address.clone(),
+        })?;
+
+    verify_snapshot_leaf(&snapshot, &leaf)?;
+    if leaf_weight(&leaf) < dispute.min_balance {
+        return Err(ContractError::ChallengeRejected {
+            reason: format!(
+                "leaf weight {} is below disputed balance {}",
+                leaf_weight(&leaf),
+                dispute.min_balance
+            ),
+        });
+    }
+    SNAPSHOT_DISPUTES.remove(deps.storage, (epoch, &disputer));
+
+    Ok(Response::new()
+        .add_attribute("action", "answer_dispute")
+        .add_attribute("epoch", epoch.to_string())
+        .add_event(
+            Event::new("chance_snapshot_dispute_answered")
+                .add_attribute("epoch", epoch.to_string())
+                .add_attribute("disputer", disputer.to_string())
+                .add_attribute("answered_by", info.sender.to_string()),
+        ))
+}
+
+/// Drop an unanswered MissingLeaf dispute. Admin only.
+pub fn dismiss_dispute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    epoch: u64,
+    disputer: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {
+            reason: "only admin can dismiss disputes".to_string(),
+        });
+    }
+
+    let disputer_addr = deps.api.addr_validate(&disputer)?;
+    if !SNAPSHOT_DISPUTES.has(deps.storage, (epoch, &disputer_addr)) {
+        return Err(ContractError::DisputeNotFound { epoch, disputer });
+    }
+    SNAPSHOT_DISPUTES.remove(deps.storage, (epoch, &disputer_addr));
+
+    Ok(Response::new()
+        .add_attribute("action", "dismiss_dispute")
+        .add_attribute("epoch", epoch.to_string())
+        .add_event(
+            Event::new("chance_snapshot_dispute_dismissed")
+                .add_attribute("epoch", epoch.to_string())
+                .add_attribute("disputer", disputer_addr.to_string()),
+        ))
+}
+
+/// Invalidate a snapshot and close its open disputes. Admin only.
+pub fn invalidate_snapshot(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    epoch: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {
+            reason: "only admin can invalidate snapshots".to_string(),
+        });
+    }
+
+    let mut snapshot = SNAPSHOTS
+        .may_load(deps.storage, epoch)?
+        .ok_or(ContractError::NoSnapshot)?;
+    if snapshot.invalidated {
+        return Err(ContractError::SnapshotInvalidated { epoch });
+    }
+    snapshot.invalidated = true;
+    SNAPSHOTS.save(deps.storage, epoch, &snapshot)?;
+
+    let disputers: Vec<Addr> = SNAPSHOT_DISPUTES
+        .prefix(epoch)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for disputer in &disputers {
+        SNAPSHOT_DISPUTES.remove(deps.storage, (epoch, disputer));
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "invalidate_snapshot")
+        .add_attribute("epoch", epoch.to_string())
+        .add_attribute("disputes_closed", disputers.len().to_string())
+        .add_event(
+            Event::new("chance_snapshot_invalidated")
+                .add_attribute("epoch", epoch.to_string())
+                .add_attribute("reason", "admin")
+                .add_attribute("challenger", info.sender.to_string()),
+        ))
+}
+
+/// Check that `leaf` is part of the snapshot's merkle tree.
+fn verify_snapshot_leaf(snapshot: &Snapshot, leaf: &SnapshotLeaf) -> Result<(), ContractError> {
+    let leaf_hash = compute_leaf_hash(
+        &leaf.address,
+        leaf.cumulative_start.u128(),
+        leaf.cumulative_end.u128(),
+    );
+    if !verify_merkle_proof(&snapshot.merkle_root, &leaf.merkle_proof, &leaf_hash) {
+        return Err(ContractError::InvalidMerkleProof);
+    }
+    Ok(())
+}
+
+fn leaf_weight(leaf: &SnapshotLeaf) -> Uint128 {
+    leaf.cumulative_end.saturating_sub(leaf.cumulative_start)
+}
+
+/// Lowest csINJ balance `address` held between the previous snapshot and this one,
+/// from the staking hub's balance checkpoints. Whenever the operator built the tree
+/// inside that window, the holder's leaf weight can't be below it.
+pub fn snapshot_min_balance(
+    storage: &dyn Storage,
+    querier: &QuerierWrapper,
+    staking_hub: &Addr,
+    snapshot: &Snapshot,
+    address: &str,
+) -> StdResult<Uint128> {
+    let window_start = SNAPSHOTS
+        .range(
+            storage,
+            None,
+            Some(Bound::exclusive(snapshot.epoch)),
+            Order::Descending,
+        )
+        .next()
+        .transpose()?
+        .map(|(_, previous)| previous.submitted_at.seconds())
+        .unwrap_or(0);
+    let window_end = snapshot.submitted_at.seconds();
+    if window_start >= window_end {
+        return Ok(Uint128::zero());
+    }
+
+    let twab: TwabResponse = querier.query_wasm_smart(
+        staking_hub,
+        &StakingHubQueryMsg::TwabBetween {
+            address: address.to_string(),
+            start: window_start,
+            end: window_end,
+        },
+    )?;
+    Ok(twab.min_balance)
+}
+
 /// Update configuration. Admin only.
 pub fn update_config(
     deps: DepsMut,
@@ -596,11 +1416,13 @@ pub fn update_config(
     params: UpdateConfigParams,
 ) -> Result<Response, ContractError> {
     let UpdateConfigParams {
-        operator,
         staking_hub,
         reveal_deadline_seconds,
         epochs_between_regular,
         epochs_between_big,
+        challenge_period_seconds,
+        regular_prize_tiers,
+        big_prize_tiers,
     } = params;
 
     let mut config = CONFIG.load(deps.storage)?;
@@ -611,12 +1433,44 @@ pub fn update_config(
         });
     }
 
-    if let Some(op) = operator {
-        config.operator = deps.api.addr_validate(&op)?;
+    let timelocked = reveal_deadline_seconds.is_some()
+        || epochs_between_regular.is_some()
+        || epochs_between_big.is_some()
+        || challenge_period_seconds.is_some()
+        || regular_prize_tiers.is_some()
+        || big_prize_tiers.is_some();
+    if timelocked && config.timelock_seconds > 0 {
+        return Err(ContractError::TimelockRequired);
     }
+
     if let Some(hub) = staking_hub {
         config.staking_hub = deps.api.addr_validate(&hub)?;
     }
+    apply_config_change(
+        &mut config,
+        reveal_deadline_seconds,
+        epochs_between_regular,
+        epochs_between_big,
+        challenge_period_seconds,
+        regular_prize_tiers,
+        big_prize_tiers,
+    )?;
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attribute("action", "update_config"))
+}
+
+/// Apply the draw-timing and prize table part of a config update.
+fn apply_config_change(
+    config: &mut DistributorConfig,
+    reveal_deadline_seconds: Option<u64>,
+    epochs_between_regular: Option<u64>,
+    epochs_between_big: Option<u64>,
+    challenge_period_seconds: Option<u64>,
+    regular_prize_tiers: Option<Vec<PrizeTier>>,
+    big_prize_tiers: Option<Vec<PrizeTier>>,
+) -> Result<(), ContractError> {
     if let Some(deadline) = reveal_deadline_seconds {
         // V2-M-03 FIX: Validate reveal deadline bounds
         validate_reveal_deadline(deadline)?;
@@ -628,8 +1482,346 @@ pub fn update_config(
     if let Some(gap) = epochs_between_big {
         config.epochs_between_big = gap;
     }
+    if let Some(period) = challenge_period_seconds {
+        config.challenge_period_seconds = period;
+    }
+    if let Some(tiers) = regular_prize_tiers {
+        validate_prize_tiers(&tiers)?;
+        config.regular_prize_tiers = tiers;
+    }
+    if let Some(tiers) = big_prize_tiers {
+        validate_prize_tiers(&tiers)?;
+        config.big_prize_tiers = tiers;
+    }
+    Ok(())
+}
+
+/// Reject `operation` while it is paused.
+pub fn assert_not_paused(
+    storage: &dyn Storage,
+    operation: &PausableOperation,
+) -> Result<(), ContractError> {
+    let paused = PAUSED_OPERATIONS.may_load(storage)?.unwrap_or_default();
+    if paused.contains(operation) {
+        return Err(ContractError::OperationPaused {
+            operation: format!("{:?}", operation),
+        });
+    }
+    Ok(())
+}
+
+/// Set or clear the guardian. Admin only.
+pub fn update_guardian(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    guardian: Option<String>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {
+            reason: "only admin can update guardian".to_string(),
+        });
+    }
 
+    config.guardian = guardian.map(|g| deps.api.addr_validate(&g)).transpose()?;
     CONFIG.save(deps.storage, &config)?;
 
-    Ok(Response::new().add_attribute("action", "update_config"))
+    Ok(Response::new()
+        .add_attribute("action", "update_guardian")
+        .add_attribute(
+            "guardian",
+            config.guardian.map(|g| g.to_string()).unwrap_or_default(),
+        ))
+}
+
+/// Pause operations during an incident. Guardian or admin.
+pub fn pause(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    operations: Vec<PausableOperation>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin && Some(&info.sender) != config.guardian.as_ref() {
+        return Err(ContractError::Unauthorized {
+            reason: "only guardian or admin can pause".to_string(),
+        });
+    }
+
+    let mut paused = PAUSED_OPERATIONS
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    for operation in &operations {
+        if !paused.contains(operation) {
+            paused.push(operation.clone());
+        }
+    }
+    PAUSED_OPERATIONS.save(deps.storage, &paused)?;
+
+    Ok(Response::new().add_attribute("action", "pause").add_event(
+        Event::new("chance_operations_paused")
+            .add_attribute("operations", format!("{:?}", operations))
+            .add_attribute("paused_by", info.sender.to_string()),
+    ))
+}
+
+/// Resume paused operations. Admin only.
+pub fn unpause(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    operations: Vec<PausableOperation>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {
+            reason: "only admin can unpause".to_string(),
+        });
+    }
+
+    let mut paused = PAUSED_OPERATIONS
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    paused.retain(|op| !operations.contains(op));
+    PAUSED_OPERATIONS.save(deps.storage, &paused)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "unpause")
+        .add_event(
+            Event::new("chance_operations_unpaused")
+                .add_attribute("operations", format!("{:?}", operations))
+                .add_attribute("unpaused_by", info.sender.to_string()),
+        ))
+}
+
+/// Propose handing `role` over to `new_address`. The handover only takes effect
+/// once the new address accepts it. Replaces any earlier proposal for the role.
+/// Admin only.
+pub fn propose_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    role: Role,
+    new_address: String,
+    expires_in_seconds: Option<u64>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {
+            reason: "only admin can propose a role transfer".to_string(),
+        });
+    }
+
+    let pending = PendingTransfer {
+        new_address: deps.api.addr_validate(&new_address)?,
+        expires_at: expires_in_seconds.map(|secs| env.block.time.plus_seconds(secs)),
+    };
+    match role {
+        Role::Admin => config.pending_admin = Some(pending.clone()),
+        Role::Operator => config.pending_operator = Some(pending.clone()),
+    }
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "propose_transfer")
+        .add_attribute("role", format!("{:?}", role))
+        .add_attribute("new_address", pending.new_address.to_string())
+        .add_attribute(
+            "expires_at",
+            pending
+                .expires_at
+                .map(|t| t.seconds())
+                .unwrap_or_default()
+                .to_string(),
+        ))
+}
+
+/// Accept a proposed role transfer. Only the proposed address can accept,
+/// and only before the proposal expires.
+pub fn accept_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    role: Role,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    let pending = match role {
+        Role::Admin => config.pending_admin.take(),
+        Role::Operator => config.pending_operator.take(),
+    }
+    .ok_or(ContractError::NoPendingTransfer {
+        role: format!("{:?}", role),
+    })?;
+
+    if info.sender != pending.new_address {
+        return Err(ContractError::Unauthorized {
+            reason: "only the proposed address can accept the transfer".to_string(),
+        });
+    }
+    if pending.is_expired(env.block.time) {
+        return Err(ContractError::TransferExpired {
+            role: format!("{:?}", role),
+        });
+    }
+
+    let previous = match role {
+        Role::Admin => std::mem::replace(&mut config.admin, pending.new_address),
+        Role::Operator => std::mem::replace(&mut config.operator, pending.new_address),
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "accept_transfer")
+        .add_attribute("role", format!("{:?}", role))
+        .add_attribute("previous", previous.to_string())
+        .add_attribute("new_address", info.sender.to_string()))
+}
+
+/// Withdraw a pending role transfer. Admin only.
+pub fn cancel_proposal(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    role: Role,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {
+            reason: "only admin can cancel a role transfer".to_string(),
+        });
+    }
+
+    let cancelled = match role {
+        Role::Admin => config.pending_admin.take(),
+        Role::Operator => config.pending_operator.take(),
+    };
+    if cancelled.is_none() {
+        return Err(ContractError::NoPendingTransfer {
+            role: format!("{:?}", role),
+        });
+    }
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "cancel_proposal")
+        .add_attribute("role", format!("{:?}", role)))
+}
+
+/// Queue a parameter change. It becomes executable once the timelock has passed.
+/// Admin only.
+pub fn schedule_change(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    change: ParamChange,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {
+            reason: "only admin can schedule changes".to_string(),
+        });
+    }
+
+    let id = NEXT_CHANGE_ID.may_load(deps.storage)?.unwrap_or(0);
+    let pending = PendingChange {
+        id,
+        change,
+        scheduled_at: env.block.time,
+        executable_at: env.block.time.plus_seconds(config.timelock_seconds),
+    };
+    PENDING_CHANGES.save(deps.storage, id, &pending)?;
+    NEXT_CHANGE_ID.save(deps.storage, &(id + 1))?;
+
+    Ok(Response::new()
+        .add_attribute("action", "schedule_change")
+        .add_attribute("change_id", id.to_string())
+        .add_event(
+            Event::new("chance_change_scheduled")
+                .add_attribute("change_id", id.to_string())
+                .add_attribute("change", to_json_string(&pending.change)?)
+                .add_attribute("executable_at", pending.executable_at.seconds().to_string()),
+        ))
+}
+
+/// Apply a queued change once its timelock has passed. Anyone can call.
+pub fn execute_change(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let pending = PENDING_CHANGES
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::ChangeNotFound { id })?;
+
+    if env.block.time < pending.executable_at {
+        return Err(ContractError::ChangeNotReady {
+            id,
+            executable_at: pending.executable_at.seconds(),
+        });
+    }
+    PENDING_CHANGES.remove(deps.storage, id);
+
+    let mut config = CONFIG.load(deps.storage)?;
+    match &pending.change {
+        ParamChange::UpdateConfig {
+            reveal_deadline_seconds,
+            epochs_between_regular,
+            epochs_between_big,
+            challenge_period_seconds,
+            regular_prize_tiers,
+            big_prize_tiers,
+        } => apply_config_change(
+            &mut config,
+            *reveal_deadline_seconds,
+            *epochs_between_regular,
+            *epochs_between_big,
+            *challenge_period_seconds,
+            regular_prize_tiers.clone(),
+            big_prize_tiers.clone(),
+        )?,
+        ParamChange::SetTimelock { seconds } => config.timelock_seconds = *seconds,
+    }
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "execute_change")
+        .add_attribute("change_id", id.to_string())
+        .add_event(
+            Event::new("chance_change_executed")
+                .add_attribute("change_id", id.to_string())
+                .add_attribute("change", to_json_string(&pending.change)?),
+        ))
+}
+
+/// Drop a queued change. Admin only.
+pub fn cancel_change(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {
+            reason: "only admin can cancel changes".to_string(),
+        });
+    }
+    if !PENDING_CHANGES.has(deps.storage, id) {
+        return Err(ContractError::ChangeNotFound { id });
+    }
+    PENDING_CHANGES.remove(deps.storage, id);
+
+    Ok(Response::new()
+        .add_attribute("action", "cancel_change")
+        .add_attribute("change_id", id.to_string()))
 }
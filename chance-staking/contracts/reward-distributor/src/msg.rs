@@ -1,8 +1,11 @@
-use chance_staking_common::types::DrawType;
+use chance_staking_common::types::{DrawType, Role};
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Uint128;
+use cosmwasm_std::{Addr, Coin, Decimal, Uint128};
 
-use crate::state::{DistributorConfig, Draw, DrawStateInfo, Snapshot};
+use crate::state::{
+    DistributorConfig, Draw, DrawStateInfo, PausableOperation, PendingChange, PrizeTier, Snapshot,
+    SnapshotDispute,
+};
 
 // M-03 FIX: Add MigrateMsg for contract upgradability
 #[cw_serde]
@@ -16,6 +19,15 @@ pub struct InstantiateMsg {
     pub reveal_deadline_seconds: u64,
     pub epochs_between_regular: u64,
     pub epochs_between_big: u64,
+    /// How long a snapshot can be challenged before draws may use it
+    /// (default 0 = no challenge window)
+    pub challenge_period_seconds: Option<u64>,
+    /// Delay for scheduled parameter changes (default 0 = no timelock)
+    pub timelock_seconds: Option<u64>,
+    /// Prize table of regular draws (default: one prize of the whole pool)
+    pub regular_prize_tiers: Option<Vec<PrizeTier>>,
+    /// Prize table of big draws (default: one prize of the whole pool)
+    pub big_prize_tiers: Option<Vec<PrizeTier>>,
 }
 
 #[cw_serde]
@@ -39,30 +51,149 @@ pub enum ExecuteMsg {
         target_drand_round: u64,
         epoch: u64,
     },
-    /// Reveal a committed draw with the winner. Operator only.
+    /// Reveal a committed draw with its winners. Operator only.
     RevealDraw {
         draw_id: u64,
         /// The pre-image secret (hex-encoded)
         operator_secret_hex: String,
-        /// Winner's bech32 address
-        winner_address: String,
-        /// Winner's cumulative_start in the snapshot
-        winner_cumulative_start: Uint128,
-        /// Winner's cumulative_end in the snapshot
-        winner_cumulative_end: Uint128,
-        /// Merkle proof (list of hex-encoded sibling hashes)
-        merkle_proof: Vec<String>,
+        /// One snapshot leaf per prize, in prize table order. Each leaf must hold
+        /// the winning ticket of its prize. A leaf of an ineligible holder is
+        /// followed by the leaf holding the prize's redrawn ticket.
+        winners: Vec<SnapshotLeaf>,
     },
     /// Expire a draw that wasn't revealed in time. Anyone can call.
     ExpireDraw { draw_id: u64 },
-    /// Update configuration. Admin only.
+    /// Escrow non-INJ native coins as a bonus prize. Anyone can call. Every denom must
+    /// be on the bonus denom list and meet its minimum amount. The coins are paid to
+    /// the winner of the first `draw_type` draw committed on a snapshot epoch
+    /// >= `target_epoch` (default: the next one), and roll over if that draw expires.
+    AddBonusPrize {
+        draw_type: DrawType,
+        target_epoch: Option<u64>,
+    },
+    /// Claim a bonus prize whose payout failed when its draw was revealed.
+    /// Winner only.
+    ClaimBonusPrize { denom: String },
+    /// Accept denoms as bonus prizes, each with the least amount one AddBonusPrize
+    /// must send (the coin's amount), or stop accepting them. Bonus prizes already
+    /// escrowed are unaffected. Admin only.
+    UpdateBonusDenoms { add: Vec<Coin>, remove: Vec<String> },
+    /// Submit a fraud proof against a snapshot during its challenge window.
+    /// Anyone can call.
+    ChallengeSnapshot {
+        epoch: u64,
+        challenge: SnapshotChallenge,
+    },
+    /// Close a MissingLeaf dispute by showing the disputer's leaf. Anyone can call.
+    AnswerDispute { epoch: u64, leaf: SnapshotLeaf },
+    /// Drop a MissingLeaf dispute that was never answered, unblocking draws on the
+    /// snapshot. Admin only.
+    DismissDispute { epoch: u64, disputer: String },
+    /// Invalidate a snapshot, e.g. after an unanswered dispute turned out to be
+    /// valid. Closes the snapshot's open disputes. Admin only.
+    InvalidateSnapshot { epoch: u64 },
+    /// Update configuration. Admin only. While a timelock is set, reveal_deadline_seconds,
+    /// epochs_between_*, challenge_period_seconds and the prize tables must go through
+    /// ScheduleChange instead. The operator only changes through ProposeOperator and
+    /// AcceptOperator.
     UpdateConfig {
-        operator: Option<String>,
         staking_hub: Option<String>,
         reveal_deadline_seconds: Option<u64>,
         epochs_between_regular: Option<u64>,
         epochs_between_big: Option<u64>,
+        challenge_period_seconds: Option<u64>,
+        regular_prize_tiers: Option<Vec<PrizeTier>>,
+        big_prize_tiers: Option<Vec<PrizeTier>>,
     },
+    /// Propose a new admin. Takes effect once the new admin sends AcceptAdmin.
+    /// Admin only.
+    ProposeAdmin {
+        new_admin: String,
+        /// Seconds after which the proposal can no longer be accepted
+        expires_in_seconds: Option<u64>,
+    },
+    /// Accept a pending admin proposal. Proposed admin only.
+    AcceptAdmin {},
+    /// Propose a new operator. Takes effect once the new operator sends
+    /// AcceptOperator. Admin only.
+    ProposeOperator {
+        new_operator: String,
+        /// Seconds after which the proposal can no longer be accepted
+        expires_in_seconds: Option<u64>,
+    },
+    /// Accept a pending operator proposal. Proposed operator only.
+    AcceptOperator {},
+    /// Withdraw a pending admin or operator proposal. Admin only.
+    CancelProposal { role: Role },
+    /// Queue a parameter change behind the timelock. Admin only.
+    ScheduleChange { change: ParamChange },
+    /// Apply a queued change once its timelock has passed. Anyone can call.
+    ExecuteChange { id: u64 },
+    /// Drop a queued change. Admin only.
+    CancelChange { id: u64 },
+    /// Set or clear the guardian. Admin only.
+    UpdateGuardian { guardian: Option<String> },
+    /// Pause the given operations. Guardian or admin.
+    Pause { operations: Vec<PausableOperation> },
+    /// Resume the given operations. Admin only.
+    Unpause { operations: Vec<PausableOperation> },
+}
+
+impl ExecuteMsg {
+    /// The pausable operation this message belongs to, if any.
+    pub fn pausable_operation(&self) -> Option<PausableOperation> {
+        match self {
+            ExecuteMsg::CommitDraw { .. } | ExecuteMsg::AddBonusPrize { .. } => {
+                Some(PausableOperation::CommitDraw)
+            }
+            ExecuteMsg::RevealDraw { .. } => Some(PausableOperation::RevealDraw),
+            _ => None,
+        }
+    }
+}
+
+/// A parameter change that can be queued with ScheduleChange.
+#[cw_serde]
+pub enum ParamChange {
+    /// Same semantics as the matching UpdateConfig fields
+    UpdateConfig {
+        reveal_deadline_seconds: Option<u64>,
+        epochs_between_regular: Option<u64>,
+        epochs_between_big: Option<u64>,
+        challenge_period_seconds: Option<u64>,
+        regular_prize_tiers: Option<Vec<PrizeTier>>,
+        big_prize_tiers: Option<Vec<PrizeTier>>,
+    },
+    /// Change the timelock delay itself
+    SetTimelock { seconds: u64 },
+}
+
+/// A fraud proof against a snapshot's merkle tree.
+#[cw_serde]
+pub enum SnapshotChallenge {
+    /// Two distinct leaves of the tree whose ticket ranges overlap.
+    /// Invalidates the snapshot.
+    OverlappingLeaves {
+        first: SnapshotLeaf,
+        second: SnapshotLeaf,
+    },
+    /// A leaf weighted below the lowest csINJ balance its holder kept between the
+    /// previous snapshot and this one. Invalidates the snapshot.
+    UnderweightLeaf { leaf: SnapshotLeaf },
+    /// The sender held csINJ throughout that window, meets min_epochs_regular and isn't
+    /// excluded, but has no leaf. Opens a dispute that blocks draws on the snapshot
+    /// until it is answered with the sender's leaf.
+    MissingLeaf {},
+}
+
+/// A leaf of a snapshot tree together with its inclusion proof.
+#[cw_serde]
+pub struct SnapshotLeaf {
+    pub address: String,
+    pub cumulative_start: Uint128,
+    pub cumulative_end: Uint128,
+    /// Merkle proof (list of hex-encoded sibling hashes)
+    pub merkle_proof: Vec<String>,
 }
 
 /// Query message for the drand oracle contract.
@@ -75,7 +206,14 @@ pub enum OracleQueryMsg {
 #[cw_serde]
 pub enum StakingHubQueryMsg {
     Config {},
-    StakerInfo { address: String },
+    StakerInfo {
+        address: String,
+    },
+    TwabBetween {
+        address: String,
+        start: u64,
+        end: u64,
+    },
 }
 
 /// Partial staking hub config — only the fields we need.
@@ -90,6 +228,15 @@ pub struct StakingHubConfigResponse {
 pub struct StakerInfoResponse {
     pub address: String,
     pub stake_epoch: Option<u64>,
+    /// On the hub's snapshot exclusion list
+    pub excluded: bool,
+}
+
+/// Partial TWAB response from staking hub.
+#[cw_serde]
+pub struct TwabResponse {
+    pub address: String,
+    pub min_balance: Uint128,
 }
 
 #[cw_serde]
@@ -108,6 +255,19 @@ pub enum QueryMsg {
     },
     #[returns(PoolBalancesResponse)]
     PoolBalances {},
+    /// Bonus prizes not yet attached to a draw, by target epoch
+    #[returns(Vec<BonusPrizeEntry>)]
+    BonusPrizes {
+        draw_type: DrawType,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Denoms accepted as bonus prizes, with their minimum amounts
+    #[returns(Vec<Coin>)]
+    BonusDenoms {},
+    /// Bonus prizes `address` won but whose payout failed, waiting for ClaimBonusPrize
+    #[returns(Vec<Coin>)]
+    UnclaimedBonusPrizes { address: String },
     #[returns(UserWinsResponse)]
     UserWins {
         address: String,
@@ -130,6 +290,30 @@ pub enum QueryMsg {
     },
     #[returns(Option<Snapshot>)]
     Snapshot { epoch: u64 },
+    #[returns(PauseStateResponse)]
+    PauseState {},
+    #[returns(Vec<PendingChange>)]
+    PendingChanges {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Open MissingLeaf disputes against a snapshot
+    #[returns(Vec<SnapshotDispute>)]
+    SnapshotDisputes {
+        epoch: u64,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Estimated prize per epoch for `address`, from its weight in the latest
+    /// snapshot, the current pool balances and the draw cadence
+    #[returns(ExpectedValueResponse)]
+    ExpectedValue { address: String },
+}
+
+#[cw_serde]
+pub struct PauseStateResponse {
+    pub guardian: Option<Addr>,
+    pub paused: Vec<PausableOperation>,
 }
 
 #[cw_serde]
@@ -141,11 +325,42 @@ pub struct DrawHistoryResponse {
 pub struct PoolBalancesResponse {
     pub regular_pool: Uint128,
     pub big_pool: Uint128,
+    /// Bonus prizes waiting for a regular draw, summed per denom
+    pub regular_bonus: Vec<Coin>,
+    /// Bonus prizes waiting for a big draw, summed per denom
+    pub big_bonus: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct BonusPrizeEntry {
+    pub target_epoch: u64,
+    pub funds: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct ExpectedValueResponse {
+    pub address: String,
+    /// Epoch of the snapshot the estimate uses (None without a valid snapshot)
+    pub snapshot_epoch: Option<u64>,
+    /// Lowest csINJ balance the address held over the snapshot window. Its leaf
+    /// carries at least this much weight, so the estimate is conservative.
+    pub weight: Uint128,
+    pub total_weight: Uint128,
+    /// Chance of winning at least one prize of a regular draw (zero if not eligible)
+    pub regular_win_probability: Decimal,
+    /// Chance of winning at least one prize of a big draw (zero if not eligible)
+    pub big_win_probability: Decimal,
+    /// Regular pool x share of the snapshot weight, spread over epochs_between_regular
+    pub regular_expected_per_epoch: Uint128,
+    /// Big pool x share of the snapshot weight, spread over epochs_between_big
+    pub big_expected_per_epoch: Uint128,
+    pub expected_per_epoch: Uint128,
 }
 
 #[cw_serde]
 pub struct UserWinsResponse {
     pub address: String,
+    /// Number of draws won; a draw counts once even if it paid several prizes
     pub total_wins: u32,
     pub total_won_amount: Uint128,
     pub draw_ids: Vec<u64>,
@@ -163,17 +378,16 @@ pub struct CommitDrawParams {
 pub struct RevealDrawParams {
     pub draw_id: u64,
     pub operator_secret_hex: String,
-    pub winner_address: String,
-    pub winner_cumulative_start: Uint128,
-    pub winner_cumulative_end: Uint128,
-    pub merkle_proof: Vec<String>,
+    pub winners: Vec<SnapshotLeaf>,
 }
 
 /// Parameters for update_config execute call (avoids too_many_arguments).
 pub struct UpdateConfigParams {
-    pub operator: Option<String>,
     pub staking_hub: Option<String>,
     pub reveal_deadline_seconds: Option<u64>,
     pub epochs_between_regular: Option<u64>,
     pub epochs_between_big: Option<u64>,
+    pub challenge_period_seconds: Option<u64>,
+    pub regular_prize_tiers: Option<Vec<PrizeTier>>,
+    pub big_prize_tiers: Option<Vec<PrizeTier>>,
 }
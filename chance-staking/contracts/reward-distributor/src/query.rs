@@ -1,10 +1,18 @@
 use chance_staking_common::merkle::{compute_leaf_hash, verify_merkle_proof};
-use cosmwasm_std::{to_json_binary, Binary, Deps, Order, StdResult, Uint128};
+use chance_staking_common::types::DrawType;
+use cosmwasm_std::{to_json_binary, Binary, Coin, Decimal, Deps, Order, StdResult, Uint128};
 use cw_storage_plus::Bound;
 
-use crate::msg::{DrawHistoryResponse, PoolBalancesResponse, UserWinsResponse};
+use crate::execute::{merge_coins, pool_name, snapshot_min_balance};
+use crate::msg::{
+    BonusPrizeEntry, DrawHistoryResponse, ExpectedValueResponse, PauseStateResponse,
+    PoolBalancesResponse, StakerInfoResponse, StakingHubConfigResponse, StakingHubQueryMsg,
+    UserWinsResponse,
+};
 use crate::state::{
-    CONFIG, DRAWS, DRAW_STATE, SNAPSHOTS, USER_TOTAL_WON, USER_WINS, USER_WIN_COUNT,
+    PrizeTier, BONUS_DENOMS, BONUS_PRIZES, CONFIG, DRAWS, DRAW_STATE, LATEST_SNAPSHOT_EPOCH,
+    PAUSED_OPERATIONS, PENDING_CHANGES, SNAPSHOTS, SNAPSHOT_DISPUTES, UNCLAIMED_BONUS_PRIZES,
+    USER_TOTAL_WON, USER_WINS, USER_WIN_COUNT,
 };
 
 pub fn query_config(deps: Deps) -> StdResult<Binary> {
@@ -42,12 +50,65 @@ pub fn query_draw_history(
 
 pub fn query_pool_balances(deps: Deps) -> StdResult<Binary> {
     let state = DRAW_STATE.load(deps.storage)?;
+    let pending_bonus = |pool: &str| -> StdResult<Vec<Coin>> {
+        let mut total = vec![];
+        for entry in BONUS_PRIZES
+            .prefix(pool)
+            .range(deps.storage, None, None, Order::Ascending)
+        {
+            merge_coins(&mut total, &entry?.1);
+        }
+        Ok(total)
+    };
     to_json_binary(&PoolBalancesResponse {
         regular_pool: state.regular_pool_balance,
         big_pool: state.big_pool_balance,
+        regular_bonus: pending_bonus(pool_name(&DrawType::Regular))?,
+        big_bonus: pending_bonus(pool_name(&DrawType::Big))?,
     })
 }
 
+pub fn query_bonus_prizes(
+    deps: Deps,
+    draw_type: DrawType,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(20).min(100) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let entries: Vec<BonusPrizeEntry> = BONUS_PRIZES
+        .prefix(pool_name(&draw_type))
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .filter_map(|r| r.ok())
+        .map(|(target_epoch, funds)| BonusPrizeEntry {
+            target_epoch,
+            funds,
+        })
+        .collect();
+
+    to_json_binary(&entries)
+}
+
+pub fn query_bonus_denoms(deps: Deps) -> StdResult<Binary> {
+    let denoms: Vec<Coin> = BONUS_DENOMS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|r| r.map(|(denom, amount)| Coin { denom, amount }))
+        .collect::<StdResult<_>>()?;
+    to_json_binary(&denoms)
+}
+
+pub fn query_unclaimed_bonus_prizes(deps: Deps, address: String) -> StdResult<Binary> {
+    let addr = deps.api.addr_validate(&address)?;
+    let prizes: Vec<Coin> = UNCLAIMED_BONUS_PRIZES
+        .prefix(&addr)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|r| r.map(|(denom, amount)| Coin { denom, amount }))
+        .collect::<StdResult<_>>()?;
+    to_json_binary(&prizes)
+}
+
 pub fn query_user_wins(
     deps: Deps,
     address: String,
@@ -121,3 +182,129 @@ pub fn query_snapshot(deps: Deps, epoch: u64) -> StdResult<Binary> {
     let snapshot = SNAPSHOTS.may_load(deps.storage, epoch)?;
     to_json_binary(&snapshot)
 }
+
+pub fn query_pause_state(deps: Deps) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let paused = PAUSED_OPERATIONS
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    to_json_binary(&PauseStateResponse {
+        guardian: config.guardian,
+        paused,
+    })
+}
+
+pub fn query_pending_changes(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(20).min(100) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let changes: Vec<_> = PENDING_CHANGES
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .filter_map(|r| r.ok())
+        .map(|(_, change)| change)
+        .collect();
+
+    to_json_binary(&changes)
+}
+
+pub fn query_expected_value(deps: Deps, address: String) -> StdResult<Binary> {
+    let addr = deps.api.addr_validate(&address)?;
+    let config = CONFIG.load(deps.storage)?;
+    let state = DRAW_STATE.load(deps.storage)?;
+
+    let snapshot = match LATEST_SNAPSHOT_EPOCH.may_load(deps.storage)? {
+        Some(epoch) => SNAPSHOTS
+            .may_load(deps.storage, epoch)?
+            .filter(|s| !s.invalidated && !s.total_weight.is_zero()),
+        None => None,
+    };
+
+    let mut weight = Uint128::zero();
+    let mut regular_odds = Decimal::zero();
+    let mut big_odds = Decimal::zero();
+    if let Some(snapshot) = &snapshot {
+        let info: StakerInfoResponse = deps.querier.query_wasm_smart(
+            &config.staking_hub,
+            &StakingHubQueryMsg::StakerInfo {
+                address: addr.to_string(),
+            },
+        )?;
+        if let (false, Some(stake_epoch)) = (info.excluded, info.stake_epoch) {
+            let hub_config: StakingHubConfigResponse = deps
+                .querier
+                .query_wasm_smart(&config.staking_hub, &StakingHubQueryMsg::Config {})?;
+            let epochs_staked = snapshot.epoch.saturating_sub(stake_epoch);
+
+            weight = snapshot_min_balance(
+                deps.storage,
+                &deps.querier,
+                &config.staking_hub,
+                snapshot,
+                addr.as_str(),
+            )?
+            .min(snapshot.total_weight);
+            let odds = Decimal::from_ratio(weight, snapshot.total_weight);
+            if epochs_staked >= hub_config.min_epochs_regular {
+                regular_odds = odds;
+            }
+            if epochs_staked >= hub_config.min_epochs_big {
+                big_odds = odds;
+            }
+        }
+    }
+
+    // Each prize is an independent ticket, so the shares of the pool add up to
+    // the pool times the holder's share of the weight whatever the prize table
+    let regular_expected_per_epoch = state.regular_pool_balance.mul_floor(regular_odds)
+        / Uint128::from(config.epochs_between_regular.max(1));
+    let big_expected_per_epoch = state.big_pool_balance.mul_floor(big_odds)
+        / Uint128::from(config.epochs_between_big.max(1));
+    let regular_win_probability = win_any_probability(regular_odds, &config.regular_prize_tiers);
+    let big_win_probability = win_any_probability(big_odds, &config.big_prize_tiers);
+
+    to_json_binary(&ExpectedValueResponse {
+        address,
+        snapshot_epoch: snapshot.as_ref().map(|s| s.epoch),
+        weight,
+        total_weight: snapshot.map(|s| s.total_weight).unwrap_or_default(),
+        regular_win_probability,
+        big_win_probability,
+        regular_expected_per_epoch,
+        big_expected_per_epoch,
+        expected_per_epoch: regular_expected_per_epoch + big_expected_per_epoch,
+    })
+}
+
+/// Chance of holding at least one of the table's winning tickets: 1 - (1 - odds)^prizes.
+fn win_any_probability(odds: Decimal, tiers: &[PrizeTier]) -> Decimal {
+    let num_prizes: u32 = tiers.iter().map(|t| t.count).sum();
+    Decimal::one() - (Decimal::one() - odds).pow(num_prizes)
+}
+
+pub fn query_snapshot_disputes(
+    deps: Deps,
+    epoch: u64,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(20).min(100) as usize;
+    let start_addr = start_after
+        .map(|a| deps.api.addr_validate(&a))
+        .transpose()?;
+    let start = start_addr.as_ref().map(Bound::exclusive);
+
+    let disputes: Vec<_> = SNAPSHOT_DISPUTES
+        .prefix(epoch)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .filter_map(|r| r.ok())
+        .map(|(_, dispute)| dispute)
+        .collect();
+
+    to_json_binary(&disputes)
+}
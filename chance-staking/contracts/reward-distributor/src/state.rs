@@ -1,8 +1,10 @@
-use chance_staking_common::types::{DrawStatus, DrawType};
+use chance_staking_common::types::{DrawStatus, DrawType, PendingTransfer};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Timestamp, Uint128};
+use cosmwasm_std::{Addr, Coin, Timestamp, Uint128};
 use cw_storage_plus::{Item, Map};
 
+use crate::msg::ParamChange;
+
 pub const CONFIG: Item<DistributorConfig> = Item::new("config");
 pub const DRAW_STATE: Item<DrawStateInfo> = Item::new("draw_state");
 pub const DRAWS: Map<u64, Draw> = Map::new("draws");
@@ -16,11 +18,35 @@ pub const LATEST_SNAPSHOT_EPOCH: Item<u64> = Item::new("latest_snapshot_epoch");
 pub const USER_WINS: Map<(&Addr, u64), ()> = Map::new("user_wins_v2");
 pub const USER_WIN_COUNT: Map<&Addr, u32> = Map::new("user_win_count");
 pub const USER_TOTAL_WON: Map<&Addr, Uint128> = Map::new("user_total_won");
+/// Parameter changes queued behind the timelock, by id.
+pub const PENDING_CHANGES: Map<u64, PendingChange> = Map::new("pending_changes");
+pub const NEXT_CHANGE_ID: Item<u64> = Item::new("next_change_id");
+/// Open MissingLeaf disputes, keyed by (epoch, disputer).
+pub const SNAPSHOT_DISPUTES: Map<(u64, &Addr), SnapshotDispute> = Map::new("snapshot_disputes");
+/// Bonus prizes waiting for a draw, keyed by (pool, target epoch). They attach to the
+/// first draw of that pool committed on a snapshot epoch >= the target epoch.
+/// Coins are merged per denom and kept sorted by denom.
+pub const BONUS_PRIZES: Map<(&str, u64), Vec<Coin>> = Map::new("bonus_prizes");
+/// Denoms accepted as bonus prizes, with the least amount of each one AddBonusPrize
+/// must send. Set by the admin, so dust and tokens that can't be paid out stay out
+/// of the bonus buckets.
+pub const BONUS_DENOMS: Map<&str, Uint128> = Map::new("bonus_denoms");
+/// Bonus prizes whose payout failed when their draw was revealed, keyed by
+/// (winner, denom). The winner pulls them with ClaimBonusPrize.
+pub const UNCLAIMED_BONUS_PRIZES: Map<(&Addr, &str), Uint128> = Map::new("unclaimed_bonus_prizes");
+/// Operations currently halted by the guardian or admin.
+pub const PAUSED_OPERATIONS: Item<Vec<PausableOperation>> = Item::new("paused_operations");
 
 #[cw_serde]
 pub struct DistributorConfig {
     pub admin: Addr,
     pub operator: Addr,
+    /// Can pause operations during an incident. Only the admin can unpause.
+    pub guardian: Option<Addr>,
+    /// Proposed admin, waiting for AcceptAdmin
+    pub pending_admin: Option<PendingTransfer>,
+    /// Proposed operator, waiting for AcceptOperator
+    pub pending_operator: Option<PendingTransfer>,
     pub staking_hub: Addr,
     pub drand_oracle: Addr,
     /// How long the operator has to reveal after committing (seconds)
@@ -29,6 +55,50 @@ pub struct DistributorConfig {
     pub epochs_between_regular: u64,
     /// Minimum epochs between big draws
     pub epochs_between_big: u64,
+    /// How long after submission a snapshot can be challenged (0 = no challenge window)
+    #[serde(default)]
+    pub challenge_period_seconds: u64,
+    /// Delay between scheduling and executing a parameter change (0 = no timelock)
+    #[serde(default)]
+    pub timelock_seconds: u64,
+    /// Prize table of regular draws
+    #[serde(default = "single_prize_tier")]
+    pub regular_prize_tiers: Vec<PrizeTier>,
+    /// Prize table of big draws
+    #[serde(default = "single_prize_tier")]
+    pub big_prize_tiers: Vec<PrizeTier>,
+}
+
+// Configs and draws stored before prize tables existed paid a single prize
+fn single_prize_tier() -> Vec<PrizeTier> {
+    crate::execute::single_prize_tier()
+}
+
+/// One tier of a prize table: `count` prizes of `share_bps` of the draw's reward each.
+/// The shares of all prizes in a table add up to 10000 bps.
+#[cw_serde]
+pub struct PrizeTier {
+    pub count: u32,
+    pub share_bps: u16,
+}
+
+/// A prize paid out by a revealed draw.
+#[cw_serde]
+pub struct PrizeWinner {
+    /// Index of the tier in the draw's prize table
+    pub tier: u32,
+    pub address: Addr,
+    pub amount: Uint128,
+    pub winning_ticket: Uint128,
+}
+
+/// Operations the guardian can pause independently. Pool funding and
+/// expiring overdue draws stay available.
+#[cw_serde]
+pub enum PausableOperation {
+    /// CommitDraw and AddBonusPrize. Fraud proofs (ChallengeSnapshot) are never paused.
+    CommitDraw,
+    RevealDraw,
 }
 
 #[cw_serde]
@@ -54,7 +124,9 @@ pub struct Draw {
     pub drand_randomness: Option<Vec<u8>>,
     pub operator_secret: Option<Vec<u8>>,
     pub final_randomness: Option<Vec<u8>>,
+    /// Winner of the first prize
     pub winner: Option<Addr>,
+    /// Total INJ reward, split across the prize table
     pub reward_amount: Uint128,
     pub created_at: Timestamp,
     pub revealed_at: Option<Timestamp>,
@@ -62,6 +134,25 @@ pub struct Draw {
     /// Merkle root used for this draw (from snapshot)
     pub merkle_root: Option<String>,
     pub total_weight: Option<Uint128>,
+    /// Non-INJ bonus prizes paid to the first prize winner alongside the INJ reward,
+    /// sorted by denom
+    #[serde(default)]
+    pub bonus_prizes: Vec<Coin>,
+    /// Prize table, fixed at commit time
+    #[serde(default = "single_prize_tier")]
+    pub prize_tiers: Vec<PrizeTier>,
+    /// Every prize of a revealed draw, tier by tier
+    #[serde(default)]
+    pub winners: Vec<PrizeWinner>,
+}
+
+/// A single-denom bonus payout of a revealed draw, carried as the payload of its
+/// submessage so a failed payout can be recorded for the winner.
+#[cw_serde]
+pub struct BonusPayout {
+    pub draw_id: u64,
+    pub winner: Addr,
+    pub coin: Coin,
 }
 
 #[cw_serde]
@@ -71,6 +162,22 @@ pub struct Snapshot {
     pub total_weight: Uint128,
     pub num_holders: u32,
     pub submitted_at: Timestamp,
+    /// Draws can use the snapshot from this time on
+    #[serde(default)]
+    pub challenge_deadline: Timestamp,
+    /// Set by a successful fraud proof. No draw can be committed on an invalidated snapshot.
+    #[serde(default)]
+    pub invalidated: bool,
+}
+
+#[cw_serde]
+pub struct SnapshotDispute {
+    pub epoch: u64,
+    pub disputer: Addr,
+    /// Lowest csINJ balance the disputer held over the snapshot window.
+    /// The answering leaf must carry at least this much weight.
+    pub min_balance: Uint128,
+    pub opened_at: Timestamp,
 }
 
 /// Response type for querying a beacon from the drand oracle.
@@ -82,3 +189,12 @@ pub struct StoredBeaconResponse {
     pub signature: Vec<u8>,
     pub verified: bool,
 }
+
+#[cw_serde]
+pub struct PendingChange {
+    pub id: u64,
+    pub change: ParamChange,
+    pub scheduled_at: Timestamp,
+    /// Earliest time the change can be executed
+    pub executable_at: Timestamp,
+}
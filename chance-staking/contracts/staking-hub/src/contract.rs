@@ -1,16 +1,18 @@
+use chance_staking_common::types::Role;
 use cosmwasm_std::{
-    entry_point, Binary, Decimal, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Uint128,
+    entry_point, Binary, Decimal, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdResult,
+    Uint128,
 };
 use cw2::{get_contract_version, set_contract_version};
 use injective_cosmwasm::InjectiveMsgWrapper;
 
 use crate::error::ContractError;
 use crate::execute;
-use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, SudoMsg};
 use crate::query;
 use crate::state::{
-    Config, EpochState, CONFIG, EPOCH_STATE, EXCHANGE_RATE, PENDING_UNSTAKE_TOTAL,
-    TOTAL_CSINJ_SUPPLY, TOTAL_INJ_BACKING,
+    Config, EpochState, CONFIG, CURRENT_BATCH_ID, EPOCH_STATE, EXCHANGE_RATE,
+    PENDING_UNSTAKE_TOTAL, TOTAL_CSINJ_SUPPLY, TOTAL_INJ_BACKING,
 };
 
 const CONTRACT_NAME: &str = "crates.io:chance-staking-hub";
@@ -50,13 +52,18 @@ pub fn instantiate(
 
     // Create Token Factory denom
     let (create_denom_msg, full_denom) = execute::create_denom(&env, &msg.csinj_subdenom)?;
+    let hook_msg = execute::create_set_before_send_hook_msg(&env, &full_denom);
 
     let config = Config {
         admin: info.sender.clone(),
         operator: deps.api.addr_validate(&msg.operator)?,
+        guardian: None,
+        pending_admin: None,
+        pending_operator: None,
         reward_distributor: deps.api.addr_validate(&msg.reward_distributor)?,
         drand_oracle: deps.api.addr_validate(&msg.drand_oracle)?,
         csinj_denom: full_denom.clone(),
+        validator_weights: vec![1; msg.validators.len()],
         validators: msg.validators,
         epoch_duration_seconds: msg.epoch_duration_seconds,
         protocol_fee_bps: msg.protocol_fee_bps,
@@ -67,7 +74,29 @@ pub fn instantiate(
         min_epochs_regular: msg.min_epochs_regular,
         min_epochs_big: msg.min_epochs_big,
         min_stake_amount: msg.min_stake_amount,
+        timelock_seconds: msg.timelock_seconds.unwrap_or(0),
+        snapshot_tolerance_bps: msg
+            .snapshot_tolerance_bps
+            .unwrap_or(execute::DEFAULT_SNAPSHOT_TOLERANCE_BPS),
+        keeper_fee_bps: msg
+            .keeper_fee_bps
+            .unwrap_or(execute::DEFAULT_KEEPER_FEE_BPS),
+        snapshot_grace_seconds: msg
+            .snapshot_grace_seconds
+            .unwrap_or(execute::DEFAULT_SNAPSHOT_GRACE_SECONDS),
     };
+    if config.snapshot_tolerance_bps > 10000 {
+        return Err(ContractError::InvalidBps {
+            field: "snapshot_tolerance_bps".to_string(),
+            value: config.snapshot_tolerance_bps,
+        });
+    }
+    if config.keeper_fee_bps > 10000 {
+        return Err(ContractError::InvalidBps {
+            field: "keeper_fee_bps".to_string(),
+            value: config.keeper_fee_bps,
+        });
+    }
 
     CONFIG.save(deps.storage, &config)?;
 
@@ -76,6 +105,7 @@ pub fn instantiate(
     TOTAL_INJ_BACKING.save(deps.storage, &Uint128::zero())?;
     TOTAL_CSINJ_SUPPLY.save(deps.storage, &Uint128::zero())?;
     PENDING_UNSTAKE_TOTAL.save(deps.storage, &Uint128::zero())?;
+    CURRENT_BATCH_ID.save(deps.storage, &1)?;
 
     // Initialize epoch state
     let epoch_state = EpochState {
@@ -87,11 +117,13 @@ pub fn instantiate(
         snapshot_total_weight: Uint128::zero(),
         snapshot_num_holders: 0,
         snapshot_uri: None,
+        snapshot_bounds: None,
     };
     EPOCH_STATE.save(deps.storage, &epoch_state)?;
 
     Ok(ContractResponse::new()
         .add_message(create_denom_msg)
+        .add_message(hook_msg)
         .add_attribute("action", "instantiate")
         .add_attribute("contract", "staking-hub")
         .add_attribute("csinj_denom", full_denom)
@@ -105,14 +137,37 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<ContractResponse, ContractError> {
+    if let Some(operation) = msg.pausable_operation() {
+        execute::assert_not_paused(deps.storage, &operation)?;
+    }
+
     match msg {
-        ExecuteMsg::Stake {} => execute::stake(deps, env, info),
-        ExecuteMsg::Unstake {} => execute::unstake(deps, env, info),
-        ExecuteMsg::ClaimUnstaked { request_ids } => {
-            execute::claim_unstaked(deps, env, info, request_ids)
+        ExecuteMsg::Stake {
+            recipient,
+            min_csinj_out,
+            deadline,
+        } => execute::stake(deps, env, info, recipient, min_csinj_out, deadline),
+        ExecuteMsg::Unstake {
+            recipient,
+            min_inj_out,
+            deadline,
+        } => execute::unstake(deps, env, info, recipient, min_inj_out, deadline),
+        ExecuteMsg::ClaimUnstaked {
+            request_ids,
+            recipient,
+        } => execute::claim_unstaked(deps, env, info, request_ids, recipient),
+        ExecuteMsg::Sponsor {} => execute::sponsor(deps, env, info),
+        ExecuteMsg::WithdrawSponsorship {} => execute::withdraw_sponsorship(deps, env, info),
+        ExecuteMsg::Donate { target } => execute::donate(deps, env, info, target),
+        ExecuteMsg::EnterYieldOnly { recipient } => {
+            execute::enter_yield_only(deps, env, info, recipient)
+        }
+        ExecuteMsg::ExitYieldOnly { shares, recipient } => {
+            execute::exit_yield_only(deps, env, info, shares, recipient)
         }
         ExecuteMsg::ClaimRewards {} => execute::claim_rewards(deps, env, info),
         ExecuteMsg::DistributeRewards {} => execute::distribute_rewards(deps, env, info),
+        ExecuteMsg::AdvanceEpoch {} => execute::advance_epoch(deps, env, info),
         ExecuteMsg::TakeSnapshot {
             merkle_root,
             total_weight,
@@ -128,8 +183,6 @@ pub fn execute(
             snapshot_uri,
         ),
         ExecuteMsg::UpdateConfig {
-            admin,
-            operator,
             protocol_fee_bps,
             base_yield_bps,
             regular_pool_bps,
@@ -137,12 +190,13 @@ pub fn execute(
             min_epochs_regular,
             min_epochs_big,
             min_stake_amount,
+            snapshot_tolerance_bps,
+            keeper_fee_bps,
+            snapshot_grace_seconds,
         } => execute::update_config(
             deps,
             env,
             info,
-            admin,
-            operator,
             protocol_fee_bps,
             base_yield_bps,
             regular_pool_bps,
@@ -150,11 +204,20 @@ pub fn execute(
             min_epochs_regular,
             min_epochs_big,
             min_stake_amount,
+            snapshot_tolerance_bps,
+            keeper_fee_bps,
+            snapshot_grace_seconds,
         ),
-        ExecuteMsg::UpdateValidators { add, remove } => {
-            execute::update_validators(deps, env, info, add, remove)
+        ExecuteMsg::UpdateSnapshotExclusions { add, remove } => {
+            execute::update_snapshot_exclusions(deps, env, info, add, remove)
         }
+        ExecuteMsg::UpdateValidators {
+            add,
+            remove,
+            weights,
+        } => execute::update_validators(deps, env, info, add, remove, weights),
         ExecuteMsg::SyncDelegations {} => execute::sync_delegations(deps, env, info),
+        ExecuteMsg::SweepSurplus {} => execute::sweep_surplus(deps, env, info),
         ExecuteMsg::RedelegateStake {
             src_validator,
             dst_validator,
@@ -163,6 +226,32 @@ pub fn execute(
         ExecuteMsg::RebalanceStake { validator_weights } => {
             execute::rebalance_stake(deps, env, info, validator_weights)
         }
+        ExecuteMsg::ProposeAdmin {
+            new_admin,
+            expires_in_seconds,
+        } => execute::propose_transfer(deps, env, info, Role::Admin, new_admin, expires_in_seconds),
+        ExecuteMsg::AcceptAdmin {} => execute::accept_transfer(deps, env, info, Role::Admin),
+        ExecuteMsg::ProposeOperator {
+            new_operator,
+            expires_in_seconds,
+        } => execute::propose_transfer(
+            deps,
+            env,
+            info,
+            Role::Operator,
+            new_operator,
+            expires_in_seconds,
+        ),
+        ExecuteMsg::AcceptOperator {} => execute::accept_transfer(deps, env, info, Role::Operator),
+        ExecuteMsg::CancelProposal { role } => execute::cancel_proposal(deps, env, info, role),
+        ExecuteMsg::ScheduleChange { change } => execute::schedule_change(deps, env, info, change),
+        ExecuteMsg::ExecuteChange { id } => execute::execute_change(deps, env, info, id),
+        ExecuteMsg::CancelChange { id } => execute::cancel_change(deps, env, info, id),
+        ExecuteMsg::UpdateGuardian { guardian } => {
+            execute::update_guardian(deps, env, info, guardian)
+        }
+        ExecuteMsg::Pause { operations } => execute::pause(deps, env, info, operations),
+        ExecuteMsg::Unpause { operations } => execute::unpause(deps, env, info, operations),
     }
 }
 
@@ -171,14 +260,41 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => query::query_config(deps),
         QueryMsg::EpochState {} => query::query_epoch_state(deps),
+        QueryMsg::EpochRecord { epoch } => query::query_epoch_record(deps, epoch),
+        QueryMsg::EpochHistory { start_after, limit } => {
+            query::query_epoch_history(deps, start_after, limit)
+        }
+        QueryMsg::YieldStats { lookback_epochs } => query::query_yield_stats(deps, lookback_epochs),
         QueryMsg::ExchangeRate {} => query::query_exchange_rate(deps),
         QueryMsg::UnstakeRequests {
             address,
             start_after,
             limit,
         } => query::query_unstake_requests(deps, address, start_after, limit),
-        QueryMsg::StakerInfo { address } => query::query_staker_info(deps, address),
+        QueryMsg::StakerInfo { address } => query::query_staker_info(deps, env, address),
         QueryMsg::ValidatorDelegations {} => query::query_validator_delegations(deps, env),
+        QueryMsg::UnbondingBatch { id } => query::query_unbonding_batch(deps, id),
+        QueryMsg::UnbondingBatches { start_after, limit } => {
+            query::query_unbonding_batches(deps, start_after, limit)
+        }
+        QueryMsg::PauseState {} => query::query_pause_state(deps),
+        QueryMsg::PendingChanges { start_after, limit } => {
+            query::query_pending_changes(deps, start_after, limit)
+        }
+        QueryMsg::TwabBetween {
+            address,
+            start,
+            end,
+        } => query::query_twab_between(deps, env, address, start, end),
+        QueryMsg::SnapshotExclusions { start_after, limit } => {
+            query::query_snapshot_exclusions(deps, start_after, limit)
+        }
+        QueryMsg::YieldOnlyPosition { address } => query::query_yield_only_position(deps, address),
+        QueryMsg::YieldOnlyTotals {} => query::query_yield_only_totals(deps),
+        QueryMsg::DonationTotals {} => query::query_donation_totals(deps),
+        QueryMsg::Sponsors { start_after, limit } => {
+            query::query_sponsors(deps, start_after, limit)
+        }
     }
 }
 
@@ -186,7 +302,7 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
 #[entry_point]
 pub fn migrate(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     _msg: MigrateMsg,
 ) -> Result<Response<InjectiveMsgWrapper>, ContractError> {
     let stored = get_contract_version(deps.storage)?;
@@ -198,19 +314,60 @@ pub fn migrate(
 
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
+    // Rewrite pre-batch unstake requests into unbonding batches
+    let legacy_batches =
+        execute::migrate_legacy_unstake_requests(deps.storage, deps.querier, &env)?;
+
+    // Configs stored before validator weights existed load with no weights:
+    // give every validator an equal weight
+    let mut config = CONFIG.load(deps.storage)?;
+    if config.validator_weights.len() != config.validators.len() {
+        config.validator_weights = vec![1; config.validators.len()];
+    }
+    CONFIG.save(deps.storage, &config)?;
+
+    // (Re-)register the csINJ transfer hook for deployments created before it existed
+    let hook_msg = execute::create_set_before_send_hook_msg(&env, &config.csinj_denom);
+
     Ok(Response::new()
+        .add_message(hook_msg)
         .add_attribute("action", "migrate")
         .add_attribute("from_version", stored.version)
-        .add_attribute("to_version", CONTRACT_VERSION))
+        .add_attribute("to_version", CONTRACT_VERSION)
+        .add_attribute("legacy_batches", legacy_batches.to_string()))
+}
+
+#[entry_point]
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<ContractResponse, ContractError> {
+    match msg.id {
+        execute::ADVANCE_EPOCH_REPLY_ID => execute::advance_epoch_reply(deps, env),
+        id => Err(ContractError::UnknownReplyId { id }),
+    }
+}
+
+#[entry_point]
+pub fn sudo(deps: DepsMut, env: Env, msg: SudoMsg) -> Result<ContractResponse, ContractError> {
+    match msg {
+        SudoMsg::BlockBeforeSend { from, to, amount }
+        | SudoMsg::TrackBeforeSend { from, to, amount } => {
+            execute::track_csinj_transfer(deps, env, from, to, amount)
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::state::UNSTAKE_REQUESTS;
+    use crate::msg::ValidatorWeight;
+    use crate::state::{
+        BatchUndelegation, EXPECTED_DELEGATIONS, PENDING_CHANGES, UNBONDING_BATCHES,
+        UNSTAKE_REQUESTS,
+    };
 
     use super::*;
-    use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env, MockApi};
-    use cosmwasm_std::{coins, Coin, Timestamp};
+    use cosmwasm_std::testing::{
+        message_info, mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage,
+    };
+    use cosmwasm_std::{coins, Coin, FullDelegation, OwnedDeps, Timestamp};
 
     fn default_instantiate_msg() -> InstantiateMsg {
         let mock_api = MockApi::default();
@@ -232,6 +389,10 @@ mod tests {
             min_epochs_regular: 0,
             min_epochs_big: 0,
             min_stake_amount: Uint128::zero(),
+            snapshot_tolerance_bps: None,
+            keeper_fee_bps: None,
+            snapshot_grace_seconds: None,
+            timelock_seconds: None,
         }
     }
 
@@ -243,6 +404,35 @@ mod tests {
         instantiate(deps, mock_env(), info, msg).unwrap();
     }
 
+    const VAL_A: &str = "injvaloper1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqj9";
+    const VAL_B: &str = "injvaloper1xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx";
+
+    fn mock_delegations(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        delegations: &[(&str, u128)],
+    ) {
+        let contract_addr = mock_env().contract.address;
+        let delegations: Vec<FullDelegation> = delegations
+            .iter()
+            .map(|(validator, amount)| {
+                FullDelegation::create(
+                    contract_addr.clone(),
+                    validator.to_string(),
+                    Coin::new(*amount, "inj"),
+                    Coin::new(*amount, "inj"),
+                    vec![],
+                )
+            })
+            .collect();
+        deps.querier.staking.update("inj", &[], &delegations);
+    }
+
+    fn distribute(deps: DepsMut, env: Env) -> ContractResponse {
+        let operator = MockApi::default().addr_make("operator");
+        let info = message_info(&operator, &[]);
+        execute(deps, env, info, ExecuteMsg::DistributeRewards {}).unwrap()
+    }
+
     #[test]
     fn test_instantiate() {
         let mut deps = mock_dependencies();
@@ -282,7 +472,17 @@ mod tests {
 
         let user1 = deps.api.addr_make("user1");
         let info = message_info(&user1, &coins(100_000_000, "inj"));
-        let res = execute(deps.as_mut(), mock_env(), info, ExecuteMsg::Stake {}).unwrap();
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
 
         // Should have mint message + delegation messages
         assert!(res.messages.len() >= 2); // 1 mint + 2 delegations
@@ -305,7 +505,17 @@ mod tests {
 
         let user1 = deps.api.addr_make("user1");
         let info = message_info(&user1, &[]);
-        let err = execute(deps.as_mut(), mock_env(), info, ExecuteMsg::Stake {}).unwrap_err();
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap_err();
         assert!(matches!(err, ContractError::NoFundsSent));
     }
 
@@ -316,7 +526,17 @@ mod tests {
 
         let user1 = deps.api.addr_make("user1");
         let info = message_info(&user1, &coins(100, "usdt"));
-        let err = execute(deps.as_mut(), mock_env(), info, ExecuteMsg::Stake {}).unwrap_err();
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap_err();
         assert!(matches!(err, ContractError::WrongDenom { .. }));
     }
 
@@ -328,7 +548,17 @@ mod tests {
         // First stake at rate 1.0
         let user1 = deps.api.addr_make("user1");
         let info = message_info(&user1, &coins(100_000_000, "inj"));
-        execute(deps.as_mut(), mock_env(), info, ExecuteMsg::Stake {}).unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
 
         // Manually increase exchange rate to 2.0
         EXCHANGE_RATE
@@ -338,13 +568,106 @@ mod tests {
         // Second stake at rate 2.0 — should get half the csINJ
         let user2 = deps.api.addr_make("user2");
         let info2 = message_info(&user2, &coins(100_000_000, "inj"));
-        execute(deps.as_mut(), mock_env(), info2, ExecuteMsg::Stake {}).unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info2,
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
 
         let supply = TOTAL_CSINJ_SUPPLY.load(deps.as_ref().storage).unwrap();
         // user1 got 100M csINJ, user2 got 50M csINJ = 150M total
         assert_eq!(supply, Uint128::from(150_000_000u128));
     }
 
+    #[test]
+    fn test_stake_unstake_slippage_and_deadline() {
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+
+        let user1 = deps.api.addr_make("user1");
+        let now = mock_env().block.time.seconds();
+
+        // Deadline in the past
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user1, &coins(100_000_000, "inj")),
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: Some(now - 1),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::DeadlineExceeded { .. }));
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user1, &coins(100_000_000, "inj")),
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: Some(Uint128::new(100_000_000)),
+                deadline: Some(now),
+            },
+        )
+        .unwrap();
+
+        // The rate moves against the next staker between signing and execution
+        EXCHANGE_RATE
+            .save(deps.as_mut().storage, &Decimal::from_ratio(2u128, 1u128))
+            .unwrap();
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user1, &coins(100_000_000, "inj")),
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: Some(Uint128::new(100_000_000)),
+                deadline: None,
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::SlippageExceeded { amount, .. } if amount == Uint128::new(50_000_000)
+        ));
+
+        // Unstaking 10M csINJ at rate 2.0 owes 20M INJ
+        let config = CONFIG.load(deps.as_ref().storage).unwrap();
+        let csinj = [Coin::new(10_000_000u128, &config.csinj_denom)];
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user1, &csinj),
+            ExecuteMsg::Unstake {
+                recipient: None,
+                min_inj_out: Some(Uint128::new(20_000_001)),
+                deadline: None,
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::SlippageExceeded { .. }));
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user1, &csinj),
+            ExecuteMsg::Unstake {
+                recipient: None,
+                min_inj_out: Some(Uint128::new(20_000_000)),
+                deadline: Some(now + 60),
+            },
+        )
+        .unwrap();
+    }
+
     #[test]
     fn test_unstake() {
         let mut deps = mock_dependencies();
@@ -353,7 +676,17 @@ mod tests {
         // First stake
         let user1 = deps.api.addr_make("user1");
         let info = message_info(&user1, &coins(100_000_000, "inj"));
-        execute(deps.as_mut(), mock_env(), info, ExecuteMsg::Stake {}).unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
 
         // Get the csINJ denom
         let config = CONFIG.load(deps.as_ref().storage).unwrap();
@@ -361,10 +694,20 @@ mod tests {
         // Unstake half
         let user1 = deps.api.addr_make("user1");
         let info = message_info(&user1, &[Coin::new(50_000_000u128, &config.csinj_denom)]);
-        let res = execute(deps.as_mut(), mock_env(), info, ExecuteMsg::Unstake {}).unwrap();
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Unstake {
+                recipient: None,
+                min_inj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
 
-        // Should have burn message + undelegation messages
-        assert!(res.messages.len() >= 2);
+        // Only the burn message: undelegation waits for the batch to be submitted
+        assert_eq!(res.messages.len(), 1);
 
         // Check totals
         let backing = TOTAL_INJ_BACKING.load(deps.as_ref().storage).unwrap();
@@ -379,7 +722,14 @@ mod tests {
             .load(deps.as_ref().storage, (&addr, 0))
             .unwrap();
         assert_eq!(request.inj_amount, Uint128::from(50_000_000u128));
+        assert_eq!(request.batch_id, 1);
         assert!(!request.claimed);
+
+        // Request is accounted for in the open batch
+        let batch = UNBONDING_BATCHES.load(deps.as_ref().storage, 1).unwrap();
+        assert_eq!(batch.total_inj, Uint128::from(50_000_000u128));
+        assert_eq!(batch.num_requests, 1);
+        assert!(batch.unlock_time.is_none());
     }
 
     #[test]
@@ -390,16 +740,36 @@ mod tests {
         // Stake
         let user1 = deps.api.addr_make("user1");
         let info = message_info(&user1, &coins(100_000_000, "inj"));
-        execute(deps.as_mut(), mock_env(), info, ExecuteMsg::Stake {}).unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
 
         let config = CONFIG.load(deps.as_ref().storage).unwrap();
 
         // Unstake
         let user1 = deps.api.addr_make("user1");
         let info = message_info(&user1, &[Coin::new(50_000_000u128, &config.csinj_denom)]);
-        execute(deps.as_mut(), mock_env(), info, ExecuteMsg::Unstake {}).unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Unstake {
+                recipient: None,
+                min_inj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
 
-        // Try to claim immediately — should fail
+        // Try to claim immediately — batch not submitted yet
         let user1 = deps.api.addr_make("user1");
         let info = message_info(&user1, &[]);
         let err = execute(
@@ -408,6 +778,29 @@ mod tests {
             info,
             ExecuteMsg::ClaimUnstaked {
                 request_ids: vec![0],
+                recipient: None,
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::UnstakeBatchPending { id: 0, batch_id: 1 }
+        ));
+
+        // Submit the batch, then claim before the unbonding period ends
+        mock_delegations(&mut deps, &[(VAL_A, 50_000_000), (VAL_B, 50_000_000)]);
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(86400);
+        distribute(deps.as_mut(), env.clone());
+
+        let info = message_info(&user1, &[]);
+        let err = execute(
+            deps.as_mut(),
+            env,
+            info,
+            ExecuteMsg::ClaimUnstaked {
+                request_ids: vec![0],
+                recipient: None,
             },
         )
         .unwrap_err();
@@ -422,17 +815,42 @@ mod tests {
         // Stake
         let user1 = deps.api.addr_make("user1");
         let info = message_info(&user1, &coins(100_000_000, "inj"));
-        execute(deps.as_mut(), mock_env(), info, ExecuteMsg::Stake {}).unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
 
         let config = CONFIG.load(deps.as_ref().storage).unwrap();
 
         // Unstake
         let user1 = deps.api.addr_make("user1");
         let info = message_info(&user1, &[Coin::new(50_000_000u128, &config.csinj_denom)]);
-        execute(deps.as_mut(), mock_env(), info, ExecuteMsg::Unstake {}).unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Unstake {
+                recipient: None,
+                min_inj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
 
-        // Fast forward past 21 days
+        // Submit the unbonding batch at the end of the epoch
+        mock_delegations(&mut deps, &[(VAL_A, 50_000_000), (VAL_B, 50_000_000)]);
         let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(86400);
+        distribute(deps.as_mut(), env.clone());
+
+        // Fast forward past 21 days
         env.block.time = Timestamp::from_seconds(env.block.time.seconds() + 22 * 24 * 60 * 60);
 
         let user1 = deps.api.addr_make("user1");
@@ -443,6 +861,7 @@ mod tests {
             info,
             ExecuteMsg::ClaimUnstaked {
                 request_ids: vec![0],
+                recipient: None,
             },
         )
         .unwrap();
@@ -459,189 +878,2488 @@ mod tests {
     }
 
     #[test]
-    fn test_claim_rewards_unauthorized() {
+    fn test_stake_unstake_claim_on_behalf() {
+        use crate::state::USER_STAKE_EPOCH;
+
         let mut deps = mock_dependencies();
         setup_contract(deps.as_mut());
 
-        let random_user = deps.api.addr_make("random_user");
-        let info = message_info(&random_user, &[]);
-        let err =
-            execute(deps.as_mut(), mock_env(), info, ExecuteMsg::ClaimRewards {}).unwrap_err();
-        assert!(matches!(err, ContractError::Unauthorized { .. }));
-    }
+        let vault = deps.api.addr_make("vault");
+        let beneficiary = deps.api.addr_make("beneficiary");
+        let payout = deps.api.addr_make("payout");
 
-    #[test]
-    fn test_claim_rewards() {
-        let mut deps = mock_dependencies();
-        setup_contract(deps.as_mut());
+        // The vault pays, the beneficiary gets the csINJ and the stake epoch
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&vault, &coins(100_000_000, "inj")),
+            ExecuteMsg::Stake {
+                recipient: Some(beneficiary.to_string()),
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
+        let event = res.events.iter().find(|e| e.ty == "chance_stake").unwrap();
+        assert!(event
+            .attributes
+            .iter()
+            .any(|a| a.key == "user" && a.value == vault.as_str()));
+        assert!(event
+            .attributes
+            .iter()
+            .any(|a| a.key == "recipient" && a.value == beneficiary.as_str()));
+        assert_eq!(
+            USER_STAKE_EPOCH
+                .load(deps.as_ref().storage, &beneficiary)
+                .unwrap(),
+            1
+        );
+        assert!(USER_STAKE_EPOCH
+            .may_load(deps.as_ref().storage, &vault)
+            .unwrap()
+            .is_none());
 
-        let operator = deps.api.addr_make("operator");
-        let info = message_info(&operator, &[]);
-        let res = execute(deps.as_mut(), mock_env(), info, ExecuteMsg::ClaimRewards {}).unwrap();
+        // The vault unstakes into a request owned by the beneficiary
+        let config = CONFIG.load(deps.as_ref().storage).unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&vault, &[Coin::new(50_000_000u128, &config.csinj_denom)]),
+            ExecuteMsg::Unstake {
+                recipient: Some(beneficiary.to_string()),
+                min_inj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
+        assert!(UNSTAKE_REQUESTS.has(deps.as_ref().storage, (&beneficiary, 0)));
+        assert!(!UNSTAKE_REQUESTS.has(deps.as_ref().storage, (&vault, 0)));
 
-        // Should have: 1 SetWithdrawAddress + 2 WithdrawDelegatorReward (one per validator)
-        assert_eq!(res.messages.len(), 3);
+        mock_delegations(&mut deps, &[(VAL_A, 50_000_000), (VAL_B, 50_000_000)]);
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(86400);
+        distribute(deps.as_mut(), env.clone());
+        env.block.time = env.block.time.plus_seconds(22 * 24 * 60 * 60);
 
-        // Check event
-        assert!(res.events.iter().any(|e| e.ty == "chance_rewards_claimed"));
+        // The vault has no request of its own to claim
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&vault, &[]),
+            ExecuteMsg::ClaimUnstaked {
+                request_ids: vec![0],
+                recipient: None,
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::UnstakeRequestNotFound { .. }));
+
+        // The owner claims and routes the INJ elsewhere
+        let res = execute(
+            deps.as_mut(),
+            env,
+            message_info(&beneficiary, &[]),
+            ExecuteMsg::ClaimUnstaked {
+                request_ids: vec![0],
+                recipient: Some(payout.to_string()),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            cosmwasm_std::CosmosMsg::Bank(cosmwasm_std::BankMsg::Send {
+                to_address: payout.to_string(),
+                amount: coins(50_000_000, "inj"),
+            })
+        );
     }
 
     #[test]
-    fn test_distribute_rewards() {
+    fn test_unbonding_batch_submission_rate_limited() {
         let mut deps = mock_dependencies();
         setup_contract(deps.as_mut());
 
-        // Stake first so there's backing
         let user1 = deps.api.addr_make("user1");
-        let info = message_info(&user1, &coins(1_000_000_000, "inj"));
-        execute(deps.as_mut(), mock_env(), info, ExecuteMsg::Stake {}).unwrap();
-
-        // Simulate rewards arriving in contract balance (100M INJ rewards)
-        let mut env = mock_env();
-        let contract_addr = env.contract.address.clone();
-        deps.querier
-            .bank
-            .update_balance(contract_addr, coins(100_000_000, "inj"));
+        let info = message_info(&user1, &coins(100_000_000, "inj"));
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
+        mock_delegations(&mut deps, &[(VAL_A, 50_000_000), (VAL_B, 50_000_000)]);
+        let config = CONFIG.load(deps.as_ref().storage).unwrap();
 
-        // H-01 FIX: Advance time by epoch duration (86400 seconds = 1 day)
+        // Two unstakes in the same epoch share a batch
+        for _ in 0..2 {
+            let info = message_info(&user1, &[Coin::new(10_000_000u128, &config.csinj_denom)]);
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                info,
+                ExecuteMsg::Unstake {
+                    recipient: None,
+                    min_inj_out: None,
+                    deadline: None,
+                },
+            )
+            .unwrap();
+        }
+
+        // First batch is submitted at the next distribution: one undelegation per validator
+        let mut env = mock_env();
         env.block.time = env.block.time.plus_seconds(86400);
+        let res = distribute(deps.as_mut(), env.clone());
+        let undelegations = res
+            .messages
+            .iter()
+            .filter(|m| {
+                matches!(
+                    m.msg,
+                    cosmwasm_std::CosmosMsg::Staking(cosmwasm_std::StakingMsg::Undelegate { .. })
+                )
+            })
+            .count();
+        assert_eq!(undelegations, 2);
+        assert!(res
+            .events
+            .iter()
+            .any(|e| e.ty == "chance_unbonding_batch_submitted"));
+
+        let batch = UNBONDING_BATCHES.load(deps.as_ref().storage, 1).unwrap();
+        assert_eq!(batch.total_inj, Uint128::from(20_000_000u128));
+        assert_eq!(batch.num_requests, 2);
+        assert_eq!(batch.submitted_at, Some(env.block.time));
+        assert_eq!(
+            batch.unlock_time,
+            Some(env.block.time.plus_seconds(21 * 24 * 60 * 60))
+        );
+        assert_eq!(CURRENT_BATCH_ID.load(deps.as_ref().storage).unwrap(), 2);
+
+        // Next unstake goes into batch 2, which stays open one epoch later
+        let info = message_info(&user1, &[Coin::new(10_000_000u128, &config.csinj_denom)]);
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            ExecuteMsg::Unstake {
+                recipient: None,
+                min_inj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
+        env.block.time = env.block.time.plus_seconds(86400);
+        let res = distribute(deps.as_mut(), env.clone());
+        assert!(!res
+            .events
+            .iter()
+            .any(|e| e.ty == "chance_unbonding_batch_submitted"));
+        assert_eq!(CURRENT_BATCH_ID.load(deps.as_ref().storage).unwrap(), 2);
+
+        // Once the submission interval has passed, batch 2 is submitted
+        env.block.time = env.block.time.plus_seconds(3 * 86400);
+        distribute(deps.as_mut(), env.clone());
+        let batch = UNBONDING_BATCHES.load(deps.as_ref().storage, 2).unwrap();
+        assert_eq!(batch.total_inj, Uint128::from(10_000_000u128));
+        assert_eq!(batch.submitted_at, Some(env.block.time));
+        assert_eq!(CURRENT_BATCH_ID.load(deps.as_ref().storage).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_claim_rewards_unauthorized() {
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+
+        let random_user = deps.api.addr_make("random_user");
+        let info = message_info(&random_user, &[]);
+        let err =
+            execute(deps.as_mut(), mock_env(), info, ExecuteMsg::ClaimRewards {}).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized { .. }));
+    }
+
+    #[test]
+    fn test_claim_rewards() {
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
 
-        // Distribute rewards (step 2)
         let operator = deps.api.addr_make("operator");
         let info = message_info(&operator, &[]);
-        let res = execute(deps.as_mut(), env, info, ExecuteMsg::DistributeRewards {}).unwrap();
+        let res = execute(deps.as_mut(), mock_env(), info, ExecuteMsg::ClaimRewards {}).unwrap();
 
-        // Should have messages: fund regular pool, fund big pool, treasury send
-        assert!(res.messages.len() >= 3);
+        // Should have: 1 SetWithdrawAddress + 2 WithdrawDelegatorReward (one per validator)
+        assert_eq!(res.messages.len(), 3);
 
-        // Check epoch advanced
-        let epoch = EPOCH_STATE.load(deps.as_ref().storage).unwrap();
-        assert_eq!(epoch.current_epoch, 2);
-        assert!(!epoch.snapshot_finalized);
+        // Check event
+        assert!(res.events.iter().any(|e| e.ty == "chance_rewards_claimed"));
+    }
 
-        // Check exchange rate increased (base yield = 5% of 100M = 5M added to 1B backing)
-        let rate = EXCHANGE_RATE.load(deps.as_ref().storage).unwrap();
-        assert!(rate > Decimal::one());
+    #[test]
+    fn test_advance_epoch() {
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+        let operator = deps.api.addr_make("operator");
 
-        // Check event
+        let user1 = deps.api.addr_make("user1");
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user1, &coins(1_000_000_000, "inj")),
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&operator, &[]),
+            ExecuteMsg::AdvanceEpoch {},
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::EpochNotReady));
+
+        // Another stake auto-withdraws the 2M pending rewards of each validator
+        let contract_addr = mock_env().contract.address;
+        let delegations: Vec<FullDelegation> = [VAL_A, VAL_B]
+            .iter()
+            .map(|validator| {
+                FullDelegation::create(
+                    contract_addr.clone(),
+                    validator.to_string(),
+                    Coin::new(500_000_000u128, "inj"),
+                    Coin::new(500_000_000u128, "inj"),
+                    coins(2_000_000, "inj"),
+                )
+            })
+            .collect();
+        deps.querier.staking.update("inj", &[], &delegations);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user1, &coins(2_000, "inj")),
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            crate::state::AUTO_WITHDRAWN_REWARDS
+                .load(deps.as_ref().storage)
+                .unwrap(),
+            Uint128::new(4_000_000)
+        );
+
+        // 5M INJ already sits in the hub before the withdrawals, 4M of it those rewards
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(86400);
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.clone(), coins(5_000_000, "inj"));
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&operator, &[]),
+            ExecuteMsg::AdvanceEpoch {},
+        )
+        .unwrap();
+
+        // SetWithdrawAddress + one withdrawal per validator; only the last one replies
+        assert_eq!(res.messages.len(), 3);
+        assert_eq!(res.messages[0].reply_on, cosmwasm_std::ReplyOn::Never);
+        assert_eq!(res.messages[1].reply_on, cosmwasm_std::ReplyOn::Never);
+        assert_eq!(res.messages[2].reply_on, cosmwasm_std::ReplyOn::Success);
+        assert_eq!(res.messages[2].id, execute::ADVANCE_EPOCH_REPLY_ID);
+
+        // The withdrawals bring in 100M; that delta and the auto-withdrawn rewards
+        // are distributed
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.clone(), coins(105_000_000, "inj"));
+        mock_delegations(&mut deps, &[(VAL_A, 500_001_000), (VAL_B, 500_001_000)]);
+        #[allow(deprecated)]
+        let withdraw_reply = Reply {
+            id: execute::ADVANCE_EPOCH_REPLY_ID,
+            payload: Binary::default(),
+            gas_used: 0,
+            result: cosmwasm_std::SubMsgResult::Ok(cosmwasm_std::SubMsgResponse {
+                events: vec![],
+                data: None,
+                msg_responses: vec![],
+            }),
+        };
+        let res = reply(deps.as_mut(), env.clone(), withdraw_reply.clone()).unwrap();
         assert!(res.events.iter().any(|e| e.ty == "chance_epoch_advanced"));
+
+        let record = crate::state::EPOCH_HISTORY
+            .load(deps.as_ref().storage, 1)
+            .unwrap();
+        assert_eq!(record.total_rewards, Uint128::new(104_000_000));
+        assert_eq!(record.unexplained_surplus, Uint128::new(1_000_000));
+        assert_eq!(record.regular_pool_amount, Uint128::new(72_800_000));
+        assert_eq!(record.big_pool_amount, Uint128::new(20_800_000));
+        assert!(crate::state::AUTO_WITHDRAWN_REWARDS
+            .load(deps.as_ref().storage)
+            .unwrap()
+            .is_zero());
+        let epoch_state = EPOCH_STATE.load(deps.as_ref().storage).unwrap();
+        assert_eq!(epoch_state.current_epoch, 2);
+        assert!(!crate::state::PENDING_EPOCH_ADVANCE.exists(deps.as_ref().storage));
+
+        let err = reply(deps.as_mut(), env.clone(), withdraw_reply.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::NoPendingEpochAdvance));
+
+        let err = reply(
+            deps.as_mut(),
+            env,
+            Reply {
+                id: 99,
+                ..withdraw_reply
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::UnknownReplyId { id: 99 }));
     }
 
     #[test]
-    fn test_distribute_rewards_unauthorized() {
+    fn test_unexplained_surplus_reported_once_and_swept() {
         let mut deps = mock_dependencies();
         setup_contract(deps.as_mut());
+        let config = CONFIG.load(deps.as_ref().storage).unwrap();
 
-        let random_user = deps.api.addr_make("random_user");
-        let info = message_info(&random_user, &[]);
+        #[allow(deprecated)]
+        let withdraw_reply = Reply {
+            id: execute::ADVANCE_EPOCH_REPLY_ID,
+            payload: Binary::default(),
+            gas_used: 0,
+            result: cosmwasm_std::SubMsgResult::Ok(cosmwasm_std::SubMsgResponse {
+                events: vec![],
+                data: None,
+                msg_responses: vec![],
+            }),
+        };
+        // A 1M transfer before epoch 1 ends, then another 500k before epoch 2 ends
+        let mut env = mock_env();
+        for (epoch, balance, reported) in [(1, 1_000_000, 1_000_000), (2, 1_500_000, 500_000)] {
+            env.block.time = env.block.time.plus_seconds(86400);
+            deps.querier
+                .bank
+                .update_balance(env.contract.address.clone(), coins(balance, "inj"));
+            execute(
+                deps.as_mut(),
+                env.clone(),
+                message_info(&config.operator, &[]),
+                ExecuteMsg::AdvanceEpoch {},
+            )
+            .unwrap();
+            reply(deps.as_mut(), env.clone(), withdraw_reply.clone()).unwrap();
+
+            let record = crate::state::EPOCH_HISTORY
+                .load(deps.as_ref().storage, epoch)
+                .unwrap();
+            assert!(record.total_rewards.is_zero());
+            assert_eq!(record.unexplained_surplus, Uint128::new(reported));
+        }
+
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&config.operator, &[]),
+            ExecuteMsg::SweepSurplus {},
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized { .. }));
+
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&config.admin, &[]),
+            ExecuteMsg::SweepSurplus {},
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            cosmwasm_std::CosmosMsg::Bank(cosmwasm_std::BankMsg::Send {
+                to_address: config.treasury.to_string(),
+                amount: coins(1_500_000, "inj"),
+            })
+        );
+        assert!(crate::state::UNEXPLAINED_SURPLUS
+            .load(deps.as_ref().storage)
+            .unwrap()
+            .is_zero());
+
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.clone(), vec![]);
         let err = execute(
+            deps.as_mut(),
+            env,
+            message_info(&config.admin, &[]),
+            ExecuteMsg::SweepSurplus {},
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NoSurplus));
+    }
+
+    #[test]
+    fn test_keeper_advance_epoch_and_snapshot() {
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+        let keeper = deps.api.addr_make("keeper");
+
+        let user1 = deps.api.addr_make("user1");
+        execute(
             deps.as_mut(),
             mock_env(),
-            info,
-            ExecuteMsg::DistributeRewards {},
+            message_info(&user1, &coins(1_000_000_000, "inj")),
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&keeper, &[]),
+            ExecuteMsg::AdvanceEpoch {},
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::EpochNotReady));
+
+        // Anyone can advance an ended epoch, but while the operator's grace period
+        // runs the keeper isn't paid
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(86400);
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&keeper, &[]),
+            ExecuteMsg::AdvanceEpoch {},
+        )
+        .unwrap();
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.clone(), coins(100_000_000, "inj"));
+        #[allow(deprecated)]
+        let withdraw_reply = Reply {
+            id: execute::ADVANCE_EPOCH_REPLY_ID,
+            payload: Binary::default(),
+            gas_used: 0,
+            result: cosmwasm_std::SubMsgResult::Ok(cosmwasm_std::SubMsgResponse {
+                events: vec![],
+                data: None,
+                msg_responses: vec![],
+            }),
+        };
+        reply(deps.as_mut(), env.clone(), withdraw_reply.clone()).unwrap();
+        let record = crate::state::EPOCH_HISTORY
+            .load(deps.as_ref().storage, 1)
+            .unwrap();
+        assert!(record.keeper_fee.is_zero());
+        assert_eq!(record.treasury_fee, Uint128::new(5_000_000));
+
+        // Snapshots stay with the operator until the epoch has ended and the grace
+        // period has passed
+        let snapshot_msg = ExecuteMsg::TakeSnapshot {
+            merkle_root: "abcd1234abcd1234abcd1234abcd1234abcd1234abcd1234abcd1234abcd1234"
+                .to_string(),
+            total_weight: Uint128::from(1000u128),
+            num_holders: 5,
+            snapshot_uri: "ipfs://snapshot".to_string(),
+        };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&keeper, &[]),
+            snapshot_msg.clone(),
         )
         .unwrap_err();
         assert!(matches!(err, ContractError::Unauthorized { .. }));
+
+        // After it, anyone can submit, but only while the distributor lets the
+        // snapshot be challenged
+        let challenge_period = std::rc::Rc::new(std::cell::Cell::new(0u64));
+        let period = challenge_period.clone();
+        deps.querier.update_wasm(move |_| {
+            let config = crate::msg::DistributorConfigResponse {
+                challenge_period_seconds: period.get(),
+            };
+            cosmwasm_std::SystemResult::Ok(cosmwasm_std::ContractResult::Ok(
+                cosmwasm_std::to_json_binary(&config).unwrap(),
+            ))
+        });
+        env.block.time = env.block.time.plus_seconds(86400 + 3600);
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&keeper, &[]),
+            snapshot_msg.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized { .. }));
+
+        challenge_period.set(600);
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&keeper, &[]),
+            snapshot_msg,
+        )
+        .unwrap();
+        let epoch_state = EPOCH_STATE.load(deps.as_ref().storage).unwrap();
+        assert!(epoch_state.snapshot_finalized);
+
+        // After the grace period the keeper earns 10% of the treasury fee
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&keeper, &[]),
+            ExecuteMsg::AdvanceEpoch {},
+        )
+        .unwrap();
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.clone(), coins(200_000_000, "inj"));
+        let res = reply(deps.as_mut(), env, withdraw_reply).unwrap();
+        assert!(res.messages.iter().any(|m| m.msg
+            == cosmwasm_std::CosmosMsg::Bank(cosmwasm_std::BankMsg::Send {
+                to_address: keeper.to_string(),
+                amount: coins(500_000, "inj"),
+            })));
+
+        let record = crate::state::EPOCH_HISTORY
+            .load(deps.as_ref().storage, 2)
+            .unwrap();
+        assert_eq!(record.keeper_fee, Uint128::new(500_000));
+        assert_eq!(record.treasury_fee, Uint128::new(4_500_000));
     }
 
     #[test]
-    fn test_take_snapshot() {
+    fn test_donate() {
         let mut deps = mock_dependencies();
         setup_contract(deps.as_mut());
+        let config = CONFIG.load(deps.as_ref().storage).unwrap();
+        let donor = deps.api.addr_make("donor");
 
-        let operator = deps.api.addr_make("operator");
-        let info = message_info(&operator, &[]);
+        // Base yield needs holders to accrue to
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&donor, &coins(20_000_000, "inj")),
+            ExecuteMsg::Donate {
+                target: crate::state::DonationTarget::BaseYield,
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NoCsinjSupply));
+
+        let user1 = deps.api.addr_make("user1");
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user1, &coins(1_000_000_000, "inj")),
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
+
+        // Pool donations are forwarded in full
         let res = execute(
             deps.as_mut(),
             mock_env(),
-            info,
-            ExecuteMsg::TakeSnapshot {
-                merkle_root: "abcd1234abcd1234abcd1234abcd1234abcd1234abcd1234abcd1234abcd1234"
-                    .to_string(),
-                total_weight: Uint128::from(1000u128),
-                num_holders: 5,
-                snapshot_uri: "ipfs://snapshot".to_string(),
-            },
+            message_info(&donor, &coins(10_000_000, "inj")),
+            ExecuteMsg::Donate {
+                target: crate::state::DonationTarget::RegularPool,
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            cosmwasm_std::CosmosMsg::Wasm(cosmwasm_std::WasmMsg::Execute {
+                contract_addr: config.reward_distributor.to_string(),
+                msg: cosmwasm_std::to_json_binary(
+                    &crate::msg::DistributorExecuteMsg::FundRegularPool {}
+                )
+                .unwrap(),
+                funds: coins(10_000_000, "inj"),
+            })
+        );
+        assert!(res.events.iter().any(|e| e.ty == "chance_donation"));
+
+        // Base yield donations raise the exchange rate
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&donor, &coins(20_000_000, "inj")),
+            ExecuteMsg::Donate {
+                target: crate::state::DonationTarget::BaseYield,
+            },
+        )
+        .unwrap();
+        let rate: crate::msg::ExchangeRateResponse = cosmwasm_std::from_json(
+            query(deps.as_ref(), mock_env(), QueryMsg::ExchangeRate {}).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(rate.total_inj_backing, Uint128::new(1_020_000_000));
+        assert_eq!(rate.rate, Decimal::from_ratio(102u128, 100u128));
+
+        let totals: crate::state::DonationTotals = cosmwasm_std::from_json(
+            query(deps.as_ref(), mock_env(), QueryMsg::DonationTotals {}).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(totals.regular_pool, Uint128::new(10_000_000));
+        assert_eq!(totals.big_pool, Uint128::zero());
+        assert_eq!(totals.base_yield, Uint128::new(20_000_000));
+    }
+
+    #[test]
+    fn test_distribute_rewards() {
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+
+        // Stake first so there's backing
+        let user1 = deps.api.addr_make("user1");
+        let info = message_info(&user1, &coins(1_000_000_000, "inj"));
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
+
+        // Simulate rewards arriving in contract balance (100M INJ rewards)
+        let mut env = mock_env();
+        let contract_addr = env.contract.address.clone();
+        deps.querier
+            .bank
+            .update_balance(contract_addr, coins(100_000_000, "inj"));
+
+        // H-01 FIX: Advance time by epoch duration (86400 seconds = 1 day)
+        env.block.time = env.block.time.plus_seconds(86400);
+
+        // Distribute rewards (step 2)
+        let operator = deps.api.addr_make("operator");
+        let info = message_info(&operator, &[]);
+        let res = execute(deps.as_mut(), env, info, ExecuteMsg::DistributeRewards {}).unwrap();
+
+        // Should have messages: fund regular pool, fund big pool, treasury send
+        assert!(res.messages.len() >= 3);
+
+        // Check epoch advanced
+        let epoch = EPOCH_STATE.load(deps.as_ref().storage).unwrap();
+        assert_eq!(epoch.current_epoch, 2);
+        assert!(!epoch.snapshot_finalized);
+
+        // Check exchange rate increased (base yield = 5% of 100M = 5M added to 1B backing)
+        let rate = EXCHANGE_RATE.load(deps.as_ref().storage).unwrap();
+        assert!(rate > Decimal::one());
+
+        // Check event
+        assert!(res.events.iter().any(|e| e.ty == "chance_epoch_advanced"));
+
+        // Epoch 1 is recorded in the history
+        let record: crate::state::EpochRecord = cosmwasm_std::from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::EpochRecord { epoch: 1 },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(record.epoch, 1);
+        assert_eq!(record.total_rewards, Uint128::from(100_000_000u128));
+        assert_eq!(record.base_yield, Uint128::from(5_000_000u128));
+        assert_eq!(
+            record.regular_pool_amount
+                + record.big_pool_amount
+                + record.base_yield
+                + record.treasury_fee,
+            record.total_rewards
+        );
+        assert_eq!(record.total_inj_backing, Uint128::from(1_005_000_000u128));
+        assert_eq!(record.total_csinj_supply, Uint128::from(1_000_000_000u128));
+        assert_eq!(record.exchange_rate, rate);
+
+        let history: Vec<crate::state::EpochRecord> = cosmwasm_std::from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::EpochHistory {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(history, vec![record]);
+        assert!(query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::EpochRecord { epoch: 2 }
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_yield_stats() {
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+
+        let yield_stats = |deps: Deps, lookback_epochs: u32| -> crate::msg::YieldStatsResponse {
+            cosmwasm_std::from_json(
+                query(deps, mock_env(), QueryMsg::YieldStats { lookback_epochs }).unwrap(),
+            )
+            .unwrap()
+        };
+
+        // No finished epoch yet
+        let stats = yield_stats(deps.as_ref(), 10);
+        assert_eq!(stats.num_epochs, 0);
+        assert_eq!(stats.base_apr, Decimal::zero());
+
+        let user1 = deps.api.addr_make("user1");
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user1, &coins(1_000_000_000, "inj")),
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
+
+        // Two one-day epochs with 100M INJ rewards each: 5M base yield per epoch
+        let mut env = mock_env();
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.clone(), coins(100_000_000, "inj"));
+        let operator = deps.api.addr_make("operator");
+        for _ in 0..2 {
+            env.block.time = env.block.time.plus_seconds(86400);
+            execute(
+                deps.as_mut(),
+                env.clone(),
+                message_info(&operator, &[]),
+                ExecuteMsg::DistributeRewards {},
+            )
+            .unwrap();
+        }
+
+        // Rate 1 -> 1.01 over two days
+        let stats = yield_stats(deps.as_ref(), 10);
+        assert_eq!(stats.num_epochs, 2);
+        assert_eq!(stats.from_epoch, Some(1));
+        assert_eq!(stats.to_epoch, Some(2));
+        assert_eq!(stats.start_rate, Decimal::one());
+        assert_eq!(stats.end_rate, Decimal::from_ratio(101u128, 100u128));
+        assert_eq!(stats.elapsed_seconds, 2 * 86400);
+        assert_eq!(stats.base_apr, Decimal::from_ratio(1825u128, 1000u128));
+        assert!(stats.base_apy > stats.base_apr);
+
+        // Only the last epoch: rate 1.005 -> 1.01 over one day
+        let stats = yield_stats(deps.as_ref(), 1);
+        assert_eq!(stats.num_epochs, 1);
+        assert_eq!(stats.from_epoch, Some(2));
+        assert_eq!(stats.start_rate, Decimal::from_ratio(1005u128, 1000u128));
+        assert_eq!(stats.elapsed_seconds, 86400);
+    }
+
+    #[test]
+    fn test_yield_only_mode() {
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+        let config = CONFIG.load(deps.as_ref().storage).unwrap();
+
+        let user1 = deps.api.addr_make("user1");
+        let user2 = deps.api.addr_make("user2");
+        for user in [&user1, &user2] {
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(user, &coins(1_000_000_000, "inj")),
+                ExecuteMsg::Stake {
+                    recipient: None,
+                    min_csinj_out: None,
+                    deadline: None,
+                },
+            )
+            .unwrap();
+        }
+
+        // Only csINJ can enter yield-only mode
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user2, &coins(1_000, "inj")),
+            ExecuteMsg::EnterYieldOnly { recipient: None },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::WrongYieldOnlyDenom { .. }));
+
+        // user2 moves all of their csINJ into yield-only mode
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user2, &[Coin::new(1_000_000_000u128, &config.csinj_denom)]),
+            ExecuteMsg::EnterYieldOnly { recipient: None },
+        )
+        .unwrap();
+
+        // 100M INJ rewards: the pools get 70M and 20M, half of which belongs to
+        // the yield-only half of the supply and is restaked for it
+        let mut env = mock_env();
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.clone(), coins(100_000_000, "inj"));
+        env.block.time = env.block.time.plus_seconds(86400);
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&config.operator, &[]),
+            ExecuteMsg::DistributeRewards {},
+        )
+        .unwrap();
+
+        let record = crate::state::EPOCH_HISTORY
+            .load(deps.as_ref().storage, 1)
+            .unwrap();
+        assert_eq!(record.regular_pool_amount, Uint128::new(35_000_000));
+        assert_eq!(record.big_pool_amount, Uint128::new(10_000_000));
+        assert_eq!(record.yield_only_amount, Uint128::new(45_000_000));
+        // Minted at the post-base-yield rate of 1.0025
+        assert_eq!(record.yield_only_minted, Uint128::new(44_887_780));
+        assert_eq!(record.total_inj_backing, Uint128::new(2_050_000_000));
+        assert_eq!(record.total_csinj_supply, Uint128::new(2_044_887_780));
+        assert!(record.exchange_rate >= Decimal::from_ratio(10025u128, 10000u128));
+
+        let position: crate::msg::YieldOnlyPositionResponse = cosmwasm_std::from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::YieldOnlyPosition {
+                    address: user2.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(position.shares, Uint128::new(1_000_000_000));
+        assert_eq!(position.csinj_amount, Uint128::new(1_044_887_780));
+
+        // The hub's own csINJ never plays the draws
+        let info: crate::msg::StakerInfoResponse = cosmwasm_std::from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::StakerInfo {
+                    address: env.contract.address.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert!(info.excluded);
+
+        // Exiting returns the grown csINJ position
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&user2, &[]),
+            ExecuteMsg::ExitYieldOnly {
+                shares: None,
+                recipient: None,
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            cosmwasm_std::CosmosMsg::Bank(cosmwasm_std::BankMsg::Send {
+                to_address: user2.to_string(),
+                amount: coins(1_044_887_780, &config.csinj_denom),
+            })
+        );
+        let totals: crate::state::YieldOnlyTotals = cosmwasm_std::from_json(
+            query(deps.as_ref(), mock_env(), QueryMsg::YieldOnlyTotals {}).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(totals, crate::state::YieldOnlyTotals::default());
+
+        let err = execute(
+            deps.as_mut(),
+            env,
+            message_info(&user2, &[]),
+            ExecuteMsg::ExitYieldOnly {
+                shares: None,
+                recipient: None,
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::InsufficientYieldOnlyShares { .. }
+        ));
+    }
+
+    #[test]
+    fn test_sponsor_rewards_count_open_unbonding_batch() {
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+        let config = CONFIG.load(deps.as_ref().storage).unwrap();
+
+        let user1 = deps.api.addr_make("user1");
+        let sponsor = deps.api.addr_make("sponsor");
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user1, &coins(1_000_000_000, "inj")),
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&sponsor, &coins(1_000_000_000, "inj")),
+            ExecuteMsg::Sponsor {},
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user1, &[Coin::new(500_000_000u128, &config.csinj_denom)]),
+            ExecuteMsg::Unstake {
+                recipient: None,
+                min_inj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
+        mock_delegations(&mut deps, &[(VAL_A, 1_000_000_000), (VAL_B, 1_000_000_000)]);
+
+        // 2000M INJ delegated: 500M backing, 500M in the open batch, 1000M sponsored.
+        // The sponsors earned half of the 100M rewards, not 1000 / 1500 of them.
+        // The balance also holds the 500M owed to the unstake.
+        let mut env = mock_env();
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.clone(), coins(600_000_000, "inj"));
+        env.block.time = env.block.time.plus_seconds(86400);
+        distribute(deps.as_mut(), env);
+
+        let record = crate::state::EPOCH_HISTORY
+            .load(deps.as_ref().storage, 1)
+            .unwrap();
+        assert_eq!(record.sponsor_rewards, Uint128::new(50_000_000));
+    }
+
+    #[test]
+    fn test_sponsorship() {
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+        let config = CONFIG.load(deps.as_ref().storage).unwrap();
+
+        let user1 = deps.api.addr_make("user1");
+        let sponsor = deps.api.addr_make("sponsor");
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user1, &coins(1_000_000_000, "inj")),
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&sponsor, &coins(1_000_000_000, "inj")),
+            ExecuteMsg::Sponsor {},
+        )
+        .unwrap();
+
+        // Sponsoring mints nothing and doesn't move the exchange rate
+        let state: crate::msg::ExchangeRateResponse = cosmwasm_std::from_json(
+            query(deps.as_ref(), mock_env(), QueryMsg::ExchangeRate {}).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(state.total_inj_backing, Uint128::new(1_000_000_000));
+        assert_eq!(state.total_csinj_supply, Uint128::new(1_000_000_000));
+
+        // 100M INJ rewards: the sponsored half (50M) goes to the pools at 7:2, the
+        // stakers' half is split as usual
+        let mut env = mock_env();
+        deps.querier
+            .bank
+            .update_balance(env.contract.address.clone(), coins(100_000_000, "inj"));
+        env.block.time = env.block.time.plus_seconds(86400);
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&config.operator, &[]),
+            ExecuteMsg::DistributeRewards {},
+        )
+        .unwrap();
+
+        let record = crate::state::EPOCH_HISTORY
+            .load(deps.as_ref().storage, 1)
+            .unwrap();
+        assert_eq!(record.sponsor_rewards, Uint128::new(50_000_000));
+        assert_eq!(record.regular_pool_amount, Uint128::new(73_888_888));
+        assert_eq!(record.big_pool_amount, Uint128::new(21_111_112));
+        assert_eq!(record.base_yield, Uint128::new(2_500_000));
+        assert_eq!(record.treasury_fee, Uint128::new(2_500_000));
+        assert_eq!(record.total_inj_backing, Uint128::new(1_002_500_000));
+
+        let sponsors: crate::msg::SponsorsResponse = cosmwasm_std::from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::Sponsors {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(sponsors.sponsors.len(), 1);
+        assert_eq!(sponsors.sponsors[0].address, sponsor);
+        assert_eq!(sponsors.sponsors[0].inj_amount, Uint128::new(1_000_000_000));
+        assert_eq!(sponsors.total_sponsored, Uint128::new(1_000_000_000));
+
+        // Sponsors never play the draws
+        let info: crate::msg::StakerInfoResponse = cosmwasm_std::from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::StakerInfo {
+                    address: sponsor.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert!(info.excluded);
+
+        // Withdrawing queues the principal in the open unbonding batch
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&sponsor, &[]),
+            ExecuteMsg::WithdrawSponsorship {},
+        )
+        .unwrap();
+        let request = crate::state::UNSTAKE_REQUESTS
+            .load(deps.as_ref().storage, (&sponsor, 0))
+            .unwrap();
+        assert_eq!(request.inj_amount, Uint128::new(1_000_000_000));
+        assert_eq!(request.csinj_burned, Uint128::zero());
+        assert!(!crate::state::SPONSOR_SHARES.has(deps.as_ref().storage, &sponsor));
+
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&sponsor, &[]),
+            ExecuteMsg::WithdrawSponsorship {},
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NoSponsorship { .. }));
+
+        // A former sponsor stays excluded
+        let info: crate::msg::StakerInfoResponse = cosmwasm_std::from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::StakerInfo {
+                    address: sponsor.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert!(info.excluded);
+
+        // csINJ sent to a sponsor leaves the snapshot totals (1% tolerance)
+        sudo(
+            deps.as_mut(),
+            env.clone(),
+            crate::msg::SudoMsg::TrackBeforeSend {
+                from: user1.to_string(),
+                to: sponsor.to_string(),
+                amount: Coin::new(300_000_000u128, &config.csinj_denom),
+            },
+        )
+        .unwrap();
+        let totals = crate::state::SPONSOR_TOTALS
+            .load(deps.as_ref().storage)
+            .unwrap();
+        assert_eq!(totals.csinj, Uint128::new(300_000_000));
+        let err = execute(
+            deps.as_mut(),
+            env,
+            message_info(&config.operator, &[]),
+            ExecuteMsg::TakeSnapshot {
+                merkle_root: "abcd1234abcd1234abcd1234abcd1234abcd1234abcd1234abcd1234abcd1234"
+                    .to_string(),
+                total_weight: Uint128::new(1_000_000_000),
+                num_holders: 1,
+                snapshot_uri: "ipfs://snapshot".to_string(),
+            },
+        )
+        .unwrap_err();
+        match err {
+            ContractError::SnapshotWeightTooHigh { max_weight, .. } => {
+                assert_eq!(max_weight, Uint128::new(707_000_000));
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_distribute_rewards_unauthorized() {
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+
+        let random_user = deps.api.addr_make("random_user");
+        let info = message_info(&random_user, &[]);
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::DistributeRewards {},
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized { .. }));
+    }
+
+    #[test]
+    fn test_take_snapshot() {
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+        TOTAL_CSINJ_SUPPLY
+            .save(deps.as_mut().storage, &Uint128::from(1000u128))
+            .unwrap();
+
+        let operator = deps.api.addr_make("operator");
+        let info = message_info(&operator, &[]);
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::TakeSnapshot {
+                merkle_root: "abcd1234abcd1234abcd1234abcd1234abcd1234abcd1234abcd1234abcd1234"
+                    .to_string(),
+                total_weight: Uint128::from(1000u128),
+                num_holders: 5,
+                snapshot_uri: "ipfs://snapshot".to_string(),
+            },
+        )
+        .unwrap();
+
+        let epoch = EPOCH_STATE.load(deps.as_ref().storage).unwrap();
+        assert!(epoch.snapshot_finalized);
+        assert_eq!(
+            epoch.snapshot_merkle_root,
+            Some("abcd1234abcd1234abcd1234abcd1234abcd1234abcd1234abcd1234abcd1234".to_string())
+        );
+        assert_eq!(epoch.snapshot_total_weight, Uint128::from(1000u128));
+        assert_eq!(epoch.snapshot_num_holders, 5);
+
+        // Should have forwarded to distributor
+        assert_eq!(res.messages.len(), 1);
+    }
+
+    #[test]
+    fn test_take_snapshot_duplicate() {
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+        TOTAL_CSINJ_SUPPLY
+            .save(deps.as_mut().storage, &Uint128::from(1000u128))
+            .unwrap();
+
+        let operator = deps.api.addr_make("operator");
+        let info = message_info(&operator, &[]);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info.clone(),
+            ExecuteMsg::TakeSnapshot {
+                merkle_root: "abcd1234abcd1234abcd1234abcd1234abcd1234abcd1234abcd1234abcd1234"
+                    .to_string(),
+                total_weight: Uint128::from(1000u128),
+                num_holders: 5,
+                snapshot_uri: "ipfs://snapshot".to_string(),
+            },
+        )
+        .unwrap();
+
+        // Second snapshot should fail while the distributor's copy stands
+        deps.querier.update_wasm(|_| {
+            let snapshot = Some(crate::msg::DistributorSnapshotResponse { invalidated: false });
+            cosmwasm_std::SystemResult::Ok(cosmwasm_std::ContractResult::Ok(
+                cosmwasm_std::to_json_binary(&snapshot).unwrap(),
+            ))
+        });
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::TakeSnapshot {
+                merkle_root: "ef125678ef125678ef125678ef125678ef125678ef125678ef125678ef125678"
+                    .to_string(),
+                total_weight: Uint128::from(2000u128),
+                num_holders: 10,
+                snapshot_uri: "ipfs://snapshot2".to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::SnapshotAlreadyTaken));
+    }
+
+    #[test]
+    fn test_take_snapshot_checks_totals() {
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+        TOTAL_CSINJ_SUPPLY
+            .save(deps.as_mut().storage, &Uint128::from(10_000u128))
+            .unwrap();
+
+        let admin = deps.api.addr_make("admin");
+        let operator = deps.api.addr_make("operator");
+        let pool = deps.api.addr_make("dex_pool");
+        let config = CONFIG.load(deps.as_ref().storage).unwrap();
+        deps.querier
+            .bank
+            .update_balance(&pool, coins(2_000, &config.csinj_denom));
+
+        let snapshot = |total_weight: u128, num_holders: u32| ExecuteMsg::TakeSnapshot {
+            merkle_root: "abcd1234abcd1234abcd1234abcd1234abcd1234abcd1234abcd1234abcd1234"
+                .to_string(),
+            total_weight: Uint128::from(total_weight),
+            num_holders,
+            snapshot_uri: "ipfs://snapshot".to_string(),
+        };
+
+        // Far above the supply
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&operator, &[]),
+            snapshot(1_000_000, 5),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::SnapshotWeightTooHigh { .. }));
+
+        // Only admin can exclude addresses
+        let exclude = ExecuteMsg::UpdateSnapshotExclusions {
+            add: vec![pool.to_string()],
+            remove: vec![],
+        };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&operator, &[]),
+            exclude.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized { .. }));
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            exclude,
+        )
+        .unwrap();
+
+        // The full supply is now too much: the pool's 2000 are excluded (1% tolerance)
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&operator, &[]),
+            snapshot(10_000, 5),
+        )
+        .unwrap_err();
+        match err {
+            ContractError::SnapshotWeightTooHigh { max_weight, .. } => {
+                assert_eq!(max_weight, Uint128::from(8_080u128));
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+
+        // More holders than weight units
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&operator, &[]),
+            snapshot(8_000, 9_000),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidSnapshotHolders { .. }));
+
+        // Lower weights are fine: ineligible holders are left out of the tree too
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&operator, &[]),
+            snapshot(6_000, 5),
+        )
+        .unwrap();
+        let bounds = EPOCH_STATE
+            .load(deps.as_ref().storage)
+            .unwrap()
+            .snapshot_bounds
+            .unwrap();
+        assert_eq!(bounds.csinj_supply, Uint128::from(10_000u128));
+        assert_eq!(bounds.excluded_addresses, vec![pool.clone()]);
+        assert_eq!(bounds.excluded_balance, Uint128::from(2_000u128));
+
+        // The operator and the distributor can read the exclusion list
+        let exclusions: Vec<cosmwasm_std::Addr> = cosmwasm_std::from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::SnapshotExclusions {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(exclusions, vec![pool.clone()]);
+        let info: crate::msg::StakerInfoResponse = cosmwasm_std::from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::StakerInfo {
+                    address: pool.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert!(info.excluded);
+    }
+
+    #[test]
+    fn test_update_validators() {
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+
+        let admin = deps.api.addr_make("admin");
+        let info = message_info(&admin, &[]);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::UpdateValidators {
+                add: vec!["injvaloper1zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzz".to_string()],
+                remove: vec!["injvaloper1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqj9".to_string()],
+                weights: None,
+            },
+        )
+        .unwrap();
+
+        let config = CONFIG.load(deps.as_ref().storage).unwrap();
+        assert_eq!(
+            config.validators,
+            vec![
+                "injvaloper1xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx".to_string(),
+                "injvaloper1zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzz".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_update_validators_sets_weights() {
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+
+        let val_a = "injvaloper1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqj9".to_string();
+        let val_b = "injvaloper1xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx".to_string();
+        let val_c = "injvaloper1zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzz".to_string();
+
+        // Defaults to equal weights
+        let config = CONFIG.load(deps.as_ref().storage).unwrap();
+        assert_eq!(config.validator_weights, vec![1, 1]);
+
+        // Add a validator and set weights in the same call
+        let admin = deps.api.addr_make("admin");
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            ExecuteMsg::UpdateValidators {
+                add: vec![val_c.clone()],
+                remove: vec![],
+                weights: Some(vec![
+                    ValidatorWeight {
+                        validator: val_a.clone(),
+                        weight: 3,
+                    },
+                    ValidatorWeight {
+                        validator: val_c.clone(),
+                        weight: 2,
+                    },
+                ]),
+            },
+        )
+        .unwrap();
+        let config = CONFIG.load(deps.as_ref().storage).unwrap();
+        assert_eq!(config.validator_weights, vec![3, 1, 2]);
+
+        // Removing a validator drops its weight
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            ExecuteMsg::UpdateValidators {
+                add: vec![],
+                remove: vec![val_b.clone()],
+                weights: None,
+            },
+        )
+        .unwrap();
+        let config = CONFIG.load(deps.as_ref().storage).unwrap();
+        assert_eq!(config.validators, vec![val_a.clone(), val_c.clone()]);
+        assert_eq!(config.validator_weights, vec![3, 2]);
+
+        // Unknown validator rejected
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            ExecuteMsg::UpdateValidators {
+                add: vec![],
+                remove: vec![],
+                weights: Some(vec![ValidatorWeight {
+                    validator: val_b,
+                    weight: 1,
+                }]),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::ValidatorNotInSet { .. }));
+
+        // All-zero weights rejected
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            ExecuteMsg::UpdateValidators {
+                add: vec![],
+                remove: vec![],
+                weights: Some(vec![
+                    ValidatorWeight {
+                        validator: val_a,
+                        weight: 0,
+                    },
+                    ValidatorWeight {
+                        validator: val_c,
+                        weight: 0,
+                    },
+                ]),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidValidatorWeights { .. }));
+    }
+
+    #[test]
+    fn test_weighted_delegation_and_undelegation() {
+        use cosmwasm_std::{CosmosMsg, StakingMsg};
+
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+        let env = mock_env();
+
+        let val_a = VAL_A.to_string();
+        let val_b = VAL_B.to_string();
+
+        // Validator A already holds 300, B holds 100 (equal target weights)
+        mock_delegations(&mut deps, &[(VAL_A, 300), (VAL_B, 100)]);
+
+        // Staking 100 tops up the under-weight validator only
+        let user1 = deps.api.addr_make("user1");
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&user1, &coins(100, "inj")),
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
+        let delegated: Vec<(String, u128)> = res
+            .messages
+            .iter()
+            .filter_map(|m| match &m.msg {
+                CosmosMsg::Staking(StakingMsg::Delegate { validator, amount }) => {
+                    Some((validator.clone(), amount.amount.u128()))
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(delegated, vec![(val_b.clone(), 100)]);
+
+        // Unstaking 100 takes it from the over-weight validator only
+        let config = CONFIG.load(deps.as_ref().storage).unwrap();
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&user1, &[Coin::new(100u128, &config.csinj_denom)]),
+            ExecuteMsg::Unstake {
+                recipient: None,
+                min_inj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
+        let mut env = env;
+        env.block.time = env.block.time.plus_seconds(86400);
+        let res = distribute(deps.as_mut(), env);
+        let undelegated: Vec<(String, u128)> = res
+            .messages
+            .iter()
+            .filter_map(|m| match &m.msg {
+                CosmosMsg::Staking(StakingMsg::Undelegate { validator, amount }) => {
+                    Some((validator.clone(), amount.amount.u128()))
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(undelegated, vec![(val_a, 100)]);
+    }
+
+    #[test]
+    fn test_batch_undelegation_respects_actual_delegations() {
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+
+        let user1 = deps.api.addr_make("user1");
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user1, &coins(1_030, "inj")),
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
+        let config = CONFIG.load(deps.as_ref().storage).unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user1, &[Coin::new(400u128, &config.csinj_denom)]),
+            ExecuteMsg::Unstake {
+                recipient: None,
+                min_inj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
+
+        // Validator A was slashed down to 30, far below its even share of 200
+        mock_delegations(&mut deps, &[(VAL_A, 30), (VAL_B, 1_000)]);
+
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(86400);
+        let res = distribute(deps.as_mut(), env.clone());
+
+        // The batch bears 400/1030 of A's 485 loss, and what is left of it
+        // comes entirely from validator B
+        let batch = UNBONDING_BATCHES.load(deps.as_ref().storage, 1).unwrap();
+        assert_eq!(batch.slashed_inj, Uint128::new(188));
+        assert_eq!(
+            batch.undelegations,
+            vec![BatchUndelegation {
+                validator: VAL_B.to_string(),
+                amount: Uint128::new(212),
+            }]
+        );
+        let event = res
+            .events
+            .iter()
+            .find(|e| e.ty == "chance_unbonding_batch_submitted")
+            .unwrap();
+        assert!(event
+            .attributes
+            .iter()
+            .any(|a| a.key == "undelegations" && a.value == format!("{}:212", VAL_B)));
+
+        // Validator A's shortfall was booked as a slashing loss
+        assert!(res
+            .events
+            .iter()
+            .any(|e| e.ty == "chance_slashing_detected"));
+
+        // The unstake event previews the split, skipping the under-delegated validator
+        let info = message_info(&user1, &[Coin::new(600u128, &config.csinj_denom)]);
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            ExecuteMsg::Unstake {
+                recipient: None,
+                min_inj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
+        let event = res
+            .events
+            .iter()
+            .find(|e| e.ty == "chance_unstake")
+            .unwrap();
+        let split = event
+            .attributes
+            .iter()
+            .find(|a| a.key == "undelegations")
+            .unwrap();
+        assert!(split.value.starts_with(VAL_B));
+        assert!(!split.value.contains(VAL_A));
+
+        // A batch larger than all delegations stays open instead of overdrawing,
+        // and the epoch still closes
+        mock_delegations(&mut deps, &[]);
+        env.block.time = env.block.time.plus_seconds(4 * 86400);
+        let res = distribute(deps.as_mut(), env.clone());
+        assert!(!res
+            .events
+            .iter()
+            .any(|e| e.ty == "chance_unbonding_batch_submitted"));
+        let batch = UNBONDING_BATCHES.load(deps.as_ref().storage, 2).unwrap();
+        assert_eq!(batch.submitted_at, None);
+        assert_eq!(
+            EPOCH_STATE
+                .load(deps.as_ref().storage)
+                .unwrap()
+                .current_epoch,
+            3
+        );
+
+        // It is submitted once the delegations cover it again
+        mock_delegations(&mut deps, &[(VAL_A, 30), (VAL_B, 1_000)]);
+        env.block.time = env.block.time.plus_seconds(86400);
+        let res = distribute(deps.as_mut(), env);
+        assert!(res
+            .events
+            .iter()
+            .any(|e| e.ty == "chance_unbonding_batch_submitted"));
+        let batch = UNBONDING_BATCHES.load(deps.as_ref().storage, 2).unwrap();
+        assert!(batch.submitted_at.is_some());
+    }
+
+    #[test]
+    fn test_slashing_detected_on_stake() {
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+
+        // 1000 INJ staked and split evenly
+        let user1 = deps.api.addr_make("user1");
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user1, &coins(1_000, "inj")),
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
+
+        // Validator A gets slashed by 50
+        mock_delegations(&mut deps, &[(VAL_A, 450), (VAL_B, 500)]);
+
+        // The next stake books the loss before pricing the new csINJ
+        let user2 = deps.api.addr_make("user2");
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user2, &coins(95, "inj")),
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
+
+        let event = res
+            .events
+            .iter()
+            .find(|e| e.ty == "chance_slashing_detected")
+            .unwrap();
+        let attr = |key: &str| {
+            event
+                .attributes
+                .iter()
+                .find(|a| a.key == key)
+                .unwrap()
+                .value
+                .clone()
+        };
+        assert_eq!(attr("total_loss"), "50");
+        assert_eq!(attr("losses"), format!("{}:50", VAL_A));
+        assert_eq!(attr("new_backing"), "950");
+
+        // 95 INJ at a rate of 0.95 mints 100 csINJ
+        let supply = TOTAL_CSINJ_SUPPLY.load(deps.as_ref().storage).unwrap();
+        assert_eq!(supply, Uint128::new(1_100));
+        let backing = TOTAL_INJ_BACKING.load(deps.as_ref().storage).unwrap();
+        assert_eq!(backing, Uint128::new(1_045));
+
+        // The loss is only booked once: mirror the ledger and stake again
+        let expected: Vec<(String, Uint128)> = EXPECTED_DELEGATIONS
+            .range(
+                deps.as_ref().storage,
+                None,
+                None,
+                cosmwasm_std::Order::Ascending,
+            )
+            .map(|r| r.unwrap())
+            .collect();
+        assert_eq!(expected.iter().map(|(_, a)| a.u128()).sum::<u128>(), 1_045);
+        let mirrored: Vec<(&str, u128)> = expected
+            .iter()
+            .map(|(v, a)| (v.as_str(), a.u128()))
+            .collect();
+        mock_delegations(&mut deps, &mirrored);
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user2, &coins(95, "inj")),
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
+        assert!(!res
+            .events
+            .iter()
+            .any(|e| e.ty == "chance_slashing_detected"));
+    }
+
+    #[test]
+    fn test_slashing_applied_to_unstake_requests() {
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+        let config = CONFIG.load(deps.as_ref().storage).unwrap();
+
+        // 1000 INJ staked and split evenly, then 200 csINJ unstaked into the open batch
+        let user1 = deps.api.addr_make("user1");
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user1, &coins(1_000, "inj")),
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user1, &[Coin::new(200u128, &config.csinj_denom)]),
+            ExecuteMsg::Unstake {
+                recipient: None,
+                min_inj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
+
+        // Validator A is slashed by 100 while the batch is still delegated:
+        // the open batch carries 200/1000 of the loss
+        mock_delegations(&mut deps, &[(VAL_A, 400), (VAL_B, 500)]);
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(86400);
+        let res = distribute(deps.as_mut(), env.clone());
+        let slashing = res
+            .events
+            .iter()
+            .find(|e| e.ty == "chance_slashing_detected")
+            .unwrap();
+        assert!(slashing
+            .attributes
+            .iter()
+            .any(|a| a.key == "open_batch_loss" && a.value == "20"));
+        assert_eq!(
+            TOTAL_INJ_BACKING.load(deps.as_ref().storage).unwrap(),
+            Uint128::new(720)
+        );
+
+        // Only the post-slash amount was undelegated
+        let batch = UNBONDING_BATCHES.load(deps.as_ref().storage, 1).unwrap();
+        assert_eq!(batch.slashed_inj, Uint128::new(20));
+        let undelegated: Uint128 = batch.undelegations.iter().map(|u| u.amount).sum();
+        assert_eq!(undelegated, Uint128::new(180));
+        let from_b = batch
+            .undelegations
+            .iter()
+            .find(|u| u.validator == VAL_B)
+            .map(|u| u.amount)
+            .unwrap();
+        assert!(!from_b.is_zero());
+
+        // Validator B is slashed by 10% while the batch is unbonding
+        let expected_b = EXPECTED_DELEGATIONS
+            .load(deps.as_ref().storage, VAL_B)
+            .unwrap();
+        let expected_a = EXPECTED_DELEGATIONS
+            .load(deps.as_ref().storage, VAL_A)
+            .unwrap();
+        mock_delegations(
+            &mut deps,
+            &[
+                (VAL_A, expected_a.u128()),
+                (VAL_B, expected_b.u128() * 9 / 10),
+            ],
+        );
+        let user2 = deps.api.addr_make("user2");
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&user2, &coins(100, "inj")),
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
+        let slashing = res
+            .events
+            .iter()
+            .find(|e| e.ty == "chance_slashing_detected")
+            .unwrap();
+        let unbonding_cut = from_b.multiply_ratio(1u128, 10u128);
+        assert!(slashing
+            .attributes
+            .iter()
+            .any(|a| a.key == "unbonding_loss" && a.value == unbonding_cut.to_string()));
+
+        let batch = UNBONDING_BATCHES.load(deps.as_ref().storage, 1).unwrap();
+        let payout = Uint128::new(180) - unbonding_cut;
+        assert_eq!(batch.total_inj - batch.slashed_inj, payout);
+        assert_eq!(
+            PENDING_UNSTAKE_TOTAL.load(deps.as_ref().storage).unwrap(),
+            payout
+        );
+
+        // The query reports both the original and the adjusted amount
+        let entries: Vec<crate::msg::UnstakeRequestEntry> = cosmwasm_std::from_json(
+            query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::UnstakeRequests {
+                    address: user1.to_string(),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(entries[0].request.inj_amount, Uint128::new(200));
+        assert_eq!(entries[0].adjusted_inj_amount, payout);
+
+        // Claiming pays out the post-slash amount
+        env.block.time = env.block.time.plus_seconds(22 * 86400);
+        let res = execute(
+            deps.as_mut(),
+            env,
+            message_info(&user1, &[]),
+            ExecuteMsg::ClaimUnstaked {
+                request_ids: vec![0],
+                recipient: None,
+            },
+        )
+        .unwrap();
+        assert!(res
+            .attributes
+            .iter()
+            .any(|a| a.key == "total_claimed" && a.value == payout.to_string()));
+        assert!(PENDING_UNSTAKE_TOTAL
+            .load(deps.as_ref().storage)
+            .unwrap()
+            .is_zero());
+    }
+
+    #[test]
+    fn test_migrate_legacy_unstake_requests() {
+        use crate::state::{LegacyUnstakeRequest, LEGACY_UNSTAKE_REQUESTS};
+
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+
+        // Pre-batch deployment: no batch state, requests carry their own unlock time
+        CURRENT_BATCH_ID.remove(deps.as_mut().storage);
+        let user1 = deps.api.addr_make("user1");
+        let user2 = deps.api.addr_make("user2");
+        let env = mock_env();
+        let early = env.block.time.plus_seconds(86400);
+        let late = env.block.time.plus_seconds(5 * 86400);
+        for (owner, id, amount, unlock_time) in [
+            (&user1, 0u64, 100u128, early),
+            (&user1, 1, 50, late),
+            (&user2, 0, 30, early),
+        ] {
+            LEGACY_UNSTAKE_REQUESTS
+                .save(
+                    deps.as_mut().storage,
+                    (owner, id),
+                    &LegacyUnstakeRequest {
+                        inj_amount: Uint128::new(amount),
+                        csinj_burned: Uint128::new(amount),
+                        unlock_time,
+                        claimed: false,
+                    },
+                )
+                .unwrap();
+        }
+        PENDING_UNSTAKE_TOTAL
+            .save(deps.as_mut().storage, &Uint128::new(180))
+            .unwrap();
+        mock_delegations(&mut deps, &[(VAL_A, 400), (VAL_B, 500)]);
+
+        let res = migrate(deps.as_mut(), env.clone(), MigrateMsg {}).unwrap();
+        assert!(res
+            .attributes
+            .iter()
+            .any(|a| a.key == "legacy_batches" && a.value == "2"));
+
+        // One submitted batch per unlock time, the open batch after them
+        let batch = UNBONDING_BATCHES.load(deps.as_ref().storage, 1).unwrap();
+        assert_eq!(batch.total_inj, Uint128::new(130));
+        assert_eq!(batch.num_requests, 2);
+        assert_eq!(batch.unlock_time, Some(early));
+        let batch = UNBONDING_BATCHES.load(deps.as_ref().storage, 2).unwrap();
+        assert_eq!(batch.total_inj, Uint128::new(50));
+        assert_eq!(batch.unlock_time, Some(late));
+        assert_eq!(CURRENT_BATCH_ID.load(deps.as_ref().storage).unwrap(), 3);
+
+        let request = UNSTAKE_REQUESTS
+            .load(deps.as_ref().storage, (&user1, 1))
+            .unwrap();
+        assert_eq!(request.batch_id, 2);
+        assert_eq!(request.batch_shares, Uint128::new(50));
+        assert_eq!(
+            EXPECTED_DELEGATIONS
+                .load(deps.as_ref().storage, VAL_B)
+                .unwrap(),
+            Uint128::new(500)
+        );
+
+        // Legacy requests unlock at their original time
+        let mut claim_env = env.clone();
+        claim_env.block.time = early;
+        let res = execute(
+            deps.as_mut(),
+            claim_env,
+            message_info(&user1, &[]),
+            ExecuteMsg::ClaimUnstaked {
+                request_ids: vec![0],
+                recipient: None,
+            },
+        )
+        .unwrap();
+        assert!(res
+            .attributes
+            .iter()
+            .any(|a| a.key == "total_claimed" && a.value == "100"));
+
+        // A second migration leaves the batches alone
+        let res = migrate(deps.as_mut(), env, MigrateMsg {}).unwrap();
+        assert!(res
+            .attributes
+            .iter()
+            .any(|a| a.key == "legacy_batches" && a.value == "0"));
+        assert_eq!(CURRENT_BATCH_ID.load(deps.as_ref().storage).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_migrate_config_defaults() {
+        use cosmwasm_std::Storage;
+
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+
+        // Store the config as the first deployment did, without the later fields
+        let mut stored: serde_json::Value =
+            serde_json::from_slice(&deps.storage.get(b"config").unwrap()).unwrap();
+        let fields = stored.as_object_mut().unwrap();
+        for field in [
+            "guardian",
+            "pending_admin",
+            "pending_operator",
+            "validator_weights",
+            "timelock_seconds",
+            "snapshot_tolerance_bps",
+            "keeper_fee_bps",
+            "snapshot_grace_seconds",
+        ] {
+            fields.remove(field);
+        }
+        deps.storage
+            .set(b"config", &serde_json::to_vec(&stored).unwrap());
+
+        migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+
+        let config = CONFIG.load(deps.as_ref().storage).unwrap();
+        assert_eq!(config.validator_weights, vec![1, 1]);
+        assert_eq!(config.timelock_seconds, 0);
+        assert_eq!(
+            config.snapshot_tolerance_bps,
+            execute::DEFAULT_SNAPSHOT_TOLERANCE_BPS
+        );
+        assert_eq!(config.keeper_fee_bps, execute::DEFAULT_KEEPER_FEE_BPS);
+        assert_eq!(
+            config.snapshot_grace_seconds,
+            execute::DEFAULT_SNAPSHOT_GRACE_SECONDS
+        );
+    }
+
+    #[test]
+    fn test_pause_and_unpause() {
+        use crate::msg::PauseStateResponse;
+        use crate::state::PausableOperation;
+
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+
+        let admin = deps.api.addr_make("admin");
+        let guardian = deps.api.addr_make("guardian");
+        let user1 = deps.api.addr_make("user1");
+
+        // Only the admin can appoint the guardian
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&guardian, &[]),
+            ExecuteMsg::UpdateGuardian {
+                guardian: Some(guardian.to_string()),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized { .. }));
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            ExecuteMsg::UpdateGuardian {
+                guardian: Some(guardian.to_string()),
+            },
+        )
+        .unwrap();
+
+        // Other accounts cannot pause
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user1, &[]),
+            ExecuteMsg::Pause {
+                operations: vec![PausableOperation::Stake],
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized { .. }));
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&guardian, &[]),
+            ExecuteMsg::Pause {
+                operations: vec![PausableOperation::Stake, PausableOperation::EpochAdvance],
+            },
+        )
+        .unwrap();
+        assert!(res
+            .events
+            .iter()
+            .any(|e| e.ty == "chance_operations_paused"));
+
+        let state: PauseStateResponse = cosmwasm_std::from_json(
+            query(deps.as_ref(), mock_env(), QueryMsg::PauseState {}).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(state.guardian, Some(guardian.clone()));
+        assert_eq!(
+            state.paused,
+            vec![PausableOperation::Stake, PausableOperation::EpochAdvance]
+        );
+
+        // Paused operations are rejected
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user1, &coins(1_000, "inj")),
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::OperationPaused { .. }));
+        let operator = deps.api.addr_make("operator");
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&operator, &[]),
+            ExecuteMsg::ClaimRewards {},
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::OperationPaused { .. }));
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user1, &[]),
+            ExecuteMsg::EnterYieldOnly { recipient: None },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::OperationPaused { .. }));
+
+        // Claims are never paused
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user1, &[]),
+            ExecuteMsg::ClaimUnstaked {
+                request_ids: vec![0],
+                recipient: None,
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::UnstakeRequestNotFound { .. }));
+
+        // Only the admin can unpause
+        let unpause = ExecuteMsg::Unpause {
+            operations: vec![PausableOperation::Stake],
+        };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&guardian, &[]),
+            unpause.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized { .. }));
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            unpause,
+        )
+        .unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user1, &coins(1_000, "inj")),
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_operator_handover() {
+        use chance_staking_common::types::Role;
+
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+
+        let admin = deps.api.addr_make("admin");
+        let operator = deps.api.addr_make("operator");
+        let new_operator = deps.api.addr_make("new_operator");
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            ExecuteMsg::ProposeOperator {
+                new_operator: new_operator.to_string(),
+                expires_in_seconds: None,
+            },
+        )
+        .unwrap();
+
+        // The pending proposal is visible in the config, the old operator stays active
+        let config: Config =
+            cosmwasm_std::from_json(query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap())
+                .unwrap();
+        assert_eq!(config.operator, operator);
+        assert_eq!(
+            config.pending_operator.unwrap().new_address,
+            new_operator.clone()
+        );
+        assert!(config.pending_admin.is_none());
+
+        // The operator proposal cannot be used to take over the admin role
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&new_operator, &[]),
+            ExecuteMsg::AcceptAdmin {},
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NoPendingTransfer { .. }));
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&new_operator, &[]),
+            ExecuteMsg::AcceptOperator {},
+        )
+        .unwrap();
+        let config = CONFIG.load(deps.as_ref().storage).unwrap();
+        assert_eq!(config.operator, new_operator);
+        assert!(config.pending_operator.is_none());
+
+        // Nothing left to cancel
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            ExecuteMsg::CancelProposal {
+                role: Role::Operator,
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NoPendingTransfer { .. }));
+    }
+
+    #[test]
+    fn test_timelocked_param_change() {
+        use crate::msg::ParamChange;
+        use crate::state::PendingChange;
+
+        let mut deps = mock_dependencies();
+        let admin = deps.api.addr_make("admin");
+        let mut msg = default_instantiate_msg();
+        msg.timelock_seconds = Some(86400);
+        instantiate(deps.as_mut(), mock_env(), message_info(&admin, &[]), msg).unwrap();
+
+        // Economic params can no longer be changed directly
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            ExecuteMsg::UpdateConfig {
+                protocol_fee_bps: Some(1000),
+                base_yield_bps: Some(0),
+                regular_pool_bps: None,
+                big_pool_bps: None,
+                min_epochs_regular: None,
+                min_epochs_big: None,
+                min_stake_amount: None,
+                snapshot_tolerance_bps: None,
+                keeper_fee_bps: None,
+                snapshot_grace_seconds: None,
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::TimelockRequired));
+
+        // Neither can the keeper fee and snapshot parameters
+        for (snapshot_tolerance_bps, keeper_fee_bps, snapshot_grace_seconds) in [
+            (Some(500), None, None),
+            (None, Some(100), None),
+            (None, None, Some(60)),
+        ] {
+            let err = execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&admin, &[]),
+                ExecuteMsg::UpdateConfig {
+                    protocol_fee_bps: None,
+                    base_yield_bps: None,
+                    regular_pool_bps: None,
+                    big_pool_bps: None,
+                    min_epochs_regular: None,
+                    min_epochs_big: None,
+                    min_stake_amount: None,
+                    snapshot_tolerance_bps,
+                    keeper_fee_bps,
+                    snapshot_grace_seconds,
+                },
+            )
+            .unwrap_err();
+            assert!(matches!(err, ContractError::TimelockRequired));
+        }
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            ExecuteMsg::UpdateValidators {
+                add: vec![],
+                remove: vec![],
+                weights: None,
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::TimelockRequired));
+
+        // Only the admin can schedule
+        let change = ParamChange::UpdateConfig {
+            protocol_fee_bps: Some(1000),
+            base_yield_bps: Some(0),
+            regular_pool_bps: None,
+            big_pool_bps: None,
+            min_epochs_regular: None,
+            min_epochs_big: None,
+            snapshot_tolerance_bps: None,
+            keeper_fee_bps: Some(100),
+            snapshot_grace_seconds: Some(60),
+        };
+        let user = deps.api.addr_make("user1");
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user, &[]),
+            ExecuteMsg::ScheduleChange {
+                change: change.clone(),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized { .. }));
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            ExecuteMsg::ScheduleChange { change },
         )
         .unwrap();
 
-        let epoch = EPOCH_STATE.load(deps.as_ref().storage).unwrap();
-        assert!(epoch.snapshot_finalized);
+        let pending: Vec<PendingChange> = cosmwasm_std::from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::PendingChanges {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].id, 0);
         assert_eq!(
-            epoch.snapshot_merkle_root,
-            Some("abcd1234abcd1234abcd1234abcd1234abcd1234abcd1234abcd1234abcd1234".to_string())
+            pending[0].executable_at,
+            mock_env().block.time.plus_seconds(86400)
         );
-        assert_eq!(epoch.snapshot_total_weight, Uint128::from(1000u128));
-        assert_eq!(epoch.snapshot_num_holders, 5);
-
-        // Should have forwarded to distributor
-        assert_eq!(res.messages.len(), 1);
-    }
 
-    #[test]
-    fn test_take_snapshot_duplicate() {
-        let mut deps = mock_dependencies();
-        setup_contract(deps.as_mut());
+        // Not executable before the delay has passed
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user, &[]),
+            ExecuteMsg::ExecuteChange { id: 0 },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::ChangeNotReady { id: 0, .. }));
 
-        let operator = deps.api.addr_make("operator");
-        let info = message_info(&operator, &[]);
+        // Anyone can execute it once the delay has passed
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(86400);
         execute(
             deps.as_mut(),
-            mock_env(),
-            info.clone(),
-            ExecuteMsg::TakeSnapshot {
-                merkle_root: "abcd1234abcd1234abcd1234abcd1234abcd1234abcd1234abcd1234abcd1234"
-                    .to_string(),
-                total_weight: Uint128::from(1000u128),
-                num_holders: 5,
-                snapshot_uri: "ipfs://snapshot".to_string(),
-            },
+            env.clone(),
+            message_info(&user, &[]),
+            ExecuteMsg::ExecuteChange { id: 0 },
         )
         .unwrap();
 
-        // Second snapshot should fail
+        let config = CONFIG.load(deps.as_ref().storage).unwrap();
+        assert_eq!(config.protocol_fee_bps, 1000);
+        assert_eq!(config.base_yield_bps, 0);
+        assert_eq!(config.keeper_fee_bps, 100);
+        assert_eq!(config.snapshot_grace_seconds, 60);
+        assert!(PENDING_CHANGES.is_empty(deps.as_ref().storage));
+
         let err = execute(
             deps.as_mut(),
-            mock_env(),
-            info,
-            ExecuteMsg::TakeSnapshot {
-                merkle_root: "ef125678ef125678ef125678ef125678ef125678ef125678ef125678ef125678"
-                    .to_string(),
-                total_weight: Uint128::from(2000u128),
-                num_holders: 10,
-                snapshot_uri: "ipfs://snapshot2".to_string(),
-            },
+            env.clone(),
+            message_info(&user, &[]),
+            ExecuteMsg::ExecuteChange { id: 0 },
         )
         .unwrap_err();
-        assert!(matches!(err, ContractError::SnapshotAlreadyTaken));
-    }
-
-    #[test]
-    fn test_update_validators() {
-        let mut deps = mock_dependencies();
-        setup_contract(deps.as_mut());
+        assert!(matches!(err, ContractError::ChangeNotFound { id: 0 }));
 
-        let admin = deps.api.addr_make("admin");
-        let info = message_info(&admin, &[]);
+        // A cancelled change can no longer be executed
         execute(
             deps.as_mut(),
-            mock_env(),
-            info,
-            ExecuteMsg::UpdateValidators {
-                add: vec!["injvaloper1zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzz".to_string()],
-                remove: vec!["injvaloper1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqj9".to_string()],
+            env.clone(),
+            message_info(&admin, &[]),
+            ExecuteMsg::ScheduleChange {
+                change: ParamChange::SetTimelock { seconds: 0 },
             },
         )
         .unwrap();
-
-        let config = CONFIG.load(deps.as_ref().storage).unwrap();
-        assert_eq!(
-            config.validators,
-            vec![
-                "injvaloper1xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx".to_string(),
-                "injvaloper1zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzz".to_string()
-            ]
-        );
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&admin, &[]),
+            ExecuteMsg::CancelChange { id: 1 },
+        )
+        .unwrap();
+        env.block.time = env.block.time.plus_seconds(86400);
+        let err = execute(
+            deps.as_mut(),
+            env,
+            message_info(&user, &[]),
+            ExecuteMsg::ExecuteChange { id: 1 },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::ChangeNotFound { id: 1 }));
     }
 
     #[test]
@@ -658,6 +3376,7 @@ mod tests {
             ExecuteMsg::UpdateValidators {
                 add: vec!["injvaloper1zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzz".to_string()],
                 remove: vec![],
+                weights: None,
             },
         )
         .unwrap_err();
@@ -692,6 +3411,7 @@ mod tests {
             ExecuteMsg::UpdateValidators {
                 add: vec!["injvaloper1zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzz".to_string()],
                 remove: vec!["injvaloper1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqj9".to_string()],
+                weights: None,
             },
         )
         .unwrap();
@@ -733,6 +3453,7 @@ mod tests {
                     "injvaloper1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqj9".to_string(),
                     "injvaloper1xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx".to_string(),
                 ],
+                weights: None,
             },
         )
         .unwrap_err();
@@ -740,36 +3461,299 @@ mod tests {
     }
 
     #[test]
-    fn test_stake_records_and_resets_epoch() {
+    fn test_stake_epoch_weighted_on_top_up() {
+        use crate::msg::SudoMsg;
         use crate::state::{EPOCH_STATE, USER_STAKE_EPOCH};
 
         let mut deps = mock_dependencies();
         setup_contract(deps.as_mut());
+        let denom = CONFIG.load(deps.as_ref().storage).unwrap().csinj_denom;
 
         let user1 = deps.api.addr_make("user1");
         let info = message_info(&user1, &coins(100_000_000, "inj"));
-        execute(deps.as_mut(), mock_env(), info, ExecuteMsg::Stake {}).unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
 
         // Verify stake epoch recorded as epoch 1
-        let addr = deps.api.addr_make("user1");
-        let stake_epoch = USER_STAKE_EPOCH.load(deps.as_ref().storage, &addr).unwrap();
+        let stake_epoch = USER_STAKE_EPOCH
+            .load(deps.as_ref().storage, &user1)
+            .unwrap();
         assert_eq!(stake_epoch, 1);
 
-        // Simulate epoch advancing to 5
+        // Simulate epoch advancing to 5, with the minted csINJ in the user's wallet
         let mut epoch_state = EPOCH_STATE.load(deps.as_ref().storage).unwrap();
         epoch_state.current_epoch = 5;
         EPOCH_STATE
             .save(deps.as_mut().storage, &epoch_state)
             .unwrap();
+        deps.querier
+            .bank
+            .update_balance(&user1, coins(100_000_000, &denom));
 
-        // Second stake should reset epoch to 5
-        let user1 = deps.api.addr_make("user1");
+        // Top-up of 50M at epoch 5: ceil((100M * 1 + 50M * 5) / 150M) = ceil(2.33) = 3
         let info = message_info(&user1, &coins(50_000_000, "inj"));
-        execute(deps.as_mut(), mock_env(), info, ExecuteMsg::Stake {}).unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
+        let stake_epoch = USER_STAKE_EPOCH
+            .load(deps.as_ref().storage, &user1)
+            .unwrap();
+        assert_eq!(stake_epoch, 3);
 
-        let addr = deps.api.addr_make("user1");
-        let stake_epoch = USER_STAKE_EPOCH.load(deps.as_ref().storage, &addr).unwrap();
-        assert_eq!(stake_epoch, 5); // reset to current epoch
+        // A partial unstake leaves the entry epoch unchanged
+        deps.querier
+            .bank
+            .update_balance(&user1, coins(150_000_000, &denom));
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user1, &[Coin::new(75_000_000u128, &denom)]),
+            ExecuteMsg::Unstake {
+                recipient: None,
+                min_inj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
+        let stake_epoch = USER_STAKE_EPOCH
+            .load(deps.as_ref().storage, &user1)
+            .unwrap();
+        assert_eq!(stake_epoch, 3);
+
+        // Received csINJ is blended the same way: ceil((75M * 3 + 25M * 5) / 100M) = 4
+        deps.querier
+            .bank
+            .update_balance(&user1, coins(75_000_000, &denom));
+        let user2 = deps.api.addr_make("user2");
+        sudo(
+            deps.as_mut(),
+            mock_env(),
+            SudoMsg::TrackBeforeSend {
+                from: user2.to_string(),
+                to: user1.to_string(),
+                amount: Coin::new(25_000_000u128, &denom),
+            },
+        )
+        .unwrap();
+        let stake_epoch = USER_STAKE_EPOCH
+            .load(deps.as_ref().storage, &user1)
+            .unwrap();
+        assert_eq!(stake_epoch, 4);
+
+        // The mint leg of a stake is not counted a second time
+        let module = execute::tokenfactory_module_address(&deps.api).unwrap();
+        sudo(
+            deps.as_mut(),
+            mock_env(),
+            SudoMsg::TrackBeforeSend {
+                from: module.to_string(),
+                to: user1.to_string(),
+                amount: Coin::new(25_000_000u128, &denom),
+            },
+        )
+        .unwrap();
+        let stake_epoch = USER_STAKE_EPOCH
+            .load(deps.as_ref().storage, &user1)
+            .unwrap();
+        assert_eq!(stake_epoch, 4);
+    }
+
+    #[test]
+    fn test_csinj_transfer_resets_receiver_epoch() {
+        use crate::msg::SudoMsg;
+        use crate::state::{EPOCH_STATE, USER_STAKE_EPOCH};
+
+        let mut deps = mock_dependencies();
+        let admin = deps.api.addr_make("admin");
+        let res = instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            default_instantiate_msg(),
+        )
+        .unwrap();
+        // Create denom + register the before-send hook
+        assert_eq!(res.messages.len(), 2);
+
+        let old_staker = deps.api.addr_make("old_staker");
+        let fresh = deps.api.addr_make("fresh");
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&old_staker, &coins(100_000_000, "inj")),
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
+
+        let mut epoch_state = EPOCH_STATE.load(deps.as_ref().storage).unwrap();
+        epoch_state.current_epoch = 10;
+        EPOCH_STATE
+            .save(deps.as_mut().storage, &epoch_state)
+            .unwrap();
+
+        let denom = CONFIG.load(deps.as_ref().storage).unwrap().csinj_denom;
+        let transfer = |from: &cosmwasm_std::Addr, to: &cosmwasm_std::Addr, denom: &str| {
+            SudoMsg::TrackBeforeSend {
+                from: from.to_string(),
+                to: to.to_string(),
+                amount: Coin::new(1_000u128, denom),
+            }
+        };
+
+        // The receiver does not inherit the sender's holding time
+        sudo(
+            deps.as_mut(),
+            mock_env(),
+            transfer(&old_staker, &fresh, &denom),
+        )
+        .unwrap();
+        assert_eq!(
+            USER_STAKE_EPOCH
+                .load(deps.as_ref().storage, &fresh)
+                .unwrap(),
+            10
+        );
+        assert_eq!(
+            USER_STAKE_EPOCH
+                .load(deps.as_ref().storage, &old_staker)
+                .unwrap(),
+            1
+        );
+
+        // Sends to the hub (unstake) and other denoms are ignored
+        let hub = mock_env().contract.address;
+        sudo(
+            deps.as_mut(),
+            mock_env(),
+            transfer(&old_staker, &hub, &denom),
+        )
+        .unwrap();
+        assert!(!USER_STAKE_EPOCH.has(deps.as_ref().storage, &hub));
+        sudo(
+            deps.as_mut(),
+            mock_env(),
+            transfer(&fresh, &old_staker, "inj"),
+        )
+        .unwrap();
+        assert_eq!(
+            USER_STAKE_EPOCH
+                .load(deps.as_ref().storage, &old_staker)
+                .unwrap(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_twab_between() {
+        use crate::msg::{SudoMsg, TwabResponse};
+
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+        let denom = CONFIG.load(deps.as_ref().storage).unwrap().csinj_denom;
+        let user1 = deps.api.addr_make("user1");
+        let user2 = deps.api.addr_make("user2");
+        let t0 = mock_env().block.time.seconds();
+        let env_at = |offset: u64| {
+            let mut env = mock_env();
+            env.block.time = env.block.time.plus_seconds(offset);
+            env
+        };
+
+        // user1 holds 100 for 100s, 50 for 100s, then transfers the rest to user2
+        execute(
+            deps.as_mut(),
+            env_at(0),
+            message_info(&user1, &coins(100_000_000, "inj")),
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            env_at(100),
+            message_info(&user1, &[Coin::new(50_000_000u128, &denom)]),
+            ExecuteMsg::Unstake {
+                recipient: None,
+                min_inj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
+        sudo(
+            deps.as_mut(),
+            env_at(200),
+            SudoMsg::TrackBeforeSend {
+                from: user1.to_string(),
+                to: user2.to_string(),
+                amount: Coin::new(50_000_000u128, &denom),
+            },
+        )
+        .unwrap();
+
+        let twab = |deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>,
+                    address: &cosmwasm_std::Addr,
+                    start: u64,
+                    end: u64| {
+            let res: TwabResponse = cosmwasm_std::from_json(
+                query(
+                    deps.as_ref(),
+                    env_at(300),
+                    QueryMsg::TwabBetween {
+                        address: address.to_string(),
+                        start,
+                        end,
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap();
+            (
+                res.average_balance.u128(),
+                res.total_average_balance.u128(),
+                res.min_balance.u128(),
+            )
+        };
+
+        // (100 * 100s + 50 * 100s + 0 * 100s) / 300s = 50; total (100 * 100s + 50 * 200s) / 300s
+        assert_eq!(
+            twab(&deps, &user1, t0, t0 + 300),
+            (50_000_000, 66_666_666, 0)
+        );
+        assert_eq!(
+            twab(&deps, &user2, t0, t0 + 300),
+            (16_666_666, 66_666_666, 0)
+        );
+        // A window inside a single checkpoint interval
+        assert_eq!(twab(&deps, &user1, t0 + 120, t0 + 180).0, 50_000_000);
+        assert_eq!(twab(&deps, &user1, t0, t0 + 200).2, 50_000_000);
+        // A window before any activity
+        assert_eq!(twab(&deps, &user1, t0 - 100, t0), (0, 0, 0));
+        // `end` is capped at the current block time
+        assert_eq!(twab(&deps, &user2, t0 + 200, t0 + 1_000).0, 50_000_000);
     }
 
     #[test]
@@ -796,7 +3780,17 @@ mod tests {
         // Stake
         let user1 = deps.api.addr_make("user1");
         let msg_info = message_info(&user1, &coins(100_000_000, "inj"));
-        execute(deps.as_mut(), mock_env(), msg_info, ExecuteMsg::Stake {}).unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            msg_info,
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
 
         // Query after staking — should return epoch 1
         let res = query(
@@ -842,18 +3836,48 @@ mod tests {
         // Stake below minimum should fail
         let user1 = deps.api.addr_make("user1");
         let info = message_info(&user1, &coins(999_999, "inj"));
-        let err = execute(deps.as_mut(), mock_env(), info, ExecuteMsg::Stake {}).unwrap_err();
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap_err();
         assert!(matches!(err, ContractError::StakeBelowMinimum { .. }));
 
         // Stake at exactly minimum should succeed
         let user1 = deps.api.addr_make("user1");
         let info = message_info(&user1, &coins(1_000_000, "inj"));
-        execute(deps.as_mut(), mock_env(), info, ExecuteMsg::Stake {}).unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
 
         // Stake above minimum should succeed
         let user2 = deps.api.addr_make("user2");
         let info = message_info(&user2, &coins(2_000_000, "inj"));
-        execute(deps.as_mut(), mock_env(), info, ExecuteMsg::Stake {}).unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
     }
 
     #[test]
@@ -864,7 +3888,17 @@ mod tests {
         // With min_stake_amount = 0, even 1 wei should work
         let user1 = deps.api.addr_make("user1");
         let info = message_info(&user1, &coins(1, "inj"));
-        execute(deps.as_mut(), mock_env(), info, ExecuteMsg::Stake {}).unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
     }
 
     #[test]
@@ -884,8 +3918,6 @@ mod tests {
             mock_env(),
             info,
             ExecuteMsg::UpdateConfig {
-                admin: None,
-                operator: None,
                 protocol_fee_bps: None,
                 base_yield_bps: None,
                 regular_pool_bps: None,
@@ -893,6 +3925,9 @@ mod tests {
                 min_epochs_regular: None,
                 min_epochs_big: None,
                 min_stake_amount: Some(Uint128::new(5_000_000)),
+                snapshot_tolerance_bps: None,
+                keeper_fee_bps: None,
+                snapshot_grace_seconds: None,
             },
         )
         .unwrap();
@@ -903,13 +3938,33 @@ mod tests {
         // Now staking below the new minimum should fail
         let user1 = deps.api.addr_make("user1");
         let info = message_info(&user1, &coins(4_999_999, "inj"));
-        let err = execute(deps.as_mut(), mock_env(), info, ExecuteMsg::Stake {}).unwrap_err();
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap_err();
         assert!(matches!(err, ContractError::StakeBelowMinimum { .. }));
 
         // Staking at the minimum should succeed
         let user1 = deps.api.addr_make("user1");
         let info = message_info(&user1, &coins(5_000_000, "inj"));
-        execute(deps.as_mut(), mock_env(), info, ExecuteMsg::Stake {}).unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
+        )
+        .unwrap();
     }
 
     #[test]
@@ -24,12 +24,33 @@ pub enum ContractError {
     #[error("no csINJ sent with unstake")]
     NoUnstakeFunds,
 
+    #[error("must send csINJ to enter yield-only mode, got {denom}")]
+    WrongYieldOnlyDenom { denom: String },
+
+    #[error("no csINJ sent to enter yield-only mode")]
+    NoYieldOnlyFunds,
+
+    #[error("{address} has no sponsorship")]
+    NoSponsorship { address: String },
+
+    #[error("no csINJ supply to donate base yield to")]
+    NoCsinjSupply,
+
+    #[error("cannot withdraw {requested} yield-only shares: only {available} held")]
+    InsufficientYieldOnlyShares {
+        requested: Uint128,
+        available: Uint128,
+    },
+
     #[error("unstake request {id} not found for {address}")]
     UnstakeRequestNotFound { address: String, id: u64 },
 
     #[error("unstake request {id} not yet unlocked (unlocks at {unlock_time})")]
     UnstakeNotUnlocked { id: u64, unlock_time: u64 },
 
+    #[error("unstake request {id} is waiting for unbonding batch {batch_id} to be submitted")]
+    UnstakeBatchPending { id: u64, batch_id: u64 },
+
     #[error("unstake request {id} already claimed")]
     UnstakeAlreadyClaimed { id: u64 },
 
@@ -39,6 +60,15 @@ pub enum ContractError {
     #[error("epoch not ready to advance (current epoch started too recently)")]
     EpochNotReady,
 
+    #[error("no unexplained surplus to sweep")]
+    NoSurplus,
+
+    #[error("no epoch advance in progress")]
+    NoPendingEpochAdvance,
+
+    #[error("unknown reply id: {id}")]
+    UnknownReplyId { id: u64 },
+
     #[error("snapshot already taken for this epoch")]
     SnapshotAlreadyTaken,
 
@@ -65,6 +95,12 @@ pub enum ContractError {
         min_stake_amount: Uint128,
     },
 
+    #[error("slippage exceeded: would receive {amount}, minimum is {min_out}")]
+    SlippageExceeded { amount: Uint128, min_out: Uint128 },
+
+    #[error("transaction deadline {deadline} has passed")]
+    DeadlineExceeded { deadline: u64 },
+
     #[error("insufficient contract balance for claim")]
     InsufficientBalance,
 
@@ -85,6 +121,45 @@ pub enum ContractError {
         delegated: Uint128,
     },
 
+    #[error("cannot undelegate {requested}: only {available} is delegated")]
+    InsufficientDelegations {
+        requested: Uint128,
+        available: Uint128,
+    },
+
     #[error("invalid validator weights: {reason}")]
     InvalidValidatorWeights { reason: String },
+
+    #[error("{operation} is paused")]
+    OperationPaused { operation: String },
+
+    #[error("no pending {role} transfer")]
+    NoPendingTransfer { role: String },
+
+    #[error("{role} transfer proposal has expired")]
+    TransferExpired { role: String },
+
+    #[error("a timelock is set: schedule this change with ScheduleChange")]
+    TimelockRequired,
+
+    #[error("scheduled change {id} not found")]
+    ChangeNotFound { id: u64 },
+
+    #[error("scheduled change {id} is not executable until {executable_at}")]
+    ChangeNotReady { id: u64, executable_at: u64 },
+
+    #[error("snapshot total_weight {total_weight} exceeds maximum {max_weight}")]
+    SnapshotWeightTooHigh {
+        total_weight: Uint128,
+        max_weight: Uint128,
+    },
+
+    #[error("snapshot num_holders {num_holders} inconsistent with total_weight {total_weight}")]
+    InvalidSnapshotHolders {
+        num_holders: u32,
+        total_weight: Uint128,
+    },
+
+    #[error("at most {max} addresses can be excluded from snapshots")]
+    TooManySnapshotExclusions { max: u32 },
 }
@@ -1,16 +1,30 @@
+use chance_staking_common::types::{PendingTransfer, Role};
 use cosmwasm_std::{
-    coins, BankMsg, Coin, CosmosMsg, Decimal, DepsMut, DistributionMsg, Env, Event, MessageInfo,
-    QuerierWrapper, StakingMsg, Uint128, WasmMsg,
+    coins, to_json_string, Addr, AnyMsg, Api, BankMsg, CanonicalAddr, Coin, CosmosMsg, Decimal,
+    Deps, DepsMut, DistributionMsg, Env, Event, MessageInfo, Order, QuerierWrapper, StakingMsg,
+    StdResult, Storage, SubMsg, Timestamp, Uint128, Uint256, WasmMsg,
 };
+use cw_storage_plus::Bound;
 use injective_cosmwasm::{
     create_burn_tokens_msg, create_mint_tokens_msg, create_new_denom_msg, InjectiveMsgWrapper,
 };
+use sha2::{Digest, Sha256};
 
 use crate::error::ContractError;
-use crate::msg::{DistributorExecuteMsg, ValidatorWeight};
+use crate::msg::{
+    DistributorConfigResponse, DistributorExecuteMsg, DistributorQueryMsg,
+    DistributorSnapshotResponse, ParamChange, ValidatorWeight,
+};
 use crate::state::{
-    UnstakeRequest, CONFIG, EPOCH_STATE, EXCHANGE_RATE, NEXT_UNSTAKE_ID, PENDING_UNSTAKE_TOTAL,
-    TOTAL_CSINJ_SUPPLY, TOTAL_INJ_BACKING, UNSTAKE_REQUESTS, USER_STAKE_EPOCH,
+    BatchUndelegation, Config, DonationTarget, EpochRecord, EpochState, PausableOperation,
+    PendingChange, PendingEpochAdvance, SnapshotBounds, TwabCheckpoint, UnbondingBatch,
+    UnstakeRequest, AUTO_WITHDRAWN_REWARDS, CONFIG, CURRENT_BATCH_ID, DONATION_TOTALS,
+    EPOCH_HISTORY, EPOCH_STATE, EXCHANGE_RATE, EXPECTED_DELEGATIONS, LEGACY_UNSTAKE_REQUESTS,
+    NEXT_CHANGE_ID, NEXT_UNSTAKE_ID, PAUSED_OPERATIONS, PENDING_CHANGES, PENDING_EPOCH_ADVANCE,
+    PENDING_UNSTAKE_TOTAL, SNAPSHOT_EXCLUSIONS, SPONSORS, SPONSOR_SHARES, SPONSOR_TOTALS,
+    TOTAL_CSINJ_SUPPLY, TOTAL_INJ_BACKING, TOTAL_TWAB_CHECKPOINTS, TWAB_CHECKPOINTS,
+    UNBONDING_BATCHES, UNEXPLAINED_SURPLUS, UNSTAKE_REQUESTS, USER_STAKE_EPOCH, YIELD_ONLY_SHARES,
+    YIELD_ONLY_TOTALS,
 };
 
 /// 10^18 — Decimal's internal scaling factor, used for overflow-safe rate arithmetic.
@@ -18,9 +32,35 @@ const DECIMAL_FRACTIONAL: u128 = 1_000_000_000_000_000_000;
 
 type ContractResponse = cosmwasm_std::Response<InjectiveMsgWrapper>;
 
+/// Reply ID of AdvanceEpoch's last reward withdrawal.
+pub const ADVANCE_EPOCH_REPLY_ID: u64 = 1;
+
 /// 21 days in seconds for unbonding period
 const UNBONDING_PERIOD_SECS: u64 = 21 * 24 * 60 * 60;
 
+/// Cosmos SDK limit on concurrent unbonding entries per delegator/validator pair.
+const MAX_UNBONDING_ENTRIES: u64 = 7;
+
+/// Token Factory message that sets a denom's before-send hook contract.
+const SET_BEFORE_SEND_HOOK_TYPE_URL: &str = "/injective.tokenfactory.v1beta1.MsgSetBeforeSendHook";
+
+/// Minimum spacing between unbonding batch submissions. Keeps at most
+/// MAX_UNBONDING_ENTRIES - 1 batches unbonding at once, leaving one entry of headroom.
+const BATCH_SUBMIT_INTERVAL_SECS: u64 = UNBONDING_PERIOD_SECS / (MAX_UNBONDING_ENTRIES - 1);
+
+/// Default allowance for snapshot totals above the non-excluded supply (1%)
+pub const DEFAULT_SNAPSHOT_TOLERANCE_BPS: u16 = 100;
+
+/// Default keeper share of the treasury fee (10%)
+pub const DEFAULT_KEEPER_FEE_BPS: u16 = 1000;
+
+/// Default operator-only window at the start of an epoch before anyone may take its
+/// snapshot (1 hour)
+pub const DEFAULT_SNAPSHOT_GRACE_SECONDS: u64 = 3600;
+
+/// Cap on snapshot exclusions, so checking a snapshot's totals stays cheap.
+const MAX_SNAPSHOT_EXCLUSIONS: u32 = 50;
+
 /// Create the Token Factory denom during instantiation.
 pub fn create_denom(
     env: &Env,
@@ -32,12 +72,211 @@ pub fn create_denom(
     Ok((msg, full_denom))
 }
 
+/// Register this contract as the csINJ before-send hook so every transfer reaches
+/// the `sudo` entry point. Sent after the denom is created, and again on migrate.
+pub fn create_set_before_send_hook_msg(env: &Env, denom: &str) -> CosmosMsg<InjectiveMsgWrapper> {
+    let contract_addr = env.contract.address.as_str();
+    let mut value = Vec::new();
+    encode_proto_string(&mut value, 1, contract_addr); // sender
+    encode_proto_string(&mut value, 2, denom);
+    encode_proto_string(&mut value, 3, contract_addr); // cosmwasm_address
+    CosmosMsg::Any(AnyMsg {
+        type_url: SET_BEFORE_SEND_HOOK_TYPE_URL.to_string(),
+        value: value.into(),
+    })
+}
+
+/// Append a length-delimited protobuf string field.
+fn encode_proto_string(buf: &mut Vec<u8>, field: u8, value: &str) {
+    buf.push((field << 3) | 2);
+    let mut len = value.len();
+    while len >= 0x80 {
+        buf.push((len as u8 & 0x7f) | 0x80);
+        len >>= 7;
+    }
+    buf.push(len as u8);
+    buf.extend_from_slice(value.as_bytes());
+}
+
+/// Before-send hook for csINJ. Received csINJ counts as entering at the current
+/// epoch, exactly like staked csINJ, so the draw `min_epochs` checks measure how
+/// long the current holder has held. Mints (already recorded by `stake`) and
+/// transfers into and out of the hub itself (unstake, burn) are ignored.
+/// Never rejects a transfer.
+pub fn track_csinj_transfer(
+    deps: DepsMut,
+    env: Env,
+    from: String,
+    to: String,
+    amount: Coin,
+) -> Result<ContractResponse, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let contract_addr = env.contract.address.as_str();
+
+    if amount.denom != config.csinj_denom
+        || amount.amount.is_zero()
+        || from == to
+        || from == contract_addr
+        || to == contract_addr
+        || from == tokenfactory_module_address(deps.api)?.as_str()
+    {
+        return Ok(ContractResponse::new().add_attribute("action", "csinj_transfer_ignored"));
+    }
+
+    let sender = deps.api.addr_validate(&from)?;
+    let receiver = deps.api.addr_validate(&to)?;
+    checkpoint_twab(
+        deps.storage,
+        env.block.time.seconds(),
+        Some(&sender),
+        Some(&receiver),
+        amount.amount,
+    )?;
+    let current_epoch = EPOCH_STATE.load(deps.storage)?.current_epoch;
+    let stake_epoch = record_stake_epoch(
+        deps.storage,
+        deps.querier,
+        &config.csinj_denom,
+        &receiver,
+        amount.amount,
+        current_epoch,
+    )?;
+
+    Ok(ContractResponse::new()
+        .add_attribute("action", "csinj_transfer")
+        .add_attribute("from", from)
+        .add_attribute("to", to)
+        .add_attribute("amount", amount.amount.to_string())
+        .add_attribute("stake_epoch", stake_epoch.to_string()))
+}
+
+/// Move `amount` csINJ between holders in the TWAB checkpoints. `None` stands for
+/// outside the tracked supply (mint or burn), which also moves the total.
+fn checkpoint_twab(
+    storage: &mut dyn Storage,
+    now: u64,
+    from: Option<&Addr>,
+    to: Option<&Addr>,
+    amount: Uint128,
+) -> StdResult<()> {
+    if let Some(holder) = from {
+        checkpoint_holder_twab(storage, holder, now, Uint128::zero(), amount)?;
+    }
+    if let Some(holder) = to {
+        checkpoint_holder_twab(storage, holder, now, amount, Uint128::zero())?;
+    }
+    // Sponsors' csINJ stays out of the draws, so its total is kept for the snapshot checks
+    let from_sponsor = from.is_some_and(|holder| SPONSORS.has(storage, holder));
+    let to_sponsor = to.is_some_and(|holder| SPONSORS.has(storage, holder));
+    if from_sponsor != to_sponsor {
+        let mut totals = SPONSOR_TOTALS.may_load(storage)?.unwrap_or_default();
+        totals.csinj = if to_sponsor {
+            totals.csinj + amount
+        } else {
+            totals.csinj.saturating_sub(amount)
+        };
+        SPONSOR_TOTALS.save(storage, &totals)?;
+    }
+    if from.is_none() || to.is_none() {
+        let last = TOTAL_TWAB_CHECKPOINTS
+            .range(storage, None, None, Order::Descending)
+            .next()
+            .transpose()?
+            .map(|(_, checkpoint)| checkpoint);
+        let (added, removed) = if from.is_none() {
+            (amount, Uint128::zero())
+        } else {
+            (Uint128::zero(), amount)
+        };
+        let checkpoint = next_twab_checkpoint(last, now, added, removed);
+        TOTAL_TWAB_CHECKPOINTS.save(storage, now, &checkpoint)?;
+    }
+    Ok(())
+}
+
+fn checkpoint_holder_twab(
+    storage: &mut dyn Storage,
+    holder: &Addr,
+    now: u64,
+    added: Uint128,
+    removed: Uint128,
+) -> StdResult<()> {
+    let last = TWAB_CHECKPOINTS
+        .prefix(holder)
+        .range(storage, None, None, Order::Descending)
+        .next()
+        .transpose()?
+        .map(|(_, checkpoint)| checkpoint);
+    let checkpoint = next_twab_checkpoint(last, now, added, removed);
+    TWAB_CHECKPOINTS.save(storage, (holder, now), &checkpoint)
+}
+
+/// Roll a checkpoint forward to `now` and apply a balance change. Balances that
+/// predate TWAB tracking are unknown, so removals saturate at zero.
+fn next_twab_checkpoint(
+    last: Option<TwabCheckpoint>,
+    now: u64,
+    added: Uint128,
+    removed: Uint128,
+) -> TwabCheckpoint {
+    let (balance, cumulative) = match last {
+        Some(last) => (
+            last.balance,
+            last.cumulative + last.balance * Uint128::from(now - last.timestamp),
+        ),
+        None => (Uint128::zero(), Uint128::zero()),
+    };
+    TwabCheckpoint {
+        timestamp: now,
+        balance: (balance + added).saturating_sub(removed),
+        cumulative,
+    }
+}
+
+/// Token Factory module account, the sender of every csINJ mint.
+pub(crate) fn tokenfactory_module_address(api: &dyn Api) -> StdResult<Addr> {
+    let hash = Sha256::digest(b"tokenfactory");
+    api.addr_humanize(&CanonicalAddr::from(&hash[..20]))
+}
+
+/// Blend a holder's entry epoch with `added` csINJ entering at the current epoch,
+/// weighted by the csINJ they already hold. Partial unstakes leave the epoch as is,
+/// and an emptied position carries no weight into the next stake. Rounds up so a
+/// top-up never makes a position look older than it is.
+fn record_stake_epoch(
+    storage: &mut dyn Storage,
+    querier: QuerierWrapper,
+    csinj_denom: &str,
+    holder: &Addr,
+    added: Uint128,
+    current_epoch: u64,
+) -> StdResult<u64> {
+    let held = querier.query_balance(holder, csinj_denom)?.amount;
+    let stake_epoch = match USER_STAKE_EPOCH.may_load(storage, holder)? {
+        Some(entry_epoch) if !held.is_zero() && entry_epoch < current_epoch => {
+            let weighted = Uint256::from(held) * Uint256::from(entry_epoch)
+                + Uint256::from(added) * Uint256::from(current_epoch);
+            let total = Uint256::from(held + added);
+            let epoch = (weighted + total - Uint256::one()) / total;
+            Uint128::try_from(epoch)?.u128() as u64
+        }
+        _ => current_epoch,
+    };
+    USER_STAKE_EPOCH.save(storage, holder, &stake_epoch)?;
+    Ok(stake_epoch)
+}
+
 /// Stake INJ → mint csINJ at current exchange rate.
 pub fn stake(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
+    recipient: Option<String>,
+    min_csinj_out: Option<Uint128>,
+    deadline: Option<u64>,
 ) -> Result<ContractResponse, ContractError> {
+    assert_before_deadline(&env, deadline)?;
+
     // Validate funds: exactly one coin, must be INJ
     if info.funds.is_empty() {
         return Err(ContractError::NoFundsSent);
@@ -57,6 +296,7 @@ pub fn stake(
     }
 
     let config = CONFIG.load(deps.storage)?;
+    let recipient = resolve_recipient(&deps, &info, recipient)?;
 
     // Enforce minimum stake amount
     if inj_amount < config.min_stake_amount {
@@ -66,6 +306,9 @@ pub fn stake(
         });
     }
 
+    // Book any slashing first so the new stake is priced at the post-slash rate
+    let slashing_event = reconcile_slashing(deps.storage, deps.querier, &env)?;
+
     let exchange_rate = EXCHANGE_RATE.load(deps.storage)?;
 
     // Calculate csINJ to mint: inj_amount / exchange_rate
@@ -78,6 +321,7 @@ pub fn stake(
     } else {
         inj_amount.multiply_ratio(Uint128::new(DECIMAL_FRACTIONAL), rate_atomics)
     };
+    assert_min_out(csinj_amount, min_csinj_out)?;
 
     // Update totals
     let new_backing = TOTAL_INJ_BACKING.load(deps.storage)? + inj_amount;
@@ -91,9 +335,24 @@ pub fn stake(
     epoch_state.total_staked = new_backing;
     EPOCH_STATE.save(deps.storage, &epoch_state)?;
 
-    // Record the epoch of this stake (resets on every stake so that newly added
-    // funds must also satisfy the min_epochs eligibility requirement)
-    USER_STAKE_EPOCH.save(deps.storage, &info.sender, &epoch_state.current_epoch)?;
+    // Move the recipient's entry epoch towards the current epoch in proportion to
+    // the csINJ added, so new funds age from now without wiping existing age.
+    // The mint below has not executed yet, so the balance query is pre-stake.
+    let stake_epoch = record_stake_epoch(
+        deps.storage,
+        deps.querier,
+        &config.csinj_denom,
+        &recipient,
+        csinj_amount,
+        epoch_state.current_epoch,
+    )?;
+    checkpoint_twab(
+        deps.storage,
+        env.block.time.seconds(),
+        None,
+        Some(&recipient),
+        csinj_amount,
+    )?;
 
     // Mint csINJ via Token Factory
     let mint_msg = create_mint_tokens_msg(
@@ -102,40 +361,55 @@ pub fn stake(
             denom: config.csinj_denom.clone(),
             amount: csinj_amount,
         },
-        info.sender.to_string(),
+        recipient.to_string(),
     );
 
-    // Delegate INJ to validators (round-robin)
-    let delegate_msgs = create_delegation_msgs(&config.validators, inj_amount)?;
+    // Delegate INJ to the most under-weight validators
+    let delegate_msgs = create_delegation_msgs(deps.querier, &env, &config, inj_amount)?;
+    track_staking_msgs(deps.storage, deps.querier, &env, &delegate_msgs)?;
 
     let mut response = ContractResponse::new()
         .add_message(mint_msg)
         .add_attribute("action", "stake")
         .add_attribute("user", info.sender.to_string())
+        .add_attribute("recipient", recipient.to_string())
         .add_attribute("inj_amount", inj_amount.to_string())
         .add_attribute("csinj_minted", csinj_amount.to_string())
         .add_event(
             Event::new("chance_stake")
                 .add_attribute("user", info.sender.to_string())
+                .add_attribute("recipient", recipient.to_string())
                 .add_attribute("inj_amount", inj_amount.to_string())
                 .add_attribute("csinj_minted", csinj_amount.to_string())
-                .add_attribute("exchange_rate", exchange_rate.to_string()),
+                .add_attribute("exchange_rate", exchange_rate.to_string())
+                .add_attribute("stake_epoch", stake_epoch.to_string()),
         );
 
     for msg in delegate_msgs {
         response = response.add_message(msg);
     }
 
+    if let Some(event) = slashing_event {
+        response = response.add_event(event);
+    }
+
     Ok(response)
 }
 
-/// Unstake csINJ → begin unbonding, create unstake request.
+/// Unstake csINJ → burn it and add an unstake request to the open unbonding batch.
+/// The INJ stays delegated until the batch is submitted in `distribute_rewards`.
 pub fn unstake(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
+    recipient: Option<String>,
+    min_inj_out: Option<Uint128>,
+    deadline: Option<u64>,
 ) -> Result<ContractResponse, ContractError> {
+    assert_before_deadline(&env, deadline)?;
+
     let config = CONFIG.load(deps.storage)?;
+    let recipient = resolve_recipient(&deps, &info, recipient)?;
 
     // Validate funds: exactly one coin, must be csINJ
     if info.funds.is_empty() {
@@ -155,12 +429,16 @@ pub fn unstake(
         return Err(ContractError::NoUnstakeFunds);
     }
 
+    // Book any slashing first so the unstake is priced at the post-slash rate
+    let slashing_event = reconcile_slashing(deps.storage, deps.querier, &env)?;
+
     let exchange_rate = EXCHANGE_RATE.load(deps.storage)?;
 
     // Calculate INJ to return: csinj_amount * exchange_rate
     // Uses multiply_ratio with rate atomics (u256 internally) to avoid Decimal overflow.
     let rate_atomics = exchange_rate.atomics();
     let inj_amount = csinj_amount.multiply_ratio(rate_atomics, Uint128::new(DECIMAL_FRACTIONAL));
+    assert_min_out(inj_amount, min_inj_out)?;
 
     // Update totals
     // H-04 FIX: Use proper error handling instead of silent underflow
@@ -181,24 +459,22 @@ pub fn unstake(
     epoch_state.total_staked = new_backing;
     EPOCH_STATE.save(deps.storage, &epoch_state)?;
 
-    // Create unstake request
-    let unlock_time =
-        cosmwasm_std::Timestamp::from_seconds(env.block.time.seconds() + UNBONDING_PERIOD_SECS);
-    let request_id = NEXT_UNSTAKE_ID
-        .may_load(deps.storage, &info.sender)?
-        .unwrap_or(0);
-    let request = UnstakeRequest {
-        inj_amount,
-        csinj_burned: csinj_amount,
-        unlock_time,
-        claimed: false,
-    };
-    UNSTAKE_REQUESTS.save(deps.storage, (&info.sender, request_id), &request)?;
-    NEXT_UNSTAKE_ID.save(deps.storage, &info.sender, &(request_id + 1))?;
+    checkpoint_twab(
+        deps.storage,
+        env.block.time.seconds(),
+        Some(&info.sender),
+        None,
+        csinj_amount,
+    )?;
 
-    // Update pending unstake counter (O(1) instead of iterating all requests)
-    let pending_total = PENDING_UNSTAKE_TOTAL.load(deps.storage)?;
-    PENDING_UNSTAKE_TOTAL.save(deps.storage, &(pending_total + inj_amount))?;
+    let (batch_id, request_id) =
+        queue_unstake_request(deps.storage, &recipient, inj_amount, csinj_amount)?;
+
+    // Per-validator split the request's INJ would be drawn from at current delegations.
+    // The batch is undelegated as a whole and replanned when it is submitted, so an
+    // empty split here doesn't block the unstake.
+    let undelegations =
+        plan_undelegations(deps.querier, &env, &config, inj_amount).unwrap_or_default();
 
     // Burn csINJ via Token Factory
     let burn_msg = create_burn_tokens_msg(
@@ -209,39 +485,80 @@ pub fn unstake(
         },
     );
 
-    // Undelegate INJ from validators (round-robin)
-    let undelegate_msgs = create_undelegation_msgs(&config.validators, inj_amount)?;
-
     let mut response = ContractResponse::new()
         .add_message(burn_msg)
         .add_attribute("action", "unstake")
         .add_attribute("user", info.sender.to_string())
+        .add_attribute("recipient", recipient.to_string())
         .add_attribute("csinj_burned", csinj_amount.to_string())
         .add_attribute("inj_owed", inj_amount.to_string())
         .add_event(
             Event::new("chance_unstake")
                 .add_attribute("user", info.sender.to_string())
+                .add_attribute("recipient", recipient.to_string())
                 .add_attribute("csinj_burned", csinj_amount.to_string())
                 .add_attribute("inj_owed", inj_amount.to_string())
                 .add_attribute("exchange_rate", exchange_rate.to_string())
-                .add_attribute("unlock_time", unlock_time.seconds().to_string())
-                .add_attribute("request_id", request_id.to_string()),
+                .add_attribute("batch_id", batch_id.to_string())
+                .add_attribute("request_id", request_id.to_string())
+                .add_attribute("undelegations", format_undelegations(&undelegations)),
         );
 
-    for msg in undelegate_msgs {
-        response = response.add_message(msg);
+    if let Some(event) = slashing_event {
+        response = response.add_event(event);
     }
 
     Ok(response)
 }
 
-/// Claim unlocked unstake requests.
+/// Add an unstake request for `owner` to the open unbonding batch. If the batch has
+/// already been slashed, shares are issued at the post-slash ratio so the new request
+/// does not absorb part of the earlier loss. Returns (batch id, request id).
+fn queue_unstake_request(
+    storage: &mut dyn Storage,
+    owner: &Addr,
+    inj_amount: Uint128,
+    csinj_burned: Uint128,
+) -> StdResult<(u64, u64)> {
+    let mut batch = load_open_batch(storage)?;
+    let remaining = batch.total_inj - batch.slashed_inj;
+    let batch_shares = if batch.slashed_inj.is_zero() || remaining.is_zero() {
+        inj_amount
+    } else {
+        inj_amount.multiply_ratio(batch.total_shares, remaining)
+    };
+    batch.total_inj += inj_amount;
+    batch.total_shares += batch_shares;
+    batch.num_requests += 1;
+    UNBONDING_BATCHES.save(storage, batch.id, &batch)?;
+
+    let request_id = NEXT_UNSTAKE_ID.may_load(storage, owner)?.unwrap_or(0);
+    let request = UnstakeRequest {
+        inj_amount,
+        csinj_burned,
+        batch_id: batch.id,
+        batch_shares,
+        claimed: false,
+    };
+    UNSTAKE_REQUESTS.save(storage, (owner, request_id), &request)?;
+    NEXT_UNSTAKE_ID.save(storage, owner, &(request_id + 1))?;
+
+    // Update pending unstake counter (O(1) instead of iterating all requests)
+    let pending_total = PENDING_UNSTAKE_TOTAL.load(storage)?;
+    PENDING_UNSTAKE_TOTAL.save(storage, &(pending_total + inj_amount))?;
+
+    Ok((batch.id, request_id))
+}
+
+/// Claim the sender's unlocked unstake requests, paying out to `recipient`.
 pub fn claim_unstaked(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     request_ids: Vec<u64>,
+    recipient: Option<String>,
 ) -> Result<ContractResponse, ContractError> {
+    let recipient = resolve_recipient(&deps, &info, recipient)?;
     let mut total_claim = Uint128::zero();
 
     for id in &request_ids {
@@ -256,16 +573,25 @@ pub fn claim_unstaked(
             return Err(ContractError::UnstakeAlreadyClaimed { id: *id });
         }
 
-        if env.block.time < request.unlock_time {
+        // Requests unlock with their batch, once the batch has been undelegated
+        // and its unbonding period has passed.
+        let batch = UNBONDING_BATCHES.load(deps.storage, request.batch_id)?;
+        let unlock_time = batch
+            .unlock_time
+            .ok_or(ContractError::UnstakeBatchPending {
+                id: *id,
+                batch_id: request.batch_id,
+            })?;
+        if env.block.time < unlock_time {
             return Err(ContractError::UnstakeNotUnlocked {
                 id: *id,
-                unlock_time: request.unlock_time.seconds(),
+                unlock_time: unlock_time.seconds(),
             });
         }
 
         request.claimed = true;
         UNSTAKE_REQUESTS.save(deps.storage, (&info.sender, *id), &request)?;
-        total_claim += request.inj_amount;
+        total_claim += batch_payout(&batch, &request);
     }
 
     // Update pending unstake counter (O(1) instead of iterating all requests)
@@ -277,7 +603,7 @@ pub fn claim_unstaked(
     PENDING_UNSTAKE_TOTAL.save(deps.storage, &new_pending)?;
 
     let send_msg = BankMsg::Send {
-        to_address: info.sender.to_string(),
+        to_address: recipient.to_string(),
         amount: coins(total_claim.u128(), "inj"),
     };
 
@@ -285,10 +611,392 @@ pub fn claim_unstaked(
         .add_message(send_msg)
         .add_attribute("action", "claim_unstaked")
         .add_attribute("user", info.sender.to_string())
+        .add_attribute("recipient", recipient.to_string())
         .add_attribute("total_claimed", total_claim.to_string())
         .add_attribute("request_ids", format!("{:?}", request_ids)))
 }
 
+/// Deposit csINJ into yield-only mode. The hub keeps the csINJ, so it leaves the
+/// sender's draw weight, and credits shares at the current csINJ-per-share rate.
+pub fn enter_yield_only(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    recipient: Option<String>,
+) -> Result<ContractResponse, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let recipient = resolve_recipient(&deps, &info, recipient)?;
+
+    if info.funds.is_empty() {
+        return Err(ContractError::NoYieldOnlyFunds);
+    }
+    if info.funds.len() != 1 {
+        return Err(ContractError::InvalidFunds);
+    }
+    let sent = &info.funds[0];
+    if sent.denom != config.csinj_denom {
+        return Err(ContractError::WrongYieldOnlyDenom {
+            denom: sent.denom.clone(),
+        });
+    }
+    let csinj_amount = sent.amount;
+    if csinj_amount.is_zero() {
+        return Err(ContractError::NoYieldOnlyFunds);
+    }
+
+    let mut totals = YIELD_ONLY_TOTALS
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    let shares = if totals.shares.is_zero() || totals.csinj.is_zero() {
+        csinj_amount
+    } else {
+        csinj_amount.multiply_ratio(totals.shares, totals.csinj)
+    };
+    totals.shares += shares;
+    totals.csinj += csinj_amount;
+    YIELD_ONLY_TOTALS.save(deps.storage, &totals)?;
+    let position = YIELD_ONLY_SHARES
+        .may_load(deps.storage, &recipient)?
+        .unwrap_or_default()
+        + shares;
+    YIELD_ONLY_SHARES.save(deps.storage, &recipient, &position)?;
+
+    // The transfer hook ignores transfers into the hub, so book the balance change here
+    checkpoint_twab(
+        deps.storage,
+        env.block.time.seconds(),
+        Some(&info.sender),
+        None,
+        csinj_amount,
+    )?;
+
+    Ok(ContractResponse::new()
+        .add_attribute("action", "enter_yield_only")
+        .add_attribute("user", info.sender.to_string())
+        .add_attribute("recipient", recipient.to_string())
+        .add_attribute("csinj_amount", csinj_amount.to_string())
+        .add_attribute("shares", shares.to_string())
+        .add_event(
+            Event::new("chance_yield_only_entered")
+                .add_attribute("user", info.sender.to_string())
+                .add_attribute("recipient", recipient.to_string())
+                .add_attribute("csinj_amount", csinj_amount.to_string())
+                .add_attribute("shares", shares.to_string())
+                .add_attribute("total_shares", totals.shares.to_string())
+                .add_attribute("total_csinj", totals.csinj.to_string()),
+        ))
+}
+
+/// Redeem yield-only shares for csINJ. The csINJ re-enters the draws from the
+/// current epoch, like freshly staked csINJ.
+pub fn exit_yield_only(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    shares: Option<Uint128>,
+    recipient: Option<String>,
+) -> Result<ContractResponse, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let recipient = resolve_recipient(&deps, &info, recipient)?;
+
+    let available = YIELD_ONLY_SHARES
+        .may_load(deps.storage, &info.sender)?
+        .unwrap_or_default();
+    let shares = shares.unwrap_or(available);
+    if shares.is_zero() || shares > available {
+        return Err(ContractError::InsufficientYieldOnlyShares {
+            requested: shares,
+            available,
+        });
+    }
+
+    let mut totals = YIELD_ONLY_TOTALS.load(deps.storage)?;
+    let csinj_amount = shares.multiply_ratio(totals.csinj, totals.shares);
+    totals.shares -= shares;
+    totals.csinj -= csinj_amount;
+    YIELD_ONLY_TOTALS.save(deps.storage, &totals)?;
+    if shares == available {
+        YIELD_ONLY_SHARES.remove(deps.storage, &info.sender);
+    } else {
+        YIELD_ONLY_SHARES.save(deps.storage, &info.sender, &(available - shares))?;
+    }
+
+    // The transfer hook ignores transfers out of the hub: record the entry epoch and
+    // balance change here, as for a stake. The send below has not executed yet.
+    let current_epoch = EPOCH_STATE.load(deps.storage)?.current_epoch;
+    let stake_epoch = record_stake_epoch(
+        deps.storage,
+        deps.querier,
+        &config.csinj_denom,
+        &recipient,
+        csinj_amount,
+        current_epoch,
+    )?;
+    checkpoint_twab(
+        deps.storage,
+        env.block.time.seconds(),
+        None,
+        Some(&recipient),
+        csinj_amount,
+    )?;
+
+    let send_msg = BankMsg::Send {
+        to_address: recipient.to_string(),
+        amount: coins(csinj_amount.u128(), config.csinj_denom),
+    };
+
+    Ok(ContractResponse::new()
+        .add_message(send_msg)
+        .add_attribute("action", "exit_yield_only")
+        .add_attribute("user", info.sender.to_string())
+        .add_attribute("recipient", recipient.to_string())
+        .add_attribute("shares", shares.to_string())
+        .add_attribute("csinj_amount", csinj_amount.to_string())
+        .add_event(
+            Event::new("chance_yield_only_exited")
+                .add_attribute("user", info.sender.to_string())
+                .add_attribute("recipient", recipient.to_string())
+                .add_attribute("shares", shares.to_string())
+                .add_attribute("csinj_amount", csinj_amount.to_string())
+                .add_attribute("stake_epoch", stake_epoch.to_string())
+                .add_attribute("total_shares", totals.shares.to_string())
+                .add_attribute("total_csinj", totals.csinj.to_string()),
+        ))
+}
+
+/// Sponsor the prize pools with INJ. The INJ is delegated, but it mints no csINJ and
+/// backs none: its share of the staking rewards goes to the prize pools.
+pub fn sponsor(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<ContractResponse, ContractError> {
+    let inj_amount = must_pay_inj(&info)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    // Book any slashing first so the new shares are priced after it
+    let slashing_event = reconcile_slashing(deps.storage, deps.querier, &env)?;
+
+    let mut totals = SPONSOR_TOTALS.may_load(deps.storage)?.unwrap_or_default();
+    let shares = if totals.shares.is_zero() || totals.inj.is_zero() {
+        inj_amount
+    } else {
+        inj_amount.multiply_ratio(totals.shares, totals.inj)
+    };
+    totals.shares += shares;
+    totals.inj += inj_amount;
+    if !SPONSORS.has(deps.storage, &info.sender) {
+        SPONSORS.save(deps.storage, &info.sender, &())?;
+        totals.csinj += deps
+            .querier
+            .query_balance(&info.sender, &config.csinj_denom)?
+            .amount;
+    }
+    SPONSOR_TOTALS.save(deps.storage, &totals)?;
+    let position = SPONSOR_SHARES
+        .may_load(deps.storage, &info.sender)?
+        .unwrap_or_default()
+        + shares;
+    SPONSOR_SHARES.save(deps.storage, &info.sender, &position)?;
+
+    let delegate_msgs = create_delegation_msgs(deps.querier, &env, &config, inj_amount)?;
+    track_staking_msgs(deps.storage, deps.querier, &env, &delegate_msgs)?;
+
+    let mut response = ContractResponse::new()
+        .add_attribute("action", "sponsor")
+        .add_attribute("sponsor", info.sender.to_string())
+        .add_attribute("inj_amount", inj_amount.to_string())
+        .add_event(
+            Event::new("chance_sponsored")
+                .add_attribute("sponsor", info.sender.to_string())
+                .add_attribute("inj_amount", inj_amount.to_string())
+                .add_attribute("shares", shares.to_string())
+                .add_attribute("total_sponsored", totals.inj.to_string()),
+        );
+
+    for msg in delegate_msgs {
+        response = response.add_message(msg);
+    }
+
+    if let Some(event) = slashing_event {
+        response = response.add_event(event);
+    }
+
+    Ok(response)
+}
+
+/// Donate INJ to a prize pool or the base yield. The whole amount goes to the target:
+/// pool donations are forwarded to the distributor right away, and base yield is
+/// delegated and added to the backing, raising the exchange rate for all holders.
+pub fn donate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    target: DonationTarget,
+) -> Result<ContractResponse, ContractError> {
+    let inj_amount = must_pay_inj(&info)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    let mut totals = DONATION_TOTALS.may_load(deps.storage)?.unwrap_or_default();
+    let mut response = ContractResponse::new()
+        .add_attribute("action", "donate")
+        .add_attribute("donor", info.sender.to_string())
+        .add_attribute("inj_amount", inj_amount.to_string());
+
+    let fund_pool = |msg: DistributorExecuteMsg| -> StdResult<WasmMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr: config.reward_distributor.to_string(),
+            msg: cosmwasm_std::to_json_binary(&msg)?,
+            funds: coins(inj_amount.u128(), "inj"),
+        })
+    };
+    let (target_name, target_total) = match target {
+        DonationTarget::RegularPool => {
+            response = response.add_message(fund_pool(DistributorExecuteMsg::FundRegularPool {})?);
+            totals.regular_pool += inj_amount;
+            ("regular_pool", totals.regular_pool)
+        }
+        DonationTarget::BigPool => {
+            response = response.add_message(fund_pool(DistributorExecuteMsg::FundBigPool {})?);
+            totals.big_pool += inj_amount;
+            ("big_pool", totals.big_pool)
+        }
+        DonationTarget::BaseYield => {
+            let supply = TOTAL_CSINJ_SUPPLY.load(deps.storage)?;
+            if supply.is_zero() {
+                return Err(ContractError::NoCsinjSupply);
+            }
+
+            // Book any slashing first so the donation lands on the post-slash rate
+            if let Some(event) = reconcile_slashing(deps.storage, deps.querier, &env)? {
+                response = response.add_event(event);
+            }
+
+            let backing = TOTAL_INJ_BACKING.load(deps.storage)? + inj_amount;
+            let new_rate = Decimal::from_ratio(backing, supply);
+            TOTAL_INJ_BACKING.save(deps.storage, &backing)?;
+            EXCHANGE_RATE.save(deps.storage, &new_rate)?;
+
+            let delegate_msgs = create_delegation_msgs(deps.querier, &env, &config, inj_amount)?;
+            track_staking_msgs(deps.storage, deps.querier, &env, &delegate_msgs)?;
+            for msg in delegate_msgs {
+                response = response.add_message(msg);
+            }
+            response = response.add_attribute("new_exchange_rate", new_rate.to_string());
+
+            totals.base_yield += inj_amount;
+            ("base_yield", totals.base_yield)
+        }
+    };
+    DONATION_TOTALS.save(deps.storage, &totals)?;
+
+    Ok(response.add_event(
+        Event::new("chance_donation")
+            .add_attribute("donor", info.sender.to_string())
+            .add_attribute("target", target_name)
+            .add_attribute("inj_amount", inj_amount.to_string())
+            .add_attribute("target_total", target_total.to_string()),
+    ))
+}
+
+/// The INJ amount sent with a message that takes exactly one positive INJ coin.
+fn must_pay_inj(info: &MessageInfo) -> Result<Uint128, ContractError> {
+    if info.funds.is_empty() {
+        return Err(ContractError::NoFundsSent);
+    }
+    if info.funds.len() != 1 {
+        return Err(ContractError::InvalidFunds);
+    }
+    let sent = &info.funds[0];
+    if sent.denom != "inj" {
+        return Err(ContractError::WrongDenom {
+            denom: sent.denom.clone(),
+        });
+    }
+    if sent.amount.is_zero() {
+        return Err(ContractError::NoFundsSent);
+    }
+    Ok(sent.amount)
+}
+
+/// Withdraw the sender's whole sponsorship. The INJ is owed through an unstake
+/// request in the open unbonding batch, so it unbonds and is claimed like an unstake.
+pub fn withdraw_sponsorship(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<ContractResponse, ContractError> {
+    let shares = SPONSOR_SHARES.may_load(deps.storage, &info.sender)?.ok_or(
+        ContractError::NoSponsorship {
+            address: info.sender.to_string(),
+        },
+    )?;
+
+    // Book any slashing first so the withdrawal carries its share of it
+    let slashing_event = reconcile_slashing(deps.storage, deps.querier, &env)?;
+
+    let mut totals = SPONSOR_TOTALS.load(deps.storage)?;
+    let inj_amount = shares.multiply_ratio(totals.inj, totals.shares);
+    totals.shares -= shares;
+    totals.inj -= inj_amount;
+    SPONSOR_TOTALS.save(deps.storage, &totals)?;
+    SPONSOR_SHARES.remove(deps.storage, &info.sender);
+
+    let (batch_id, request_id) =
+        queue_unstake_request(deps.storage, &info.sender, inj_amount, Uint128::zero())?;
+
+    let mut response = ContractResponse::new()
+        .add_attribute("action", "withdraw_sponsorship")
+        .add_attribute("sponsor", info.sender.to_string())
+        .add_attribute("inj_owed", inj_amount.to_string())
+        .add_event(
+            Event::new("chance_sponsorship_withdrawn")
+                .add_attribute("sponsor", info.sender.to_string())
+                .add_attribute("inj_owed", inj_amount.to_string())
+                .add_attribute("batch_id", batch_id.to_string())
+                .add_attribute("request_id", request_id.to_string())
+                .add_attribute("total_sponsored", totals.inj.to_string()),
+        );
+
+    if let Some(event) = slashing_event {
+        response = response.add_event(event);
+    }
+
+    Ok(response)
+}
+
+/// Validate an optional recipient address, defaulting to the sender.
+fn resolve_recipient(
+    deps: &DepsMut,
+    info: &MessageInfo,
+    recipient: Option<String>,
+) -> StdResult<Addr> {
+    match recipient {
+        Some(addr) => deps.api.addr_validate(&addr),
+        None => Ok(info.sender.clone()),
+    }
+}
+
+/// Reject the transaction if it executes after the caller's deadline.
+fn assert_before_deadline(env: &Env, deadline: Option<u64>) -> Result<(), ContractError> {
+    match deadline {
+        Some(deadline) if env.block.time.seconds() > deadline => {
+            Err(ContractError::DeadlineExceeded { deadline })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Reject the transaction if the amount out is below the caller's minimum.
+fn assert_min_out(amount: Uint128, min_out: Option<Uint128>) -> Result<(), ContractError> {
+    match min_out {
+        Some(min_out) if amount < min_out => {
+            Err(ContractError::SlippageExceeded { amount, min_out })
+        }
+        _ => Ok(()),
+    }
+}
+
 /// Step 1 of epoch advancement: withdraw staking rewards from all validators.
 /// The rewards will be deposited into this contract's balance.
 /// Operator must call `DistributeRewards` after this tx confirms.
@@ -339,7 +1047,8 @@ pub fn claim_rewards(
 
 /// Step 2 of epoch advancement: distribute the claimed rewards and advance epoch.
 /// Reads the contract's INJ balance, subtracts reserved amounts (pending unstake claims),
-/// and distributes the surplus as staking rewards.
+/// and distributes the surplus as staking rewards. Also submits the open unbonding
+/// batch when the submission interval allows it.
 /// Operator only.
 pub fn distribute_rewards(
     deps: DepsMut,
@@ -354,57 +1063,310 @@ pub fn distribute_rewards(
         });
     }
 
-    let mut epoch_state = EPOCH_STATE.load(deps.storage)?;
+    ensure_epoch_ready(deps.storage, &env, &config)?;
+
+    // Query this contract's current INJ balance
+    let contract_balance = query_contract_inj_balance(deps.querier, &env)?;
+
+    // Calculate reserved INJ: total pending unstake claims not yet collected.
+    // Uses the O(1) counter instead of iterating all requests.
+    let reserved = PENDING_UNSTAKE_TOTAL.load(deps.storage)?;
+    let total_rewards = contract_balance.saturating_sub(reserved);
+
+    close_epoch(
+        deps,
+        env,
+        &config,
+        total_rewards,
+        Uint128::zero(),
+        None,
+        "distribute_rewards",
+    )
+}
+
+/// Claim and distribute the epoch's rewards in one transaction. The reward withdrawals
+/// are dispatched as submessages and the distribution runs in the reply to the last
+/// one, with only the INJ the withdrawals added to the balance counted as rewards.
+/// Anyone can call once the epoch has ended; callers other than the operator are paid
+/// the keeper fee once the snapshot grace period after the epoch end has passed, so
+/// the protocol keeps running without the operator.
+pub fn advance_epoch(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<ContractResponse, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
 
-    // H-01 FIX: Enforce epoch duration before allowing distribution
+    if config.validators.is_empty() {
+        return Err(ContractError::NoValidators);
+    }
+
+    ensure_epoch_ready(deps.storage, &env, &config)?;
+    // Others can step in during the operator's grace period, but are only paid the
+    // keeper fee after it
+    let grace_end = operator_grace_end(&EPOCH_STATE.load(deps.storage)?, &config);
+    let keeper = (info.sender != config.operator && env.block.time >= grace_end)
+        .then(|| info.sender.clone());
+
+    let balance_before = query_contract_inj_balance(deps.querier, &env)?;
+    PENDING_EPOCH_ADVANCE.save(
+        deps.storage,
+        &PendingEpochAdvance {
+            balance_before,
+            keeper,
+        },
+    )?;
+
+    let mut response = ContractResponse::new()
+        .add_message(DistributionMsg::SetWithdrawAddress {
+            address: env.contract.address.to_string(),
+        })
+        .add_attribute("action", "advance_epoch")
+        .add_attribute("balance_before", balance_before.to_string());
+
+    // Submessages run in order, so by the last reply every withdrawal has landed
+    let last = config.validators.len() - 1;
+    for (i, validator) in config.validators.iter().enumerate() {
+        let withdraw_msg = DistributionMsg::WithdrawDelegatorReward {
+            validator: validator.clone(),
+        };
+        let submsg = if i == last {
+            SubMsg::reply_on_success(withdraw_msg, ADVANCE_EPOCH_REPLY_ID)
+        } else {
+            SubMsg::new(withdraw_msg)
+        };
+        response = response.add_submessage(submsg);
+    }
+
+    Ok(response)
+}
+
+/// Reply to AdvanceEpoch's last reward withdrawal: distribute the balance delta since
+/// the withdrawals were dispatched and advance the epoch.
+pub fn advance_epoch_reply(deps: DepsMut, env: Env) -> Result<ContractResponse, ContractError> {
+    let pending = PENDING_EPOCH_ADVANCE
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingEpochAdvance)?;
+    PENDING_EPOCH_ADVANCE.remove(deps.storage);
+
+    let config = CONFIG.load(deps.storage)?;
+    let balance_after = query_contract_inj_balance(deps.querier, &env)?;
+    // Rewards paid out by delegation changes during the epoch were already in the
+    // starting balance
+    let auto_withdrawn = AUTO_WITHDRAWN_REWARDS
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    let total_rewards = balance_after.saturating_sub(pending.balance_before) + auto_withdrawn;
+    // Whatever else was already there beyond pending claims isn't validator rewards.
+    // Only report what arrived since the last epoch, the rest was reported before.
+    let outstanding_surplus = pending
+        .balance_before
+        .saturating_sub(PENDING_UNSTAKE_TOTAL.load(deps.storage)?)
+        .saturating_sub(auto_withdrawn);
+    let unexplained_surplus = outstanding_surplus.saturating_sub(
+        UNEXPLAINED_SURPLUS
+            .may_load(deps.storage)?
+            .unwrap_or_default(),
+    );
+    UNEXPLAINED_SURPLUS.save(deps.storage, &outstanding_surplus)?;
+
+    close_epoch(
+        deps,
+        env,
+        &config,
+        total_rewards,
+        unexplained_surplus,
+        pending.keeper.as_ref(),
+        "advance_epoch_reply",
+    )
+}
+
+/// End of the operator's grace period: snapshot_grace_seconds after the current epoch
+/// ends. Until then only the operator may take the snapshot and keepers aren't paid.
+fn operator_grace_end(epoch_state: &EpochState, config: &Config) -> Timestamp {
+    epoch_state
+        .epoch_start_time
+        .plus_seconds(config.epoch_duration_seconds)
+        .plus_seconds(config.snapshot_grace_seconds)
+}
+
+/// H-01 FIX: Enforce epoch duration before allowing distribution
+fn ensure_epoch_ready(
+    storage: &dyn Storage,
+    env: &Env,
+    config: &Config,
+) -> Result<(), ContractError> {
+    let epoch_state = EPOCH_STATE.load(storage)?;
     let epoch_end_time = epoch_state
         .epoch_start_time
         .plus_seconds(config.epoch_duration_seconds);
     if env.block.time < epoch_end_time {
         return Err(ContractError::EpochNotReady);
     }
+    Ok(())
+}
 
-    // Query this contract's current INJ balance
-    let contract_balance = query_contract_inj_balance(deps.querier, &env)?;
+/// Split `total_rewards` between the pools, base yield and treasury, submit the open
+/// unbonding batch when the submission interval allows it, and advance the epoch.
+/// A `keeper` is paid keeper_fee_bps of the treasury fee. `unexplained_surplus` is only
+/// recorded and reported.
+#[allow(clippy::too_many_arguments)]
+fn close_epoch(
+    deps: DepsMut,
+    env: Env,
+    config: &Config,
+    total_rewards: Uint128,
+    unexplained_surplus: Uint128,
+    keeper: Option<&Addr>,
+    action: &str,
+) -> Result<ContractResponse, ContractError> {
+    let mut epoch_state = EPOCH_STATE.load(deps.storage)?;
 
-    // Calculate reserved INJ: total pending unstake claims not yet collected.
-    // Uses the O(1) counter instead of iterating all requests.
-    let reserved = PENDING_UNSTAKE_TOTAL.load(deps.storage)?;
-    let total_rewards = contract_balance.saturating_sub(reserved);
+    // The auto-withdrawn rewards are part of `total_rewards`. Reset before the base
+    // yield and batch messages below book the next epoch's.
+    AUTO_WITHDRAWN_REWARDS.save(deps.storage, &Uint128::zero())?;
+
+    // Book any slashing since the last reconciliation before updating the rate
+    let slashing_event = reconcile_slashing(deps.storage, deps.querier, &env)?;
+
+    let total_backing = TOTAL_INJ_BACKING.load(deps.storage)?;
+    let total_supply = TOTAL_CSINJ_SUPPLY.load(deps.storage)?;
+
+    // Rewards earned by sponsored INJ go to the prize pools only, split between them
+    // in the ratio of their bps. The open unbonding batch is still delegated, so it
+    // counts toward the delegated total like in reconcile_slashing; its rewards stay
+    // with the stakers.
+    let sponsored = SPONSOR_TOTALS
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .inj;
+    let open_batch = load_open_batch(deps.storage)?;
+    let delegated = total_backing + (open_batch.total_inj - open_batch.slashed_inj) + sponsored;
+    let pool_bps = config.regular_pool_bps as u128 + config.big_pool_bps as u128;
+    let sponsor_rewards = if pool_bps == 0 || sponsored.is_zero() {
+        Uint128::zero()
+    } else {
+        total_rewards.multiply_ratio(sponsored, delegated)
+    };
+    let sponsor_regular = if pool_bps == 0 {
+        Uint128::zero()
+    } else {
+        sponsor_rewards.multiply_ratio(config.regular_pool_bps as u128, pool_bps)
+    };
+    let sponsor_big = sponsor_rewards - sponsor_regular;
+    let staker_rewards = total_rewards - sponsor_rewards;
 
     // Split rewards according to basis points
-    let regular_amount = total_rewards.multiply_ratio(config.regular_pool_bps as u128, 10000u128);
-    let big_amount = total_rewards.multiply_ratio(config.big_pool_bps as u128, 10000u128);
-    let base_yield = total_rewards.multiply_ratio(config.base_yield_bps as u128, 10000u128);
+    let regular_amount = staker_rewards.multiply_ratio(config.regular_pool_bps as u128, 10000u128);
+    let big_amount = staker_rewards.multiply_ratio(config.big_pool_bps as u128, 10000u128);
+    let base_yield = staker_rewards.multiply_ratio(config.base_yield_bps as u128, 10000u128);
     // L-04 FIX: Calculate treasury_fee as remainder to eliminate rounding dust
     // V2-L-02 FIX: Use saturating_sub to make intent explicit (remainder can't underflow
     // because multiply_ratio truncates, but saturating_sub is clearer than unwrap_or(zero))
-    let treasury_fee = total_rewards
+    let treasury_fee = staker_rewards
         .saturating_sub(regular_amount)
         .saturating_sub(big_amount)
         .saturating_sub(base_yield);
+    let keeper_fee = match keeper {
+        Some(_) => treasury_fee.multiply_ratio(config.keeper_fee_bps as u128, 10000u128),
+        None => Uint128::zero(),
+    };
+    let treasury_fee = treasury_fee - keeper_fee;
+
+    // Yield-only csINJ doesn't play the draws: its pro-rata share of the pool
+    // allocation is restaked for it instead
+    let mut yield_only = YIELD_ONLY_TOTALS
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    let (regular_yield_only, big_yield_only) = if total_supply.is_zero() {
+        (Uint128::zero(), Uint128::zero())
+    } else {
+        (
+            regular_amount.multiply_ratio(yield_only.csinj, total_supply),
+            big_amount.multiply_ratio(yield_only.csinj, total_supply),
+        )
+    };
+    let regular_amount = regular_amount - regular_yield_only + sponsor_regular;
+    let big_amount = big_amount - big_yield_only + sponsor_big;
+    let yield_only_amount = regular_yield_only + big_yield_only;
 
     // C-01 FIX: Delegate base yield to validators (prevents double-counting in future epochs)
-    let base_yield_delegate_msgs = if !base_yield.is_zero() {
-        create_delegation_msgs(&config.validators, base_yield)?
+    let restaked = base_yield + yield_only_amount;
+    let base_yield_delegate_msgs = if !restaked.is_zero() {
+        create_delegation_msgs(deps.querier, &env, config, restaked)?
     } else {
         vec![]
     };
+    track_staking_msgs(deps.storage, deps.querier, &env, &base_yield_delegate_msgs)?;
 
     // Update exchange rate with base yield
-    let total_backing = TOTAL_INJ_BACKING.load(deps.storage)?;
-    let total_supply = TOTAL_CSINJ_SUPPLY.load(deps.storage)?;
-
-    let new_backing = total_backing + base_yield;
-    TOTAL_INJ_BACKING.save(deps.storage, &new_backing)?;
+    let base_rate = if total_supply.is_zero() {
+        Decimal::one()
+    } else {
+        Decimal::from_ratio(total_backing + base_yield, total_supply)
+    };
 
-    let new_rate = if total_supply.is_zero() {
+    // Mint the yield-only restake as csINJ into the hub's yield-only holdings. The
+    // INJ and csINJ are added at the same rate, so other holders' rate is unchanged.
+    let yield_only_minted =
+        yield_only_amount.multiply_ratio(Uint128::new(DECIMAL_FRACTIONAL), base_rate.atomics());
+    let new_backing = total_backing + base_yield + yield_only_amount;
+    let new_supply = total_supply + yield_only_minted;
+    let new_rate = if new_supply.is_zero() {
         Decimal::one()
     } else {
-        Decimal::from_ratio(new_backing, total_supply)
+        Decimal::from_ratio(new_backing, new_supply)
     };
+    let yield_only_mint_msg = if !yield_only_minted.is_zero() {
+        yield_only.csinj += yield_only_minted;
+        YIELD_ONLY_TOTALS.save(deps.storage, &yield_only)?;
+        TOTAL_CSINJ_SUPPLY.save(deps.storage, &new_supply)?;
+        Some(create_mint_tokens_msg(
+            env.contract.address.clone(),
+            Coin {
+                denom: config.csinj_denom.clone(),
+                amount: yield_only_minted,
+            },
+            env.contract.address.to_string(),
+        ))
+    } else {
+        None
+    };
+    TOTAL_INJ_BACKING.save(deps.storage, &new_backing)?;
     EXCHANGE_RATE.save(deps.storage, &new_rate)?;
 
+    // Undelegate the open unbonding batch (rate limited to stay under the entry cap)
+    let (undelegate_msgs, submitted_batch) =
+        submit_unbonding_batch(deps.storage, deps.querier, &env, config)?;
+    track_staking_msgs(deps.storage, deps.querier, &env, &undelegate_msgs)?;
+
+    EPOCH_HISTORY.save(
+        deps.storage,
+        epoch_state.current_epoch,
+        &EpochRecord {
+            epoch: epoch_state.current_epoch,
+            start_time: epoch_state.epoch_start_time,
+            end_time: env.block.time,
+            total_rewards,
+            sponsor_rewards,
+            regular_pool_amount: regular_amount,
+            big_pool_amount: big_amount,
+            base_yield,
+            yield_only_amount,
+            yield_only_minted,
+            treasury_fee,
+            keeper_fee,
+            unexplained_surplus,
+            total_inj_backing: new_backing,
+            total_csinj_supply: new_supply,
+            exchange_rate: new_rate,
+            snapshot_merkle_root: epoch_state.snapshot_merkle_root.clone(),
+            snapshot_total_weight: epoch_state.snapshot_total_weight,
+            snapshot_num_holders: epoch_state.snapshot_num_holders,
+            snapshot_uri: epoch_state.snapshot_uri.clone(),
+        },
+    )?;
+
     // Advance epoch
     epoch_state.current_epoch += 1;
     epoch_state.epoch_start_time = env.block.time;
@@ -414,13 +1376,18 @@ pub fn distribute_rewards(
     epoch_state.snapshot_total_weight = Uint128::zero();
     epoch_state.snapshot_num_holders = 0;
     epoch_state.snapshot_uri = None;
+    epoch_state.snapshot_bounds = None;
     EPOCH_STATE.save(deps.storage, &epoch_state)?;
 
     let mut response = ContractResponse::new()
-        .add_attribute("action", "distribute_rewards")
+        .add_attribute("action", action)
         .add_attribute("new_epoch", epoch_state.current_epoch.to_string())
         .add_attribute("total_rewards", total_rewards.to_string());
 
+    if let Some(event) = slashing_event {
+        response = response.add_event(event);
+    }
+
     // Fund regular pool
     if !regular_amount.is_zero() {
         let fund_regular_msg = WasmMsg::Execute {
@@ -450,25 +1417,443 @@ pub fn distribute_rewards(
         response = response.add_message(treasury_msg);
     }
 
+    // Pay the keeper that advanced the epoch in the operator's place
+    if let Some(keeper) = keeper {
+        if !keeper_fee.is_zero() {
+            response = response.add_message(BankMsg::Send {
+                to_address: keeper.to_string(),
+                amount: coins(keeper_fee.u128(), "inj"),
+            });
+        }
+        response = response.add_attribute("keeper", keeper.to_string());
+    }
+
+    if let Some(msg) = yield_only_mint_msg {
+        response = response.add_message(msg);
+    }
+
     // C-01 FIX: Delegate base yield INJ to validators
     for msg in base_yield_delegate_msgs {
         response = response.add_message(msg);
     }
 
+    for msg in undelegate_msgs {
+        response = response.add_message(msg);
+    }
+
+    if let Some(batch) = submitted_batch {
+        response = response.add_event(
+            Event::new("chance_unbonding_batch_submitted")
+                .add_attribute("batch_id", batch.id.to_string())
+                .add_attribute("total_inj", batch.total_inj.to_string())
+                .add_attribute("slashed_inj", batch.slashed_inj.to_string())
+                .add_attribute("num_requests", batch.num_requests.to_string())
+                .add_attribute("undelegations", format_undelegations(&batch.undelegations))
+                .add_attribute(
+                    "unlock_time",
+                    batch
+                        .unlock_time
+                        .map(|t| t.seconds())
+                        .unwrap_or_default()
+                        .to_string(),
+                ),
+        );
+    }
+
     response = response.add_event(
         Event::new("chance_epoch_advanced")
             .add_attribute("epoch", epoch_state.current_epoch.to_string())
             .add_attribute("total_rewards", total_rewards.to_string())
+            .add_attribute("sponsor_rewards", sponsor_rewards.to_string())
+            .add_attribute("unexplained_surplus", unexplained_surplus.to_string())
             .add_attribute("regular_pool_funded", regular_amount.to_string())
             .add_attribute("big_pool_funded", big_amount.to_string())
             .add_attribute("base_yield_added", base_yield.to_string())
+            .add_attribute("yield_only_added", yield_only_amount.to_string())
             .add_attribute("treasury_fee", treasury_fee.to_string())
+            .add_attribute("keeper_fee", keeper_fee.to_string())
             .add_attribute("new_exchange_rate", new_rate.to_string()),
     );
 
     Ok(response)
 }
 
+/// Load the unbonding batch that is currently collecting unstake requests.
+pub fn load_open_batch(storage: &dyn Storage) -> StdResult<UnbondingBatch> {
+    let id = CURRENT_BATCH_ID.load(storage)?;
+    Ok(UNBONDING_BATCHES
+        .may_load(storage, id)?
+        .unwrap_or(UnbondingBatch {
+            id,
+            total_inj: Uint128::zero(),
+            slashed_inj: Uint128::zero(),
+            total_shares: Uint128::zero(),
+            num_requests: 0,
+            submitted_at: None,
+            unlock_time: None,
+            undelegations: vec![],
+        }))
+}
+
+/// Move a deployment from per-request undelegations to unbonding batches. Legacy
+/// unstake requests are grouped by unlock time into already-submitted batches, so
+/// they unlock exactly as before, and the open batch starts after them. The
+/// expected-delegation ledger is seeded from the current delegations.
+/// Does nothing if the batch state already exists.
+pub fn migrate_legacy_unstake_requests(
+    storage: &mut dyn Storage,
+    querier: QuerierWrapper,
+    env: &Env,
+) -> StdResult<u64> {
+    if CURRENT_BATCH_ID.may_load(storage)?.is_some() {
+        return Ok(0);
+    }
+
+    let legacy = LEGACY_UNSTAKE_REQUESTS
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut unlock_times: Vec<Timestamp> = legacy.iter().map(|(_, r)| r.unlock_time).collect();
+    unlock_times.sort();
+    unlock_times.dedup();
+
+    for (i, unlock_time) in unlock_times.iter().enumerate() {
+        let id = i as u64 + 1;
+        let mut batch = UnbondingBatch {
+            id,
+            total_inj: Uint128::zero(),
+            slashed_inj: Uint128::zero(),
+            total_shares: Uint128::zero(),
+            num_requests: 0,
+            submitted_at: Some(unlock_time.minus_seconds(UNBONDING_PERIOD_SECS)),
+            unlock_time: Some(*unlock_time),
+            undelegations: vec![],
+        };
+        for ((owner, request_id), request) in
+            legacy.iter().filter(|(_, r)| r.unlock_time == *unlock_time)
+        {
+            batch.total_inj += request.inj_amount;
+            batch.total_shares += request.inj_amount;
+            batch.num_requests += 1;
+            UNSTAKE_REQUESTS.save(
+                storage,
+                (owner, *request_id),
+                &UnstakeRequest {
+                    inj_amount: request.inj_amount,
+                    csinj_burned: request.csinj_burned,
+                    batch_id: id,
+                    batch_shares: request.inj_amount,
+                    claimed: request.claimed,
+                },
+            )?;
+        }
+        UNBONDING_BATCHES.save(storage, id, &batch)?;
+    }
+    CURRENT_BATCH_ID.save(storage, &(unlock_times.len() as u64 + 1))?;
+
+    for delegation in querier.query_all_delegations(&env.contract.address)? {
+        EXPECTED_DELEGATIONS.save(storage, &delegation.validator, &delegation.amount.amount)?;
+    }
+
+    Ok(unlock_times.len() as u64)
+}
+
+/// Submit the open unbonding batch: undelegate its total and start its unbonding period.
+/// Skipped if the batch is empty, the previous batch was submitted less than
+/// BATCH_SUBMIT_INTERVAL_SECS ago, or the delegations fall short of the batch (a slash
+/// not booked yet); requests then keep accumulating in the open batch.
+fn submit_unbonding_batch(
+    storage: &mut dyn Storage,
+    querier: QuerierWrapper,
+    env: &Env,
+    config: &Config,
+) -> Result<(Vec<CosmosMsg<InjectiveMsgWrapper>>, Option<UnbondingBatch>), ContractError> {
+    let mut batch = load_open_batch(storage)?;
+    if batch.total_inj.is_zero() {
+        return Ok((vec![], None));
+    }
+
+    if batch.id > 1 {
+        let previous = UNBONDING_BATCHES.may_load(storage, batch.id - 1)?;
+        if let Some(submitted_at) = previous.and_then(|b| b.submitted_at) {
+            if env.block.time < submitted_at.plus_seconds(BATCH_SUBMIT_INTERVAL_SECS) {
+                return Ok((vec![], None));
+            }
+        }
+    }
+
+    let undelegations =
+        match plan_undelegations(querier, env, config, batch.total_inj - batch.slashed_inj) {
+            Ok(undelegations) => undelegations,
+            // Don't fail the epoch over it; the batch is retried next epoch
+            Err(ContractError::InsufficientDelegations { .. }) => return Ok((vec![], None)),
+            Err(err) => return Err(err),
+        };
+    let msgs = undelegations
+        .iter()
+        .map(|u| {
+            CosmosMsg::Staking(StakingMsg::Undelegate {
+                validator: u.validator.clone(),
+                amount: Coin {
+                    denom: "inj".to_string(),
+                    amount: u.amount,
+                },
+            })
+        })
+        .collect();
+
+    batch.undelegations = undelegations;
+    batch.submitted_at = Some(env.block.time);
+    batch.unlock_time = Some(env.block.time.plus_seconds(UNBONDING_PERIOD_SECS));
+    UNBONDING_BATCHES.save(storage, batch.id, &batch)?;
+    CURRENT_BATCH_ID.save(storage, &(batch.id + 1))?;
+
+    Ok((msgs, Some(batch)))
+}
+
+/// Update the expected-delegation ledger for delegate, undelegate and redelegate
+/// messages about to be dispatched, and book the rewards they withdraw. The SDK pays
+/// out a delegation's pending rewards whenever the delegation changes, so these reach
+/// the hub before AdvanceEpoch records its starting balance.
+fn track_staking_msgs(
+    storage: &mut dyn Storage,
+    querier: QuerierWrapper,
+    env: &Env,
+    msgs: &[CosmosMsg<InjectiveMsgWrapper>],
+) -> StdResult<()> {
+    let mut touched: Vec<&str> = vec![];
+    for msg in msgs {
+        match msg {
+            CosmosMsg::Staking(StakingMsg::Delegate { validator, .. })
+            | CosmosMsg::Staking(StakingMsg::Undelegate { validator, .. }) => {
+                touched.push(validator);
+            }
+            CosmosMsg::Staking(StakingMsg::Redelegate {
+                src_validator,
+                dst_validator,
+                ..
+            }) => {
+                touched.push(src_validator);
+                touched.push(dst_validator);
+            }
+            _ => {}
+        }
+    }
+    touched.sort();
+    touched.dedup();
+    let mut withdrawn = Uint128::zero();
+    for validator in touched {
+        if let Some(delegation) = querier.query_delegation(&env.contract.address, validator)? {
+            withdrawn += delegation
+                .accumulated_rewards
+                .iter()
+                .filter(|coin| coin.denom == "inj")
+                .map(|coin| coin.amount)
+                .sum::<Uint128>();
+        }
+    }
+    if !withdrawn.is_zero() {
+        let total = AUTO_WITHDRAWN_REWARDS
+            .may_load(storage)?
+            .unwrap_or_default();
+        AUTO_WITHDRAWN_REWARDS.save(storage, &(total + withdrawn))?;
+    }
+
+    for msg in msgs {
+        match msg {
+            CosmosMsg::Staking(StakingMsg::Delegate { validator, amount }) => {
+                EXPECTED_DELEGATIONS.update(storage, validator, |cur| -> StdResult<_> {
+                    Ok(cur.unwrap_or_default() + amount.amount)
+                })?;
+            }
+            CosmosMsg::Staking(StakingMsg::Undelegate { validator, amount }) => {
+                EXPECTED_DELEGATIONS.update(storage, validator, |cur| -> StdResult<_> {
+                    Ok(cur.unwrap_or_default().saturating_sub(amount.amount))
+                })?;
+            }
+            CosmosMsg::Staking(StakingMsg::Redelegate {
+                src_validator,
+                dst_validator,
+                amount,
+            }) => {
+                EXPECTED_DELEGATIONS.update(storage, src_validator, |cur| -> StdResult<_> {
+                    Ok(cur.unwrap_or_default().saturating_sub(amount.amount))
+                })?;
+                EXPECTED_DELEGATIONS.update(storage, dst_validator, |cur| -> StdResult<_> {
+                    Ok(cur.unwrap_or_default() + amount.amount)
+                })?;
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Compare actual delegations with the expected-delegation ledger and book any
+/// shortfall as a slashing loss. Validators with no delegation at all are skipped,
+/// since slashing reduces a delegation but never removes it.
+///
+/// The open unbonding batch and the sponsored INJ are still delegated, so the loss is
+/// split pro rata between them and TOTAL_INJ_BACKING. Submitted batches that are still unbonding from a slashed
+/// validator lose the same fraction the delegation lost. The exchange rate is
+/// recomputed from the reduced backing.
+/// Returns a `chance_slashing_detected` event when a loss was found.
+fn reconcile_slashing(
+    storage: &mut dyn Storage,
+    querier: QuerierWrapper,
+    env: &Env,
+) -> Result<Option<Event>, ContractError> {
+    let expected: Vec<(String, Uint128)> = EXPECTED_DELEGATIONS
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+
+    // (validator, loss, expected delegation before the loss)
+    let mut losses: Vec<(String, Uint128, Uint128)> = Vec::new();
+    for (validator, expected_amount) in expected {
+        let actual = match querier.query_delegation(&env.contract.address, &validator)? {
+            Some(delegation) => delegation.amount.amount,
+            None => continue,
+        };
+        if actual < expected_amount {
+            losses.push((validator.clone(), expected_amount - actual, expected_amount));
+            EXPECTED_DELEGATIONS.save(storage, &validator, &actual)?;
+        }
+    }
+
+    if losses.is_empty() {
+        return Ok(None);
+    }
+    let total_loss: Uint128 = losses.iter().map(|(_, loss, _)| *loss).sum();
+
+    let old_backing = TOTAL_INJ_BACKING.load(storage)?;
+    let mut open_batch = load_open_batch(storage)?;
+    let open_remaining = open_batch.total_inj - open_batch.slashed_inj;
+    let mut sponsors = SPONSOR_TOTALS.may_load(storage)?.unwrap_or_default();
+    let delegated = old_backing + open_remaining + sponsors.inj;
+    let open_batch_loss = if open_remaining.is_zero() {
+        Uint128::zero()
+    } else {
+        total_loss
+            .multiply_ratio(open_remaining, delegated)
+            .min(open_remaining)
+    };
+    if !open_batch_loss.is_zero() {
+        open_batch.slashed_inj += open_batch_loss;
+        UNBONDING_BATCHES.save(storage, open_batch.id, &open_batch)?;
+    }
+    let sponsor_loss = if sponsors.inj.is_zero() {
+        Uint128::zero()
+    } else {
+        total_loss
+            .multiply_ratio(sponsors.inj, delegated)
+            .min(sponsors.inj)
+    };
+    if !sponsor_loss.is_zero() {
+        sponsors.inj -= sponsor_loss;
+        SPONSOR_TOTALS.save(storage, &sponsors)?;
+    }
+
+    let new_backing = old_backing.saturating_sub(total_loss - open_batch_loss - sponsor_loss);
+    TOTAL_INJ_BACKING.save(storage, &new_backing)?;
+
+    let unbonding_loss = slash_unbonding_batches(storage, env, &losses)?;
+    let pending_total = PENDING_UNSTAKE_TOTAL.load(storage)?;
+    PENDING_UNSTAKE_TOTAL.save(
+        storage,
+        &pending_total.saturating_sub(open_batch_loss + unbonding_loss),
+    )?;
+
+    let mut epoch_state = EPOCH_STATE.load(storage)?;
+    epoch_state.total_staked = new_backing;
+    EPOCH_STATE.save(storage, &epoch_state)?;
+
+    let total_supply = TOTAL_CSINJ_SUPPLY.load(storage)?;
+    let new_rate = if total_supply.is_zero() {
+        Decimal::one()
+    } else {
+        Decimal::from_ratio(new_backing, total_supply)
+    };
+    EXCHANGE_RATE.save(storage, &new_rate)?;
+
+    Ok(Some(
+        Event::new("chance_slashing_detected")
+            .add_attribute("total_loss", total_loss.to_string())
+            .add_attribute(
+                "losses",
+                losses
+                    .iter()
+                    .map(|(validator, loss, _)| format!("{}:{}", validator, loss))
+                    .collect::<Vec<_>>()
+                    .join(","),
+            )
+            .add_attribute("open_batch_loss", open_batch_loss.to_string())
+            .add_attribute("sponsor_loss", sponsor_loss.to_string())
+            .add_attribute("unbonding_loss", unbonding_loss.to_string())
+            .add_attribute("old_backing", old_backing.to_string())
+            .add_attribute("new_backing", new_backing.to_string())
+            .add_attribute("new_rate", new_rate.to_string()),
+    ))
+}
+
+/// Apply each validator's observed slash fraction to the submitted batches that are
+/// still unbonding from it. Returns the total INJ cut from those batches.
+fn slash_unbonding_batches(
+    storage: &mut dyn Storage,
+    env: &Env,
+    losses: &[(String, Uint128, Uint128)],
+) -> Result<Uint128, ContractError> {
+    let current_id = CURRENT_BATCH_ID.load(storage)?;
+    // Batches are submitted in id order, so everything before the first matured
+    // batch (walking backwards) is still unbonding.
+    let unbonding: Vec<UnbondingBatch> = UNBONDING_BATCHES
+        .range(
+            storage,
+            None,
+            Some(Bound::exclusive(current_id)),
+            Order::Descending,
+        )
+        .map(|r| r.map(|(_, batch)| batch))
+        .take_while(|r| {
+            r.as_ref()
+                .map(|b| b.unlock_time.is_some_and(|t| env.block.time < t))
+                .unwrap_or(true)
+        })
+        .collect::<StdResult<_>>()?;
+
+    let mut total_cut = Uint128::zero();
+    for mut batch in unbonding {
+        let mut batch_cut = Uint128::zero();
+        for undelegation in batch.undelegations.iter_mut() {
+            if let Some((_, loss, expected)) =
+                losses.iter().find(|(v, _, _)| *v == undelegation.validator)
+            {
+                let cut = undelegation.amount.multiply_ratio(*loss, *expected);
+                undelegation.amount -= cut;
+                batch_cut += cut;
+            }
+        }
+        if batch_cut.is_zero() {
+            continue;
+        }
+        let batch_cut = batch_cut.min(batch.total_inj - batch.slashed_inj);
+        batch.slashed_inj += batch_cut;
+        UNBONDING_BATCHES.save(storage, batch.id, &batch)?;
+        total_cut += batch_cut;
+    }
+
+    Ok(total_cut)
+}
+
+/// INJ paid for `request` out of its batch, after the batch's share of any slashing.
+pub fn batch_payout(batch: &UnbondingBatch, request: &UnstakeRequest) -> Uint128 {
+    if batch.slashed_inj.is_zero() {
+        return request.inj_amount;
+    }
+    request
+        .batch_shares
+        .multiply_ratio(batch.total_inj - batch.slashed_inj, batch.total_shares)
+}
+
 /// Query contract's INJ balance.
 fn query_contract_inj_balance(
     querier: QuerierWrapper,
@@ -481,7 +1866,7 @@ fn query_contract_inj_balance(
 /// Submit a snapshot merkle root for the current epoch. Operator only.
 pub fn take_snapshot(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     merkle_root: String,
     total_weight: Uint128,
@@ -489,11 +1874,27 @@ pub fn take_snapshot(
     snapshot_uri: String,
 ) -> Result<ContractResponse, ContractError> {
     let config = CONFIG.load(deps.storage)?;
+    let mut epoch_state = EPOCH_STATE.load(deps.storage)?;
 
+    // Anyone may submit once the epoch has ended and the operator's grace period has
+    // passed. The snapshot is still checked against the supply bounds, and it must be
+    // challengeable in the distributor before draws can use it.
     if info.sender != config.operator {
-        return Err(ContractError::Unauthorized {
-            reason: "only operator can take snapshots".to_string(),
-        });
+        if env.block.time < operator_grace_end(&epoch_state, &config) {
+            return Err(ContractError::Unauthorized {
+                reason: "only operator can take snapshots during the grace period".to_string(),
+            });
+        }
+        let distributor: DistributorConfigResponse = deps
+            .querier
+            .query_wasm_smart(&config.reward_distributor, &DistributorQueryMsg::Config {})?;
+        if distributor.challenge_period_seconds == 0 {
+            return Err(ContractError::Unauthorized {
+                reason:
+                    "only operator can take snapshots while the distributor has no challenge period"
+                        .to_string(),
+            });
+        }
     }
 
     // V2-L-01 FIX: Validate merkle_root is valid hex and exactly 64 chars (32 bytes)
@@ -508,17 +1909,29 @@ pub fn take_snapshot(
         });
     }
 
-    let mut epoch_state = EPOCH_STATE.load(deps.storage)?;
-
-    if epoch_state.snapshot_finalized {
-        return Err(ContractError::SnapshotAlreadyTaken);
+    // A snapshot the distributor invalidated (fraud proof or admin) can be replaced,
+    // so the epoch's draw isn't lost to a bad root
+    let retaken = epoch_state.snapshot_finalized;
+    if retaken {
+        let snapshot: Option<DistributorSnapshotResponse> = deps.querier.query_wasm_smart(
+            &config.reward_distributor,
+            &DistributorQueryMsg::Snapshot {
+                epoch: epoch_state.current_epoch,
+            },
+        )?;
+        if !snapshot.is_some_and(|s| s.invalidated) {
+            return Err(ContractError::SnapshotAlreadyTaken);
+        }
     }
 
+    let bounds = check_snapshot_totals(deps.as_ref(), &env, &config, total_weight, num_holders)?;
+
     epoch_state.snapshot_merkle_root = Some(merkle_root.clone());
     epoch_state.snapshot_finalized = true;
     epoch_state.snapshot_total_weight = total_weight;
     epoch_state.snapshot_num_holders = num_holders;
     epoch_state.snapshot_uri = Some(snapshot_uri.clone());
+    epoch_state.snapshot_bounds = Some(bounds.clone());
     EPOCH_STATE.save(deps.storage, &epoch_state)?;
 
     // Forward snapshot to reward distributor
@@ -536,6 +1949,7 @@ pub fn take_snapshot(
     Ok(ContractResponse::new()
         .add_message(set_snapshot_msg)
         .add_attribute("action", "take_snapshot")
+        .add_attribute("sender", info.sender.to_string())
         .add_attribute("epoch", epoch_state.current_epoch.to_string())
         .add_attribute("merkle_root", merkle_root)
         .add_attribute("total_weight", total_weight.to_string())
@@ -543,20 +1957,138 @@ pub fn take_snapshot(
         .add_event(
             Event::new("chance_snapshot_taken")
                 .add_attribute("epoch", epoch_state.current_epoch.to_string())
+                .add_attribute("retaken", retaken.to_string())
                 .add_attribute("total_weight", total_weight.to_string())
                 .add_attribute("num_holders", num_holders.to_string())
-                .add_attribute("snapshot_uri", snapshot_uri),
+                .add_attribute("snapshot_uri", snapshot_uri)
+                .add_attribute("csinj_supply", bounds.csinj_supply.to_string())
+                .add_attribute("excluded_balance", bounds.excluded_balance.to_string())
+                .add_attribute("yield_only_csinj", bounds.yield_only_csinj.to_string())
+                .add_attribute("sponsor_csinj", bounds.sponsor_csinj.to_string())
+                .add_attribute("max_weight", bounds.max_weight.to_string()),
+        ))
+}
+
+/// Check submitted snapshot totals against the hub's own supply accounting.
+/// The weight can't exceed the csINJ supply minus the balances of excluded addresses,
+/// the yield-only csINJ and the sponsors' csINJ by more than `snapshot_tolerance_bps`,
+/// and every holder
+/// needs a positive weight.
+/// There is no lower bound: holders below min_epochs_regular are left out of the
+/// tree too, and their balances can't be summed here without iterating all holders.
+fn check_snapshot_totals(
+    deps: Deps,
+    env: &Env,
+    config: &Config,
+    total_weight: Uint128,
+    num_holders: u32,
+) -> Result<SnapshotBounds, ContractError> {
+    let csinj_supply = TOTAL_CSINJ_SUPPLY.load(deps.storage)?;
+    let excluded_addresses: Vec<Addr> = SNAPSHOT_EXCLUSIONS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    let mut excluded_balance = Uint128::zero();
+    // The hub's own csINJ is the yield-only holdings and sponsors' csINJ is tracked,
+    // both counted below
+    for addr in excluded_addresses
+        .iter()
+        .filter(|addr| **addr != env.contract.address && !SPONSORS.has(deps.storage, addr))
+    {
+        excluded_balance += deps
+            .querier
+            .query_balance(addr, &config.csinj_denom)?
+            .amount;
+    }
+
+    let yield_only_csinj = YIELD_ONLY_TOTALS
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .csinj;
+    let sponsor_csinj = SPONSOR_TOTALS
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .csinj;
+
+    let expected = csinj_supply
+        .saturating_sub(excluded_balance)
+        .saturating_sub(yield_only_csinj)
+        .saturating_sub(sponsor_csinj);
+    let tolerance = expected.multiply_ratio(config.snapshot_tolerance_bps as u128, 10000u128);
+    let bounds = SnapshotBounds {
+        csinj_supply,
+        excluded_addresses,
+        excluded_balance,
+        yield_only_csinj,
+        sponsor_csinj,
+        max_weight: expected + tolerance,
+    };
+
+    if total_weight > bounds.max_weight {
+        return Err(ContractError::SnapshotWeightTooHigh {
+            total_weight,
+            max_weight: bounds.max_weight,
+        });
+    }
+    if (num_holders == 0) != total_weight.is_zero() || Uint128::from(num_holders) > total_weight {
+        return Err(ContractError::InvalidSnapshotHolders {
+            num_holders,
+            total_weight,
+        });
+    }
+
+    Ok(bounds)
+}
+
+/// Add or remove snapshot exclusions. Admin only.
+pub fn update_snapshot_exclusions(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    add: Vec<String>,
+    remove: Vec<String>,
+) -> Result<ContractResponse, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {
+            reason: "only admin can update snapshot exclusions".to_string(),
+        });
+    }
+
+    for addr in &remove {
+        SNAPSHOT_EXCLUSIONS.remove(deps.storage, &deps.api.addr_validate(addr)?);
+    }
+    for addr in &add {
+        SNAPSHOT_EXCLUSIONS.save(deps.storage, &deps.api.addr_validate(addr)?, &())?;
+    }
+
+    let count = SNAPSHOT_EXCLUSIONS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .count() as u32;
+    if count > MAX_SNAPSHOT_EXCLUSIONS {
+        return Err(ContractError::TooManySnapshotExclusions {
+            max: MAX_SNAPSHOT_EXCLUSIONS,
+        });
+    }
+
+    Ok(ContractResponse::new()
+        .add_attribute("action", "update_snapshot_exclusions")
+        .add_event(
+            Event::new("chance_snapshot_exclusions_updated")
+                .add_attribute("added", add.join(","))
+                .add_attribute("removed", remove.join(","))
+                .add_attribute("count", count.to_string()),
         ))
 }
 
 /// Update contract configuration. Admin only.
+/// While a timelock is set, the bps splits, min_epochs_*, keeper fee and snapshot
+/// tolerance and grace can only be changed through a scheduled change.
 #[allow(clippy::too_many_arguments)]
 pub fn update_config(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
-    admin: Option<String>,
-    operator: Option<String>,
     protocol_fee_bps: Option<u16>,
     base_yield_bps: Option<u16>,
     regular_pool_bps: Option<u16>,
@@ -564,6 +2096,9 @@ pub fn update_config(
     min_epochs_regular: Option<u64>,
     min_epochs_big: Option<u64>,
     min_stake_amount: Option<Uint128>,
+    snapshot_tolerance_bps: Option<u16>,
+    keeper_fee_bps: Option<u16>,
+    snapshot_grace_seconds: Option<u64>,
 ) -> Result<ContractResponse, ContractError> {
     let mut config = CONFIG.load(deps.storage)?;
 
@@ -573,12 +2108,54 @@ pub fn update_config(
         });
     }
 
-    if let Some(new_admin) = admin {
-        config.admin = deps.api.addr_validate(&new_admin)?;
+    let timelocked = protocol_fee_bps.is_some()
+        || base_yield_bps.is_some()
+        || regular_pool_bps.is_some()
+        || big_pool_bps.is_some()
+        || min_epochs_regular.is_some()
+        || min_epochs_big.is_some()
+        || snapshot_tolerance_bps.is_some()
+        || keeper_fee_bps.is_some()
+        || snapshot_grace_seconds.is_some();
+    if timelocked && config.timelock_seconds > 0 {
+        return Err(ContractError::TimelockRequired);
     }
-    if let Some(new_operator) = operator {
-        config.operator = deps.api.addr_validate(&new_operator)?;
+
+    if let Some(val) = min_stake_amount {
+        config.min_stake_amount = val;
     }
+    apply_config_change(
+        &mut config,
+        protocol_fee_bps,
+        base_yield_bps,
+        regular_pool_bps,
+        big_pool_bps,
+        min_epochs_regular,
+        min_epochs_big,
+        snapshot_tolerance_bps,
+        keeper_fee_bps,
+        snapshot_grace_seconds,
+    )?;
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(ContractResponse::new().add_attribute("action", "update_config"))
+}
+
+/// Apply the timelocked part of a config update and re-check the bps invariant.
+#[allow(clippy::too_many_arguments)]
+fn apply_config_change(
+    config: &mut Config,
+    protocol_fee_bps: Option<u16>,
+    base_yield_bps: Option<u16>,
+    regular_pool_bps: Option<u16>,
+    big_pool_bps: Option<u16>,
+    min_epochs_regular: Option<u64>,
+    min_epochs_big: Option<u64>,
+    snapshot_tolerance_bps: Option<u16>,
+    keeper_fee_bps: Option<u16>,
+    snapshot_grace_seconds: Option<u64>,
+) -> Result<(), ContractError> {
     if let Some(new_fee) = protocol_fee_bps {
         if new_fee > 10000 {
             return Err(ContractError::InvalidBps {
@@ -603,8 +2180,26 @@ pub fn update_config(
     if let Some(val) = min_epochs_big {
         config.min_epochs_big = val;
     }
-    if let Some(val) = min_stake_amount {
-        config.min_stake_amount = val;
+    if let Some(val) = snapshot_tolerance_bps {
+        if val > 10000 {
+            return Err(ContractError::InvalidBps {
+                field: "snapshot_tolerance_bps".to_string(),
+                value: val,
+            });
+        }
+        config.snapshot_tolerance_bps = val;
+    }
+    if let Some(val) = keeper_fee_bps {
+        if val > 10000 {
+            return Err(ContractError::InvalidBps {
+                field: "keeper_fee_bps".to_string(),
+                value: val,
+            });
+        }
+        config.keeper_fee_bps = val;
+    }
+    if let Some(val) = snapshot_grace_seconds {
+        config.snapshot_grace_seconds = val;
     }
 
     // C-02 FIX: Validate that all BPS fields sum to exactly 10000
@@ -624,12 +2219,11 @@ pub fn update_config(
         });
     }
 
-    CONFIG.save(deps.storage, &config)?;
-
-    Ok(ContractResponse::new().add_attribute("action", "update_config"))
+    Ok(())
 }
 
-/// Update validator set. Admin only.
+/// Update validator set and target weights. Admin only, and only while no
+/// timelock is set. New validators get weight 1 unless set in `weights`.
 /// Redelegates stake from removed validators to remaining validators.
 pub fn update_validators(
     deps: DepsMut,
@@ -637,14 +2231,31 @@ pub fn update_validators(
     info: MessageInfo,
     add: Vec<String>,
     remove: Vec<String>,
+    weights: Option<Vec<ValidatorWeight>>,
 ) -> Result<ContractResponse, ContractError> {
-    let mut config = CONFIG.load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
 
     if info.sender != config.admin {
         return Err(ContractError::Unauthorized {
             reason: "only admin can update validators".to_string(),
         });
     }
+    if config.timelock_seconds > 0 {
+        return Err(ContractError::TimelockRequired);
+    }
+
+    apply_validator_change(deps, env, add, remove, weights)
+}
+
+/// Apply a validator set and weight update, redelegating away from removed validators.
+fn apply_validator_change(
+    deps: DepsMut,
+    env: Env,
+    add: Vec<String>,
+    remove: Vec<String>,
+    weights: Option<Vec<ValidatorWeight>>,
+) -> Result<ContractResponse, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
 
     // First add new validators so they can receive redelegations
     // M-04 FIX: Validate new validator addresses
@@ -652,15 +2263,17 @@ pub fn update_validators(
         validate_validator_address(v)?;
         if !config.validators.contains(v) {
             config.validators.push(v.clone());
+            config.validator_weights.push(1);
         }
     }
 
     // Collect removed validators and remove from list
     let mut removed = Vec::new();
     for v in &remove {
-        if config.validators.contains(v) {
+        if let Some(idx) = config.validators.iter().position(|existing| existing == v) {
             removed.push(v.clone());
-            config.validators.retain(|existing| existing != v);
+            config.validators.remove(idx);
+            config.validator_weights.remove(idx);
         }
     }
 
@@ -668,14 +2281,45 @@ pub fn update_validators(
         return Err(ContractError::NoValidators);
     }
 
+    // Apply target weights (validators not listed keep their current weight)
+    if let Some(weights) = &weights {
+        let mut seen = std::collections::HashSet::new();
+        for vw in weights {
+            let idx = config
+                .validators
+                .iter()
+                .position(|v| v == &vw.validator)
+                .ok_or(ContractError::ValidatorNotInSet {
+                    validator: vw.validator.clone(),
+                })?;
+            if !seen.insert(vw.validator.clone()) {
+                return Err(ContractError::InvalidValidatorWeights {
+                    reason: format!("duplicate validator: {}", vw.validator),
+                });
+            }
+            config.validator_weights[idx] = vw.weight;
+        }
+    }
+    if !config.validators.is_empty() && config.validator_weights.iter().all(|w| *w == 0) {
+        return Err(ContractError::InvalidValidatorWeights {
+            reason: "at least one validator must have a non-zero weight".to_string(),
+        });
+    }
+
     CONFIG.save(deps.storage, &config)?;
 
     let mut response = ContractResponse::new().add_attribute("action", "update_validators");
 
     // Redelegate from removed validators to remaining validators
     if !removed.is_empty() && !config.validators.is_empty() {
-        let redelegate_msgs =
-            create_redelegation_msgs(deps.querier, &env, &removed, &config.validators)?;
+        let redelegate_msgs = create_redelegation_msgs(
+            deps.querier,
+            &env,
+            &removed,
+            &config.validators,
+            &config.validator_weights,
+        )?;
+        track_staking_msgs(deps.storage, deps.querier, &env, &redelegate_msgs)?;
         for msg in redelegate_msgs {
             response = response.add_message(msg);
         }
@@ -685,12 +2329,53 @@ pub fn update_validators(
         Event::new("chance_validators_updated")
             .add_attribute("added", format!("{:?}", add))
             .add_attribute("removed", format!("{:?}", remove))
-            .add_attribute("current", format!("{:?}", config.validators)),
+            .add_attribute("current", format!("{:?}", config.validators))
+            .add_attribute("weights", format!("{:?}", config.validator_weights)),
     );
 
     Ok(response)
 }
 
+/// Send the INJ in the hub that isn't reserved for pending unstake claims or the next
+/// epoch's rewards to the treasury, and reset the reported surplus. Admin only.
+pub fn sweep_surplus(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<ContractResponse, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {
+            reason: "only admin can sweep surplus".to_string(),
+        });
+    }
+
+    let balance = query_contract_inj_balance(deps.querier, &env)?;
+    let auto_withdrawn = AUTO_WITHDRAWN_REWARDS
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    let surplus = balance
+        .saturating_sub(PENDING_UNSTAKE_TOTAL.load(deps.storage)?)
+        .saturating_sub(auto_withdrawn);
+    if surplus.is_zero() {
+        return Err(ContractError::NoSurplus);
+    }
+    UNEXPLAINED_SURPLUS.save(deps.storage, &Uint128::zero())?;
+
+    Ok(ContractResponse::new()
+        .add_message(BankMsg::Send {
+            to_address: config.treasury.to_string(),
+            amount: coins(surplus.u128(), "inj"),
+        })
+        .add_attribute("action", "sweep_surplus")
+        .add_event(
+            Event::new("chance_surplus_swept")
+                .add_attribute("amount", surplus.to_string())
+                .add_attribute("recipient", config.treasury.to_string()),
+        ))
+}
+
 /// H-05 FIX: Sync TOTAL_INJ_BACKING with actual validator delegations.
 /// Call this after slashing events to reconcile accounting.
 /// Operator only.
@@ -707,7 +2392,9 @@ pub fn sync_delegations(
         });
     }
 
-    // Query actual delegated amounts from all validators
+    // Query actual delegated amounts from all validators and reset the
+    // expected-delegation ledger to match them
+    EXPECTED_DELEGATIONS.clear(deps.storage);
     let mut total_delegated = Uint128::zero();
     for validator in &config.validators {
         let delegation = deps
@@ -715,18 +2402,30 @@ pub fn sync_delegations(
             .query_delegation(&env.contract.address, validator)?;
         if let Some(del) = delegation {
             total_delegated += del.amount.amount;
+            EXPECTED_DELEGATIONS.save(deps.storage, validator, &del.amount.amount)?;
         }
     }
 
+    // INJ owed to the open unbonding batch and sponsored INJ are delegated but don't
+    // back csINJ
+    let open_batch = load_open_batch(deps.storage)?;
+    let sponsored = SPONSOR_TOTALS
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .inj;
+    let new_backing = total_delegated
+        .saturating_sub(open_batch.total_inj - open_batch.slashed_inj)
+        .saturating_sub(sponsored);
+
     let old_backing = TOTAL_INJ_BACKING.load(deps.storage)?;
-    let difference = old_backing.saturating_sub(total_delegated);
+    let difference = old_backing.saturating_sub(new_backing);
 
     // Update backing to match actual delegations
-    TOTAL_INJ_BACKING.save(deps.storage, &total_delegated)?;
+    TOTAL_INJ_BACKING.save(deps.storage, &new_backing)?;
 
     // V2-M-02 FIX: Update EPOCH_STATE.total_staked to reflect actual delegations
     let mut epoch_state = EPOCH_STATE.load(deps.storage)?;
-    epoch_state.total_staked = total_delegated;
+    epoch_state.total_staked = new_backing;
     EPOCH_STATE.save(deps.storage, &epoch_state)?;
 
     // Recompute exchange rate
@@ -734,20 +2433,20 @@ pub fn sync_delegations(
     let new_rate = if total_supply.is_zero() {
         Decimal::one()
     } else {
-        Decimal::from_ratio(total_delegated, total_supply)
+        Decimal::from_ratio(new_backing, total_supply)
     };
     EXCHANGE_RATE.save(deps.storage, &new_rate)?;
 
     Ok(ContractResponse::new()
         .add_attribute("action", "sync_delegations")
         .add_attribute("old_backing", old_backing.to_string())
-        .add_attribute("new_backing", total_delegated.to_string())
+        .add_attribute("new_backing", new_backing.to_string())
         .add_attribute("slashed_amount", difference.to_string())
         .add_attribute("new_exchange_rate", new_rate.to_string())
         .add_event(
             Event::new("chance_delegations_synced")
                 .add_attribute("slashed_amount", difference.to_string())
-                .add_attribute("new_backing", total_delegated.to_string())
+                .add_attribute("new_backing", new_backing.to_string())
                 .add_attribute("new_rate", new_rate.to_string()),
         ))
 }
@@ -812,6 +2511,12 @@ pub fn redelegate_stake(
             amount,
         },
     });
+    track_staking_msgs(
+        deps.storage,
+        deps.querier,
+        &env,
+        std::slice::from_ref(&redelegate_msg),
+    )?;
 
     Ok(ContractResponse::new()
         .add_message(redelegate_msg)
@@ -955,6 +2660,8 @@ pub fn rebalance_stake(
         }
     }
 
+    track_staking_msgs(deps.storage, deps.querier, &env, &msgs)?;
+
     let mut response = ContractResponse::new()
         .add_attribute("action", "rebalance_stake")
         .add_attribute("total_delegated", total_delegated.to_string())
@@ -993,24 +2700,92 @@ pub fn validate_validator_address(addr: &str) -> Result<(), ContractError> {
     Ok(())
 }
 
-/// Helper: create delegation messages distributing INJ across validators (round-robin).
-fn create_delegation_msgs(
+/// Helper: query the contract's current delegation to each validator, in `validators` order.
+fn query_delegated_amounts(
+    querier: QuerierWrapper,
+    env: &Env,
     validators: &[String],
+) -> Result<Vec<Uint128>, ContractError> {
+    validators
+        .iter()
+        .map(|validator| {
+            let delegation = querier.query_delegation(&env.contract.address, validator)?;
+            Ok(delegation
+                .map(|d| d.amount.amount)
+                .unwrap_or(Uint128::zero()))
+        })
+        .collect()
+}
+
+/// Helper: split `total` across validators proportionally to their target weights.
+/// Rounding dust goes to the highest-weight validator.
+fn weighted_split(total: Uint128, weights: &[u64]) -> Vec<Uint128> {
+    let total_weight: u128 = weights.iter().map(|w| *w as u128).sum();
+    if total_weight == 0 {
+        return vec![Uint128::zero(); weights.len()];
+    }
+
+    let mut amounts: Vec<Uint128> = weights
+        .iter()
+        .map(|w| total.multiply_ratio(*w as u128, total_weight))
+        .collect();
+    let assigned: Uint128 = amounts.iter().copied().sum();
+    if let Some(idx) = (0..weights.len()).max_by_key(|i| (weights[*i], std::cmp::Reverse(*i))) {
+        amounts[idx] += total - assigned;
+    }
+    amounts
+}
+
+/// Helper: greedily add `amount` to the validators with the largest gaps, largest first.
+/// Returns the part of `amount` the gaps could not absorb.
+fn fill_largest_gaps(amounts: &mut [Uint128], amount: Uint128, gaps: &[Uint128]) -> Uint128 {
+    let mut order: Vec<usize> = (0..gaps.len()).collect();
+    order.sort_by(|a, b| gaps[*b].cmp(&gaps[*a]).then(a.cmp(b)));
+
+    let mut remaining = amount;
+    for idx in order {
+        if remaining.is_zero() {
+            break;
+        }
+        let take = std::cmp::min(gaps[idx], remaining);
+        amounts[idx] += take;
+        remaining -= take;
+    }
+    remaining
+}
+
+/// Helper: create delegation messages sending INJ to the most under-weight validators.
+/// Targets are computed from the configured weights over the post-delegation total.
+fn create_delegation_msgs(
+    querier: QuerierWrapper,
+    env: &Env,
+    config: &Config,
     total_amount: Uint128,
 ) -> Result<Vec<CosmosMsg<InjectiveMsgWrapper>>, ContractError> {
-    if validators.is_empty() {
+    if config.validators.is_empty() {
         return Err(ContractError::NoValidators);
     }
 
-    let per_validator = total_amount / Uint128::from(validators.len() as u128);
-    let remainder = total_amount - per_validator * Uint128::from(validators.len() as u128);
+    let current = query_delegated_amounts(querier, env, &config.validators)?;
+    let total_after = current.iter().copied().sum::<Uint128>() + total_amount;
+    let targets = weighted_split(total_after, &config.validator_weights);
+    let deficits: Vec<Uint128> = targets
+        .iter()
+        .zip(&current)
+        .map(|(target, cur)| target.saturating_sub(*cur))
+        .collect();
+
+    let mut amounts = vec![Uint128::zero(); config.validators.len()];
+    let remaining = fill_largest_gaps(&mut amounts, total_amount, &deficits);
+    for (i, extra) in weighted_split(remaining, &config.validator_weights)
+        .into_iter()
+        .enumerate()
+    {
+        amounts[i] += extra;
+    }
 
     let mut msgs = Vec::new();
-    for (i, validator) in validators.iter().enumerate() {
-        let mut amount = per_validator;
-        if i == 0 {
-            amount += remainder;
-        }
+    for (validator, amount) in config.validators.iter().zip(amounts) {
         if !amount.is_zero() {
             msgs.push(CosmosMsg::Staking(StakingMsg::Delegate {
                 validator: validator.clone(),
@@ -1025,45 +2800,69 @@ fn create_delegation_msgs(
     Ok(msgs)
 }
 
-/// Helper: create undelegation messages distributing across validators (round-robin).
-fn create_undelegation_msgs(
-    validators: &[String],
+/// Helper: format a per-validator split as "validator:amount,..." for events.
+fn format_undelegations(undelegations: &[BatchUndelegation]) -> String {
+    undelegations
+        .iter()
+        .map(|u| format!("{}:{}", u.validator, u.amount))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Helper: plan undelegations from real delegations, never asking a validator for more
+/// than it holds. The amount is drawn from the most over-weight validators first.
+fn plan_undelegations(
+    querier: QuerierWrapper,
+    env: &Env,
+    config: &Config,
     total_amount: Uint128,
-) -> Result<Vec<CosmosMsg<InjectiveMsgWrapper>>, ContractError> {
-    if validators.is_empty() {
+) -> Result<Vec<BatchUndelegation>, ContractError> {
+    if config.validators.is_empty() {
         return Err(ContractError::NoValidators);
     }
 
-    let per_validator = total_amount / Uint128::from(validators.len() as u128);
-    let remainder = total_amount - per_validator * Uint128::from(validators.len() as u128);
-
-    let mut msgs = Vec::new();
-    for (i, validator) in validators.iter().enumerate() {
-        let mut amount = per_validator;
-        if i == 0 {
-            amount += remainder;
-        }
-        if !amount.is_zero() {
-            msgs.push(CosmosMsg::Staking(StakingMsg::Undelegate {
-                validator: validator.clone(),
-                amount: Coin {
-                    denom: "inj".to_string(),
-                    amount,
-                },
-            }));
-        }
+    let current = query_delegated_amounts(querier, env, &config.validators)?;
+    let available: Uint128 = current.iter().copied().sum();
+    if total_amount > available {
+        return Err(ContractError::InsufficientDelegations {
+            requested: total_amount,
+            available,
+        });
     }
 
-    Ok(msgs)
+    let targets = weighted_split(available - total_amount, &config.validator_weights);
+    let surpluses: Vec<Uint128> = current
+        .iter()
+        .zip(&targets)
+        .map(|(cur, target)| cur.saturating_sub(*target))
+        .collect();
+
+    // Surpluses sum to at least `total_amount` and never exceed a validator's delegation,
+    // so validators at or below target are skipped and no undelegation can overdraw.
+    let mut amounts = vec![Uint128::zero(); config.validators.len()];
+    fill_largest_gaps(&mut amounts, total_amount, &surpluses);
+
+    Ok(config
+        .validators
+        .iter()
+        .zip(amounts)
+        .filter(|(_, amount)| !amount.is_zero())
+        .map(|(validator, amount)| BatchUndelegation {
+            validator: validator.clone(),
+            amount,
+        })
+        .collect())
 }
 
 /// Helper: create redelegation messages moving stake from removed validators to remaining ones.
-/// Queries each removed validator's delegation amount and redelegates evenly to dst validators.
+/// Queries each removed validator's delegation amount and redelegates it to dst validators
+/// according to their target weights.
 fn create_redelegation_msgs(
     querier: QuerierWrapper,
     env: &Env,
     src_validators: &[String],
     dst_validators: &[String],
+    dst_weights: &[u64],
 ) -> Result<Vec<CosmosMsg<InjectiveMsgWrapper>>, ContractError> {
     if dst_validators.is_empty() {
         return Err(ContractError::NoValidators);
@@ -1083,15 +2882,10 @@ fn create_redelegation_msgs(
             continue;
         }
 
-        // Distribute evenly across destination validators
-        let per_dst = delegated_amount / Uint128::from(dst_validators.len() as u128);
-        let remainder = delegated_amount - per_dst * Uint128::from(dst_validators.len() as u128);
+        // Distribute across destination validators by target weight
+        let amounts = weighted_split(delegated_amount, dst_weights);
 
-        for (i, dst_val) in dst_validators.iter().enumerate() {
-            let mut amount = per_dst;
-            if i == 0 {
-                amount += remainder;
-            }
+        for (dst_val, amount) in dst_validators.iter().zip(amounts) {
             if !amount.is_zero() {
                 msgs.push(CosmosMsg::Staking(StakingMsg::Redelegate {
                     src_validator: src_val.clone(),
@@ -1107,3 +2901,354 @@ fn create_redelegation_msgs(
 
     Ok(msgs)
 }
+
+/// Reject `operation` while it is paused.
+pub fn assert_not_paused(
+    storage: &dyn Storage,
+    operation: &PausableOperation,
+) -> Result<(), ContractError> {
+    let paused = PAUSED_OPERATIONS.may_load(storage)?.unwrap_or_default();
+    if paused.contains(operation) {
+        return Err(ContractError::OperationPaused {
+            operation: format!("{:?}", operation),
+        });
+    }
+    Ok(())
+}
+
+/// Set or clear the guardian. Admin only.
+pub fn update_guardian(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    guardian: Option<String>,
+) -> Result<ContractResponse, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {
+            reason: "only admin can update guardian".to_string(),
+        });
+    }
+
+    config.guardian = guardian.map(|g| deps.api.addr_validate(&g)).transpose()?;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(ContractResponse::new()
+        .add_attribute("action", "update_guardian")
+        .add_attribute(
+            "guardian",
+            config.guardian.map(|g| g.to_string()).unwrap_or_default(),
+        ))
+}
+
+/// Pause operations during an incident. Guardian or admin.
+pub fn pause(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    operations: Vec<PausableOperation>,
+) -> Result<ContractResponse, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin && Some(&info.sender) != config.guardian.as_ref() {
+        return Err(ContractError::Unauthorized {
+            reason: "only guardian or admin can pause".to_string(),
+        });
+    }
+
+    let mut paused = PAUSED_OPERATIONS
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    for operation in &operations {
+        if !paused.contains(operation) {
+            paused.push(operation.clone());
+        }
+    }
+    PAUSED_OPERATIONS.save(deps.storage, &paused)?;
+
+    Ok(ContractResponse::new()
+        .add_attribute("action", "pause")
+        .add_event(
+            Event::new("chance_operations_paused")
+                .add_attribute("operations", format!("{:?}", operations))
+                .add_attribute("paused_by", info.sender.to_string()),
+        ))
+}
+
+/// Resume paused operations. Admin only.
+pub fn unpause(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    operations: Vec<PausableOperation>,
+) -> Result<ContractResponse, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {
+            reason: "only admin can unpause".to_string(),
+        });
+    }
+
+    let mut paused = PAUSED_OPERATIONS
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    paused.retain(|op| !operations.contains(op));
+    PAUSED_OPERATIONS.save(deps.storage, &paused)?;
+
+    Ok(ContractResponse::new()
+        .add_attribute("action", "unpause")
+        .add_event(
+            Event::new("chance_operations_unpaused")
+                .add_attribute("operations", format!("{:?}", operations))
+                .add_attribute("unpaused_by", info.sender.to_string()),
+        ))
+}
+
+/// Propose handing `role` over to `new_address`. The handover only takes effect
+/// once the new address accepts it. Replaces any earlier proposal for the role.
+/// Admin only.
+pub fn propose_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    role: Role,
+    new_address: String,
+    expires_in_seconds: Option<u64>,
+) -> Result<ContractResponse, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {
+            reason: "only admin can propose a role transfer".to_string(),
+        });
+    }
+
+    let pending = PendingTransfer {
+        new_address: deps.api.addr_validate(&new_address)?,
+        expires_at: expires_in_seconds.map(|secs| env.block.time.plus_seconds(secs)),
+    };
+    match role {
+        Role::Admin => config.pending_admin = Some(pending.clone()),
+        Role::Operator => config.pending_operator = Some(pending.clone()),
+    }
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(ContractResponse::new()
+        .add_attribute("action", "propose_transfer")
+        .add_attribute("role", format!("{:?}", role))
+        .add_attribute("new_address", pending.new_address.to_string())
+        .add_attribute(
+            "expires_at",
+            pending
+                .expires_at
+                .map(|t| t.seconds())
+                .unwrap_or_default()
+                .to_string(),
+        ))
+}
+
+/// Accept a proposed role transfer. Only the proposed address can accept,
+/// and only before the proposal expires.
+pub fn accept_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    role: Role,
+) -> Result<ContractResponse, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    let pending = match role {
+        Role::Admin => config.pending_admin.take(),
+        Role::Operator => config.pending_operator.take(),
+    }
+    .ok_or(ContractError::NoPendingTransfer {
+        role: format!("{:?}", role),
+    })?;
+
+    if info.sender != pending.new_address {
+        return Err(ContractError::Unauthorized {
+            reason: "only the proposed address can accept the transfer".to_string(),
+        });
+    }
+    if pending.is_expired(env.block.time) {
+        return Err(ContractError::TransferExpired {
+            role: format!("{:?}", role),
+        });
+    }
+
+    let previous = match role {
+        Role::Admin => std::mem::replace(&mut config.admin, pending.new_address),
+        Role::Operator => std::mem::replace(&mut config.operator, pending.new_address),
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(ContractResponse::new()
+        .add_attribute("action", "accept_transfer")
+        .add_attribute("role", format!("{:?}", role))
+        .add_attribute("previous", previous.to_string())
+        .add_attribute("new_address", info.sender.to_string()))
+}
+
+/// Withdraw a pending role transfer. Admin only.
+pub fn cancel_proposal(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    role: Role,
+) -> Result<ContractResponse, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {
+            reason: "only admin can cancel a role transfer".to_string(),
+        });
+    }
+
+    let cancelled = match role {
+        Role::Admin => config.pending_admin.take(),
+        Role::Operator => config.pending_operator.take(),
+    };
+    if cancelled.is_none() {
+        return Err(ContractError::NoPendingTransfer {
+            role: format!("{:?}", role),
+        });
+    }
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(ContractResponse::new()
+        .add_attribute("action", "cancel_proposal")
+        .add_attribute("role", format!("{:?}", role)))
+}
+
+/// Queue a parameter change. It becomes executable once the timelock has passed.
+/// Admin only.
+pub fn schedule_change(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    change: ParamChange,
+) -> Result<ContractResponse, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {
+            reason: "only admin can schedule changes".to_string(),
+        });
+    }
+
+    let id = NEXT_CHANGE_ID.may_load(deps.storage)?.unwrap_or(0);
+    let pending = PendingChange {
+        id,
+        change,
+        scheduled_at: env.block.time,
+        executable_at: env.block.time.plus_seconds(config.timelock_seconds),
+    };
+    PENDING_CHANGES.save(deps.storage, id, &pending)?;
+    NEXT_CHANGE_ID.save(deps.storage, &(id + 1))?;
+
+    Ok(ContractResponse::new()
+        .add_attribute("action", "schedule_change")
+        .add_attribute("change_id", id.to_string())
+        .add_event(
+            Event::new("chance_change_scheduled")
+                .add_attribute("change_id", id.to_string())
+                .add_attribute("change", to_json_string(&pending.change)?)
+                .add_attribute("executable_at", pending.executable_at.seconds().to_string()),
+        ))
+}
+
+/// Apply a queued change once its timelock has passed. Anyone can call.
+pub fn execute_change(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    id: u64,
+) -> Result<ContractResponse, ContractError> {
+    let pending = PENDING_CHANGES
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::ChangeNotFound { id })?;
+
+    if env.block.time < pending.executable_at {
+        return Err(ContractError::ChangeNotReady {
+            id,
+            executable_at: pending.executable_at.seconds(),
+        });
+    }
+    PENDING_CHANGES.remove(deps.storage, id);
+
+    let event = Event::new("chance_change_executed")
+        .add_attribute("change_id", id.to_string())
+        .add_attribute("change", to_json_string(&pending.change)?);
+
+    let response = match pending.change {
+        ParamChange::UpdateConfig {
+            protocol_fee_bps,
+            base_yield_bps,
+            regular_pool_bps,
+            big_pool_bps,
+            min_epochs_regular,
+            min_epochs_big,
+            snapshot_tolerance_bps,
+            keeper_fee_bps,
+            snapshot_grace_seconds,
+        } => {
+            let mut config = CONFIG.load(deps.storage)?;
+            apply_config_change(
+                &mut config,
+                protocol_fee_bps,
+                base_yield_bps,
+                regular_pool_bps,
+                big_pool_bps,
+                min_epochs_regular,
+                min_epochs_big,
+                snapshot_tolerance_bps,
+                keeper_fee_bps,
+                snapshot_grace_seconds,
+            )?;
+            CONFIG.save(deps.storage, &config)?;
+            ContractResponse::new()
+        }
+        ParamChange::UpdateValidators {
+            add,
+            remove,
+            weights,
+        } => apply_validator_change(deps, env, add, remove, weights)?,
+        ParamChange::SetTimelock { seconds } => {
+            let mut config = CONFIG.load(deps.storage)?;
+            config.timelock_seconds = seconds;
+            CONFIG.save(deps.storage, &config)?;
+            ContractResponse::new()
+        }
+    };
+
+    Ok(response
+        .add_attribute("action", "execute_change")
+        .add_attribute("change_id", id.to_string())
+        .add_event(event))
+}
+
+/// Drop a queued change. Admin only.
+pub fn cancel_change(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<ContractResponse, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {
+            reason: "only admin can cancel changes".to_string(),
+        });
+    }
+    if !PENDING_CHANGES.has(deps.storage, id) {
+        return Err(ContractError::ChangeNotFound { id });
+    }
+    PENDING_CHANGES.remove(deps.storage, id);
+
+    Ok(ContractResponse::new()
+        .add_attribute("action", "cancel_change")
+        .add_attribute("change_id", id.to_string()))
+}
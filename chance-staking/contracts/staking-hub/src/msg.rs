@@ -1,12 +1,31 @@
+use chance_staking_common::types::Role;
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Decimal, Uint128};
+use cosmwasm_std::{Addr, Coin, Decimal, Timestamp, Uint128};
 
-use crate::state::{Config, EpochState, UnstakeRequest};
+use crate::state::{
+    Config, DonationTarget, DonationTotals, EpochRecord, EpochState, PausableOperation,
+    PendingChange, UnbondingBatch, UnstakeRequest, YieldOnlyTotals,
+};
 
 // M-03 FIX: Add MigrateMsg for contract upgradability
 #[cw_serde]
 pub struct MigrateMsg {}
 
+/// Token Factory before-send hook calls for the csINJ denom.
+#[cw_serde]
+pub enum SudoMsg {
+    BlockBeforeSend {
+        from: String,
+        to: String,
+        amount: Coin,
+    },
+    TrackBeforeSend {
+        from: String,
+        to: String,
+        amount: Coin,
+    },
+}
+
 #[cw_serde]
 pub struct InstantiateMsg {
     pub operator: String,
@@ -27,33 +46,94 @@ pub struct InstantiateMsg {
     pub min_epochs_big: u64,
     /// Minimum INJ amount required per stake transaction (0 = no minimum)
     pub min_stake_amount: Uint128,
+    /// Delay before scheduled parameter changes can be executed
+    /// (None = 0, economic parameters can be changed directly)
+    pub timelock_seconds: Option<u64>,
+    /// How far snapshot totals may exceed the non-excluded csINJ supply
+    /// (default 100 = 1%)
+    pub snapshot_tolerance_bps: Option<u16>,
+    /// Share of the treasury fee paid to keepers that advance the epoch
+    /// (default 1000 = 10%)
+    pub keeper_fee_bps: Option<u16>,
+    /// How long after an epoch starts only the operator may take its snapshot
+    /// (default 3600)
+    pub snapshot_grace_seconds: Option<u64>,
 }
 
 #[cw_serde]
 pub enum ExecuteMsg {
     /// Stake INJ to receive csINJ. Send INJ in info.funds.
-    Stake {},
-    /// Unstake csINJ to begin unbonding. Send csINJ in info.funds.
-    Unstake {},
-    /// Claim unlocked unstake requests.
-    ClaimUnstaked { request_ids: Vec<u64> },
+    /// The csINJ is minted to `recipient` (default: sender).
+    Stake {
+        recipient: Option<String>,
+        /// Reject the stake if it would mint less csINJ than this
+        min_csinj_out: Option<Uint128>,
+        /// Reject the stake if executed after this block time (unix seconds)
+        deadline: Option<u64>,
+    },
+    /// Unstake csINJ. Send csINJ in info.funds. The request joins the open
+    /// unbonding batch, which is undelegated during DistributeRewards.
+    /// The request is owned by `recipient` (default: sender).
+    Unstake {
+        recipient: Option<String>,
+        /// Reject the unstake if it would owe less INJ than this
+        min_inj_out: Option<Uint128>,
+        /// Reject the unstake if executed after this block time (unix seconds)
+        deadline: Option<u64>,
+    },
+    /// Claim the sender's unlocked unstake requests. The INJ is sent to
+    /// `recipient` (default: sender).
+    ClaimUnstaked {
+        request_ids: Vec<u64>,
+        recipient: Option<String>,
+    },
+    /// Move csINJ into yield-only mode. Send csINJ in info.funds. The hub holds it
+    /// outside the draws and, each epoch, restakes its pro-rata share of the prize
+    /// pool allocation into it. Shares are credited to `recipient` (default: sender).
+    EnterYieldOnly { recipient: Option<String> },
+    /// Redeem yield-only shares (default: all of the sender's) for csINJ, sent to
+    /// `recipient` (default: sender)
+    ExitYieldOnly {
+        shares: Option<Uint128>,
+        recipient: Option<String>,
+    },
+    /// Sponsor the prize pools. Send INJ in info.funds. The INJ is delegated like
+    /// staked INJ but mints no csINJ: its rewards all go to the prize pools, and the
+    /// sponsor can't win draws while sponsoring.
+    Sponsor {},
+    /// Donate INJ to a prize pool or the base yield. Send INJ in info.funds. The
+    /// whole amount goes to the target, with no treasury fee, and is counted
+    /// separately from staking rewards.
+    Donate { target: DonationTarget },
+    /// Withdraw the sender's whole sponsorship. The INJ joins the open unbonding
+    /// batch as an unstake request, claimable with ClaimUnstaked.
+    WithdrawSponsorship {},
     /// Step 1: Withdraw staking rewards from all validators into the contract.
     /// Operator only. Call DistributeRewards after this tx confirms.
     ClaimRewards {},
-    /// Step 2: Distribute claimed rewards (splits to pools, yield, treasury)
-    /// and advance to next epoch. Operator only.
+    /// Step 2: Distribute claimed rewards (splits to pools, yield, treasury),
+    /// submit the open unbonding batch and advance to next epoch. Operator only.
     DistributeRewards {},
-    /// Submit a snapshot merkle root for the current epoch. Operator only.
+    /// ClaimRewards and DistributeRewards in one transaction. Only the INJ the
+    /// reward withdrawals add to the hub's balance is distributed, so direct
+    /// transfers and unbonded funds are never counted as rewards. Anyone can call
+    /// once the epoch has ended; callers other than the operator are paid
+    /// keeper_fee_bps of the treasury fee once snapshot_grace_seconds have passed
+    /// since the epoch ended.
+    AdvanceEpoch {},
+    /// Submit a snapshot merkle root for the current epoch. Operator only until
+    /// snapshot_grace_seconds after the epoch ends, then anyone. A snapshot the
+    /// distributor invalidated can be taken again.
     TakeSnapshot {
         merkle_root: String,
         total_weight: Uint128,
         num_holders: u32,
         snapshot_uri: String,
     },
-    /// Update contract configuration. Admin only.
+    /// Update contract configuration. Admin only. While a timelock is set,
+    /// everything but min_stake_amount must go through ScheduleChange instead.
+    /// The operator only changes through ProposeOperator and AcceptOperator.
     UpdateConfig {
-        admin: Option<String>,
-        operator: Option<String>,
         protocol_fee_bps: Option<u16>,
         base_yield_bps: Option<u16>,
         regular_pool_bps: Option<u16>,
@@ -61,15 +141,33 @@ pub enum ExecuteMsg {
         min_epochs_regular: Option<u64>,
         min_epochs_big: Option<u64>,
         min_stake_amount: Option<Uint128>,
+        snapshot_tolerance_bps: Option<u16>,
+        keeper_fee_bps: Option<u16>,
+        snapshot_grace_seconds: Option<u64>,
     },
-    /// Update validator set. Admin only.
+    /// Add or remove addresses whose csINJ is left out of draw snapshots and that
+    /// can't win draws (treasury, DEX pools, lending markets, ...). Admin only.
+    UpdateSnapshotExclusions {
+        add: Vec<String>,
+        remove: Vec<String>,
+    },
+    /// Update validator set and target weights. Admin only, and only while no
+    /// timelock is set (use ScheduleChange otherwise).
+    /// Added validators default to weight 1. Weights are relative and
+    /// steer where stake, base yield and unstakes are (un)delegated.
     UpdateValidators {
         add: Vec<String>,
         remove: Vec<String>,
+        weights: Option<Vec<ValidatorWeight>>,
     },
-    /// Sync TOTAL_INJ_BACKING with actual validator delegations.
-    /// Call after slashing events. Operator only.
+    /// Sync TOTAL_INJ_BACKING with actual validator delegations and reset the
+    /// expected-delegation ledger. Slashing is also booked automatically on
+    /// Stake, Unstake and DistributeRewards. Operator only.
     SyncDelegations {},
+    /// Send the INJ in the hub beyond pending unstake claims and auto-withdrawn
+    /// rewards (the unexplained surplus AdvanceEpoch reports) to the treasury.
+    /// Admin only.
+    SweepSurplus {},
     /// Redelegate a specific amount of INJ from one validator to another.
     /// Both validators must be in the active validator set. Operator only.
     RedelegateStake {
@@ -82,6 +180,83 @@ pub enum ExecuteMsg {
     RebalanceStake {
         validator_weights: Vec<ValidatorWeight>,
     },
+    /// Propose a new admin. Takes effect once the new admin sends AcceptAdmin.
+    /// Admin only.
+    ProposeAdmin {
+        new_admin: String,
+        /// Seconds after which the proposal can no longer be accepted
+        expires_in_seconds: Option<u64>,
+    },
+    /// Accept a pending admin proposal. Proposed admin only.
+    AcceptAdmin {},
+    /// Propose a new operator. Takes effect once the new operator sends
+    /// AcceptOperator. Admin only.
+    ProposeOperator {
+        new_operator: String,
+        /// Seconds after which the proposal can no longer be accepted
+        expires_in_seconds: Option<u64>,
+    },
+    /// Accept a pending operator proposal. Proposed operator only.
+    AcceptOperator {},
+    /// Withdraw a pending admin or operator proposal. Admin only.
+    CancelProposal { role: Role },
+    /// Queue a parameter change behind the timelock. Admin only.
+    ScheduleChange { change: ParamChange },
+    /// Apply a queued change once its timelock has passed. Anyone can call.
+    ExecuteChange { id: u64 },
+    /// Drop a queued change. Admin only.
+    CancelChange { id: u64 },
+    /// Set or clear the guardian. Admin only.
+    UpdateGuardian { guardian: Option<String> },
+    /// Pause the given operations. Guardian or admin.
+    Pause { operations: Vec<PausableOperation> },
+    /// Resume the given operations. Admin only.
+    Unpause { operations: Vec<PausableOperation> },
+}
+
+impl ExecuteMsg {
+    /// The pausable operation this message belongs to, if any.
+    pub fn pausable_operation(&self) -> Option<PausableOperation> {
+        match self {
+            ExecuteMsg::Stake { .. }
+            | ExecuteMsg::Sponsor {}
+            | ExecuteMsg::Donate { .. }
+            | ExecuteMsg::EnterYieldOnly { .. } => Some(PausableOperation::Stake),
+            ExecuteMsg::Unstake { .. }
+            | ExecuteMsg::WithdrawSponsorship {}
+            | ExecuteMsg::ExitYieldOnly { .. } => Some(PausableOperation::Unstake),
+            ExecuteMsg::ClaimRewards {}
+            | ExecuteMsg::DistributeRewards {}
+            | ExecuteMsg::AdvanceEpoch {} => Some(PausableOperation::EpochAdvance),
+            ExecuteMsg::TakeSnapshot { .. } => Some(PausableOperation::TakeSnapshot),
+            _ => None,
+        }
+    }
+}
+
+/// Economically relevant parameter changes, applied through the timelock.
+#[cw_serde]
+pub enum ParamChange {
+    /// Same semantics as the matching UpdateConfig fields
+    UpdateConfig {
+        protocol_fee_bps: Option<u16>,
+        base_yield_bps: Option<u16>,
+        regular_pool_bps: Option<u16>,
+        big_pool_bps: Option<u16>,
+        min_epochs_regular: Option<u64>,
+        min_epochs_big: Option<u64>,
+        snapshot_tolerance_bps: Option<u16>,
+        keeper_fee_bps: Option<u16>,
+        snapshot_grace_seconds: Option<u64>,
+    },
+    /// Same semantics as UpdateValidators
+    UpdateValidators {
+        add: Vec<String>,
+        remove: Vec<String>,
+        weights: Option<Vec<ValidatorWeight>>,
+    },
+    /// Change the timelock delay itself
+    SetTimelock { seconds: u64 },
 }
 
 /// Message sent to reward distributor to fund pools.
@@ -97,6 +272,25 @@ pub enum DistributorExecuteMsg {
     },
 }
 
+/// Query sent to the reward distributor.
+#[cw_serde]
+pub enum DistributorQueryMsg {
+    Config {},
+    Snapshot { epoch: u64 },
+}
+
+/// Partial reward distributor config — only the fields we need.
+#[cw_serde]
+pub struct DistributorConfigResponse {
+    pub challenge_period_seconds: u64,
+}
+
+/// Partial reward distributor snapshot — only the fields we need.
+#[cw_serde]
+pub struct DistributorSnapshotResponse {
+    pub invalidated: bool,
+}
+
 #[cw_serde]
 #[derive(QueryResponses)]
 pub enum QueryMsg {
@@ -104,6 +298,19 @@ pub enum QueryMsg {
     Config {},
     #[returns(EpochState)]
     EpochState {},
+    /// Record of a finished epoch
+    #[returns(EpochRecord)]
+    EpochRecord { epoch: u64 },
+    /// Records of finished epochs, oldest first
+    #[returns(Vec<EpochRecord>)]
+    EpochHistory {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Base-yield APR and APY over the last `lookback_epochs` finished epochs,
+    /// derived from the recorded exchange rates
+    #[returns(YieldStatsResponse)]
+    YieldStats { lookback_epochs: u32 },
     #[returns(ExchangeRateResponse)]
     ExchangeRate {},
     #[returns(Vec<UnstakeRequestEntry>)]
@@ -116,6 +323,49 @@ pub enum QueryMsg {
     StakerInfo { address: String },
     #[returns(ValidatorDelegationsResponse)]
     ValidatorDelegations {},
+    #[returns(UnbondingBatch)]
+    UnbondingBatch { id: u64 },
+    #[returns(Vec<UnbondingBatch>)]
+    UnbondingBatches {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(PauseStateResponse)]
+    PauseState {},
+    #[returns(Vec<PendingChange>)]
+    PendingChanges {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Time-weighted average csINJ balance of `address` and of all holders between
+    /// two unix timestamps. `end` is capped at the current block time.
+    #[returns(TwabResponse)]
+    TwabBetween {
+        address: String,
+        start: u64,
+        end: u64,
+    },
+    /// Yield-only shares of `address` and the csINJ they redeem for
+    #[returns(YieldOnlyPositionResponse)]
+    YieldOnlyPosition { address: String },
+    /// Total yield-only shares and the csINJ behind them
+    #[returns(YieldOnlyTotals)]
+    YieldOnlyTotals {},
+    /// INJ donated so far, per target
+    #[returns(DonationTotals)]
+    DonationTotals {},
+    /// Sponsors and their current sponsored INJ
+    #[returns(SponsorsResponse)]
+    Sponsors {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Addresses on the snapshot exclusion list
+    #[returns(Vec<Addr>)]
+    SnapshotExclusions {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 }
 
 #[cw_serde]
@@ -125,17 +375,87 @@ pub struct ExchangeRateResponse {
     pub total_csinj_supply: Uint128,
 }
 
+#[cw_serde]
+pub struct YieldStatsResponse {
+    /// Finished epochs the stats cover. Fewer than requested if the history is shorter.
+    pub num_epochs: u32,
+    pub from_epoch: Option<u64>,
+    pub to_epoch: Option<u64>,
+    /// Exchange rate when `from_epoch` started
+    pub start_rate: Decimal,
+    /// Exchange rate when `to_epoch` ended
+    pub end_rate: Decimal,
+    pub elapsed_seconds: u64,
+    /// Annualized exchange-rate growth, without compounding
+    pub base_apr: Decimal,
+    /// `base_apr` compounded once per epoch
+    pub base_apy: Decimal,
+}
+
 #[cw_serde]
 pub struct UnstakeRequestEntry {
     pub id: u64,
     pub request: UnstakeRequest,
+    /// INJ claimable for the request after its batch's share of slashing.
+    /// Equals `request.inj_amount` unless the batch was slashed.
+    pub adjusted_inj_amount: Uint128,
+    /// Unlock time of the request's batch, or None while the batch is still open.
+    pub unlock_time: Option<Timestamp>,
+}
+
+#[cw_serde]
+pub struct PauseStateResponse {
+    pub guardian: Option<Addr>,
+    pub paused: Vec<PausableOperation>,
 }
 
 #[cw_serde]
 pub struct StakerInfoResponse {
     pub address: String,
-    /// The epoch of this user's most recent stake, or None if they have never staked.
+    /// Balance-weighted average entry epoch of this user's csINJ, or None if they
+    /// have never held csINJ.
     pub stake_epoch: Option<u64>,
+    /// On the snapshot exclusion list, a sponsor, or the hub itself (which holds the
+    /// yield-only csINJ): left out of snapshots and can't win draws
+    pub excluded: bool,
+}
+
+#[cw_serde]
+pub struct SponsorInfo {
+    pub address: Addr,
+    pub shares: Uint128,
+    /// INJ the sponsorship withdraws for right now
+    pub inj_amount: Uint128,
+}
+
+#[cw_serde]
+pub struct SponsorsResponse {
+    pub sponsors: Vec<SponsorInfo>,
+    pub total_shares: Uint128,
+    pub total_sponsored: Uint128,
+}
+
+#[cw_serde]
+pub struct YieldOnlyPositionResponse {
+    pub address: String,
+    pub shares: Uint128,
+    /// csINJ the shares redeem for right now
+    pub csinj_amount: Uint128,
+    /// INJ value of `csinj_amount` at the current exchange rate
+    pub inj_value: Uint128,
+}
+
+#[cw_serde]
+pub struct TwabResponse {
+    pub address: String,
+    pub start: u64,
+    pub end: u64,
+    /// Average csINJ balance of `address` over [start, end)
+    pub average_balance: Uint128,
+    /// Average total tracked csINJ over [start, end)
+    pub total_average_balance: Uint128,
+    /// Lowest csINJ balance `address` held at any point in [start, end)
+    pub min_balance: Uint128,
 }
 
 #[cw_serde]
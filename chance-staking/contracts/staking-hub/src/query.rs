@@ -1,15 +1,24 @@
-use cosmwasm_std::{to_json_binary, Binary, Deps, Env, Order, StdResult, Uint128};
+use cosmwasm_std::{
+    to_json_binary, Binary, Decimal, Deps, Env, Order, StdError, StdResult, Uint128,
+};
 use cw_storage_plus::Bound;
 
+use crate::execute::batch_payout;
 use crate::msg::{
-    ExchangeRateResponse, StakerInfoResponse, UnstakeRequestEntry, ValidatorDelegation,
-    ValidatorDelegationsResponse,
+    ExchangeRateResponse, PauseStateResponse, SponsorInfo, SponsorsResponse, StakerInfoResponse,
+    TwabResponse, UnstakeRequestEntry, ValidatorDelegation, ValidatorDelegationsResponse,
+    YieldOnlyPositionResponse, YieldStatsResponse,
 };
 use crate::state::{
-    CONFIG, EPOCH_STATE, EXCHANGE_RATE, TOTAL_CSINJ_SUPPLY, TOTAL_INJ_BACKING, UNSTAKE_REQUESTS,
-    USER_STAKE_EPOCH,
+    TwabCheckpoint, CONFIG, DONATION_TOTALS, EPOCH_HISTORY, EPOCH_STATE, EXCHANGE_RATE,
+    PAUSED_OPERATIONS, PENDING_CHANGES, SNAPSHOT_EXCLUSIONS, SPONSORS, SPONSOR_SHARES,
+    SPONSOR_TOTALS, TOTAL_CSINJ_SUPPLY, TOTAL_INJ_BACKING, TOTAL_TWAB_CHECKPOINTS,
+    TWAB_CHECKPOINTS, UNBONDING_BATCHES, UNSTAKE_REQUESTS, USER_STAKE_EPOCH, YIELD_ONLY_SHARES,
+    YIELD_ONLY_TOTALS,
 };
 
+const SECONDS_PER_YEAR: u64 = 365 * 24 * 60 * 60;
+
 pub fn query_config(deps: Deps) -> StdResult<Binary> {
     let config = CONFIG.load(deps.storage)?;
     to_json_binary(&config)
@@ -20,6 +29,112 @@ pub fn query_epoch_state(deps: Deps) -> StdResult<Binary> {
     to_json_binary(&state)
 }
 
+pub fn query_epoch_record(deps: Deps, epoch: u64) -> StdResult<Binary> {
+    let record = EPOCH_HISTORY.load(deps.storage, epoch)?;
+    to_json_binary(&record)
+}
+
+pub fn query_epoch_history(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(50).min(100) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let records: Vec<_> = EPOCH_HISTORY
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .filter_map(|r| r.ok())
+        .map(|(_, record)| record)
+        .collect();
+
+    to_json_binary(&records)
+}
+
+pub fn query_yield_stats(deps: Deps, lookback_epochs: u32) -> StdResult<Binary> {
+    let last = EPOCH_HISTORY
+        .range(deps.storage, None, None, Order::Descending)
+        .next()
+        .transpose()?
+        .map(|(_, record)| record);
+    let first = match &last {
+        Some(last) if lookback_epochs > 0 => {
+            let from = (last.epoch + 1).saturating_sub(lookback_epochs as u64);
+            EPOCH_HISTORY
+                .range(
+                    deps.storage,
+                    Some(Bound::inclusive(from)),
+                    None,
+                    Order::Ascending,
+                )
+                .next()
+                .transpose()?
+                .map(|(_, record)| record)
+        }
+        _ => None,
+    };
+    let (Some(first), Some(last)) = (first, last) else {
+        let rate = EXCHANGE_RATE.load(deps.storage)?;
+        return to_json_binary(&YieldStatsResponse {
+            num_epochs: 0,
+            from_epoch: None,
+            to_epoch: None,
+            start_rate: rate,
+            end_rate: rate,
+            elapsed_seconds: 0,
+            base_apr: Decimal::zero(),
+            base_apy: Decimal::zero(),
+        });
+    };
+
+    // The rate the first epoch started at is the one the previous epoch ended at.
+    // Without a record for it, back the base yield and yield-only restake out of the
+    // first epoch's totals.
+    let start_rate = match EPOCH_HISTORY.may_load(deps.storage, first.epoch.wrapping_sub(1))? {
+        Some(previous) => previous.exchange_rate,
+        None if first.total_csinj_supply == first.yield_only_minted => Decimal::one(),
+        None => Decimal::from_ratio(
+            first.total_inj_backing - first.base_yield - first.yield_only_amount,
+            first.total_csinj_supply - first.yield_only_minted,
+        ),
+    };
+    let end_rate = last.exchange_rate;
+    let num_epochs = (last.epoch - first.epoch + 1) as u32;
+    let elapsed_seconds = last.end_time.seconds() - first.start_time.seconds();
+
+    let growth = if start_rate.is_zero() {
+        Decimal::zero()
+    } else {
+        (end_rate / start_rate).saturating_sub(Decimal::one())
+    };
+    let (base_apr, base_apy) = if elapsed_seconds == 0 {
+        (Decimal::zero(), Decimal::zero())
+    } else {
+        let apr = growth * Decimal::from_ratio(SECONDS_PER_YEAR, elapsed_seconds);
+        let epochs_per_year = (SECONDS_PER_YEAR * num_epochs as u64 / elapsed_seconds) as u32;
+        let apy = if epochs_per_year == 0 {
+            apr
+        } else {
+            (Decimal::one() + apr / Decimal::from_ratio(epochs_per_year, 1u32))
+                .saturating_pow(epochs_per_year)
+                - Decimal::one()
+        };
+        (apr, apy)
+    };
+
+    to_json_binary(&YieldStatsResponse {
+        num_epochs,
+        from_epoch: Some(first.epoch),
+        to_epoch: Some(last.epoch),
+        start_rate,
+        end_rate,
+        elapsed_seconds,
+        base_apr,
+        base_apy,
+    })
+}
+
 pub fn query_exchange_rate(deps: Deps) -> StdResult<Binary> {
     let rate = EXCHANGE_RATE.load(deps.storage)?;
     let total_inj_backing = TOTAL_INJ_BACKING.load(deps.storage)?;
@@ -47,21 +162,201 @@ pub fn query_unstake_requests(
         .range(deps.storage, start, None, Order::Ascending)
         .take(limit)
         .filter_map(|r| r.ok())
-        .map(|(id, request)| UnstakeRequestEntry { id, request })
-        .collect();
+        .map(|(id, request)| {
+            let batch = UNBONDING_BATCHES.may_load(deps.storage, request.batch_id)?;
+            let unlock_time = batch.as_ref().and_then(|b| b.unlock_time);
+            let adjusted_inj_amount = batch
+                .as_ref()
+                .map(|b| batch_payout(b, &request))
+                .unwrap_or(request.inj_amount);
+            Ok(UnstakeRequestEntry {
+                id,
+                request,
+                adjusted_inj_amount,
+                unlock_time,
+            })
+        })
+        .collect::<StdResult<_>>()?;
 
     to_json_binary(&entries)
 }
 
-pub fn query_staker_info(deps: Deps, address: String) -> StdResult<Binary> {
+pub fn query_unbonding_batch(deps: Deps, id: u64) -> StdResult<Binary> {
+    let batch = UNBONDING_BATCHES.load(deps.storage, id)?;
+    to_json_binary(&batch)
+}
+
+pub fn query_unbonding_batches(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(50).min(100) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let batches: Vec<_> = UNBONDING_BATCHES
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .filter_map(|r| r.ok())
+        .map(|(_, batch)| batch)
+        .collect();
+
+    to_json_binary(&batches)
+}
+
+pub fn query_twab_between(
+    deps: Deps,
+    env: Env,
+    address: String,
+    start: u64,
+    end: u64,
+) -> StdResult<Binary> {
+    let addr = deps.api.addr_validate(&address)?;
+    let end = end.min(env.block.time.seconds());
+    if start >= end {
+        return Err(StdError::generic_err("start must be before end"));
+    }
+
+    let user_last = |t: u64| {
+        TWAB_CHECKPOINTS
+            .prefix(&addr)
+            .range(
+                deps.storage,
+                None,
+                Some(Bound::inclusive(t)),
+                Order::Descending,
+            )
+            .next()
+            .transpose()
+            .map(|c| c.map(|(_, checkpoint)| checkpoint))
+    };
+    let total_last = |t: u64| {
+        TOTAL_TWAB_CHECKPOINTS
+            .range(
+                deps.storage,
+                None,
+                Some(Bound::inclusive(t)),
+                Order::Descending,
+            )
+            .next()
+            .transpose()
+            .map(|c| c.map(|(_, checkpoint)| checkpoint))
+    };
+
+    let duration = Uint128::from(end - start);
+    let user_cumulative =
+        cumulative_at(user_last(end)?, end) - cumulative_at(user_last(start)?, start);
+    let total_cumulative =
+        cumulative_at(total_last(end)?, end) - cumulative_at(total_last(start)?, start);
+
+    let opening_balance = user_last(start)?.map(|c| c.balance).unwrap_or_default();
+    let min_balance = TWAB_CHECKPOINTS
+        .prefix(&addr)
+        .range(
+            deps.storage,
+            Some(Bound::exclusive(start)),
+            Some(Bound::exclusive(end)),
+            Order::Ascending,
+        )
+        .filter_map(|r| r.ok())
+        .map(|(_, checkpoint)| checkpoint.balance)
+        .fold(opening_balance, Uint128::min);
+
+    to_json_binary(&TwabResponse {
+        address,
+        start,
+        end,
+        average_balance: user_cumulative / duration,
+        total_average_balance: total_cumulative / duration,
+        min_balance,
+    })
+}
+
+/// Cumulative csINJ-seconds at `t`, given the last checkpoint at or before `t`.
+fn cumulative_at(checkpoint: Option<TwabCheckpoint>, t: u64) -> Uint128 {
+    checkpoint
+        .map(|c| c.cumulative + c.balance * Uint128::from(t - c.timestamp))
+        .unwrap_or_default()
+}
+
+pub fn query_staker_info(deps: Deps, env: Env, address: String) -> StdResult<Binary> {
     let addr = deps.api.addr_validate(&address)?;
     let stake_epoch = USER_STAKE_EPOCH.may_load(deps.storage, &addr)?;
+    let excluded = SNAPSHOT_EXCLUSIONS.has(deps.storage, &addr)
+        || SPONSORS.has(deps.storage, &addr)
+        || addr == env.contract.address;
     to_json_binary(&StakerInfoResponse {
         address,
         stake_epoch,
+        excluded,
+    })
+}
+
+pub fn query_yield_only_position(deps: Deps, address: String) -> StdResult<Binary> {
+    let addr = deps.api.addr_validate(&address)?;
+    let shares = YIELD_ONLY_SHARES
+        .may_load(deps.storage, &addr)?
+        .unwrap_or_default();
+    let totals = YIELD_ONLY_TOTALS
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    let csinj_amount = if totals.shares.is_zero() {
+        Uint128::zero()
+    } else {
+        shares.multiply_ratio(totals.csinj, totals.shares)
+    };
+    let rate = EXCHANGE_RATE.load(deps.storage)?;
+
+    to_json_binary(&YieldOnlyPositionResponse {
+        address,
+        shares,
+        csinj_amount,
+        inj_value: csinj_amount.mul_floor(rate),
+    })
+}
+
+pub fn query_sponsors(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(50).min(100) as usize;
+    let start_addr = start_after
+        .map(|a| deps.api.addr_validate(&a))
+        .transpose()?;
+    let start = start_addr.as_ref().map(Bound::exclusive);
+    let totals = SPONSOR_TOTALS.may_load(deps.storage)?.unwrap_or_default();
+
+    let sponsors: Vec<_> = SPONSOR_SHARES
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .filter_map(|r| r.ok())
+        .map(|(address, shares)| SponsorInfo {
+            address,
+            shares,
+            inj_amount: shares.multiply_ratio(totals.inj, totals.shares),
+        })
+        .collect();
+
+    to_json_binary(&SponsorsResponse {
+        sponsors,
+        total_shares: totals.shares,
+        total_sponsored: totals.inj,
     })
 }
 
+pub fn query_donation_totals(deps: Deps) -> StdResult<Binary> {
+    let totals = DONATION_TOTALS.may_load(deps.storage)?.unwrap_or_default();
+    to_json_binary(&totals)
+}
+
+pub fn query_yield_only_totals(deps: Deps) -> StdResult<Binary> {
+    let totals = YIELD_ONLY_TOTALS
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    to_json_binary(&totals)
+}
+
 pub fn query_validator_delegations(deps: Deps, env: Env) -> StdResult<Binary> {
     let config = CONFIG.load(deps.storage)?;
     let mut delegations = Vec::new();
@@ -86,3 +381,52 @@ pub fn query_validator_delegations(deps: Deps, env: Env) -> StdResult<Binary> {
         total_delegated,
     })
 }
+
+pub fn query_pause_state(deps: Deps) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let paused = PAUSED_OPERATIONS
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    to_json_binary(&PauseStateResponse {
+        guardian: config.guardian,
+        paused,
+    })
+}
+
+pub fn query_pending_changes(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(50).min(100) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let changes: Vec<_> = PENDING_CHANGES
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .filter_map(|r| r.ok())
+        .map(|(_, change)| change)
+        .collect();
+
+    to_json_binary(&changes)
+}
+
+pub fn query_snapshot_exclusions(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(50).min(100) as usize;
+    let start_addr = start_after
+        .map(|a| deps.api.addr_validate(&a))
+        .transpose()?;
+    let start = start_addr.as_ref().map(Bound::exclusive);
+
+    let exclusions: Vec<_> = SNAPSHOT_EXCLUSIONS
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .filter_map(|r| r.ok())
+        .collect();
+
+    to_json_binary(&exclusions)
+}
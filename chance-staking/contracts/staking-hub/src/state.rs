@@ -1,31 +1,99 @@
+use chance_staking_common::types::PendingTransfer;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Decimal, Timestamp, Uint128};
 use cw_storage_plus::{Item, Map};
 
+use crate::msg::ParamChange;
+
 pub const CONFIG: Item<Config> = Item::new("config");
 pub const EPOCH_STATE: Item<EpochState> = Item::new("epoch_state");
 pub const EXCHANGE_RATE: Item<Decimal> = Item::new("xrate");
 pub const TOTAL_INJ_BACKING: Item<Uint128> = Item::new("inj_backing");
 pub const TOTAL_CSINJ_SUPPLY: Item<Uint128> = Item::new("csinj_supply");
 pub const UNSTAKE_REQUESTS: Map<(&Addr, u64), UnstakeRequest> = Map::new("unstake_reqs");
+/// Unstake requests as stored before unbonding batches. Only read by migrate, which
+/// rewrites them into UNSTAKE_REQUESTS.
+pub const LEGACY_UNSTAKE_REQUESTS: Map<(&Addr, u64), LegacyUnstakeRequest> =
+    Map::new("unstake_reqs");
 pub const NEXT_UNSTAKE_ID: Map<&Addr, u64> = Map::new("next_unstake_id");
 /// Running total of all unclaimed unstake request INJ amounts.
 /// Updated on unstake (increment) and claim_unstaked (decrement) to avoid
 /// iterating all requests on every distribute_rewards() call.
 pub const PENDING_UNSTAKE_TOTAL: Item<Uint128> = Item::new("pending_unstake");
-/// Tracks the epoch of the user's most recent stake. Resets on every stake
-/// so newly added funds must also satisfy the min_epochs eligibility requirement.
+/// Staking rewards the SDK paid out when the hub changed a delegation since the last
+/// epoch was closed. They land before AdvanceEpoch records its starting balance, so
+/// it adds them to the rewards it measures.
+pub const AUTO_WITHDRAWN_REWARDS: Item<Uint128> = Item::new("auto_withdrawn_rewards");
+/// INJ beyond pending unstake claims and auto-withdrawn rewards at the last
+/// AdvanceEpoch. Each epoch only reports what was added since, until the admin
+/// sweeps it with SweepSurplus.
+pub const UNEXPLAINED_SURPLUS: Item<Uint128> = Item::new("unexplained_surplus");
+/// AdvanceEpoch in flight between dispatching its reward withdrawals and the reply.
+/// Set and cleared within the same transaction.
+pub const PENDING_EPOCH_ADVANCE: Item<PendingEpochAdvance> = Item::new("pending_epoch_advance");
+/// Balance-weighted average epoch at which the user's csINJ entered their wallet.
+/// Stakes and incoming transfers pull it towards the current epoch in proportion
+/// to the amount added; unstakes leave it unchanged.
 pub const USER_STAKE_EPOCH: Map<&Addr, u64> = Map::new("user_stake_epoch");
+/// INJ the hub expects to have delegated to each validator, updated whenever it
+/// dispatches a delegate, undelegate or redelegate. A shortfall against the actual
+/// delegation is a slashing loss.
+pub const EXPECTED_DELEGATIONS: Map<&str, Uint128> = Map::new("expected_delegations");
+/// Id of the unbonding batch currently collecting unstake requests.
+pub const CURRENT_BATCH_ID: Item<u64> = Item::new("current_batch_id");
+/// Unbonding batches by id. Unstake requests are accumulated into the open batch
+/// and undelegated together when the batch is submitted, so the number of
+/// concurrent unbonding entries per validator stays under the SDK limit.
+pub const UNBONDING_BATCHES: Map<u64, UnbondingBatch> = Map::new("unbonding_batches");
+/// Parameter changes queued behind the timelock, by id.
+pub const PENDING_CHANGES: Map<u64, PendingChange> = Map::new("pending_changes");
+pub const NEXT_CHANGE_ID: Item<u64> = Item::new("next_change_id");
+/// Time-weighted balance checkpoints per holder, keyed by (holder, unix seconds).
+/// Written on stake, unstake and csINJ transfers.
+pub const TWAB_CHECKPOINTS: Map<(&Addr, u64), TwabCheckpoint> = Map::new("twab_checkpoints");
+/// Checkpoints of the total tracked csINJ balance, keyed by unix seconds.
+pub const TOTAL_TWAB_CHECKPOINTS: Map<u64, TwabCheckpoint> = Map::new("total_twab_checkpoints");
+/// Addresses whose csINJ is left out of draw snapshots (treasury, DEX pools, ...).
+/// Their balances are subtracted from the supply when checking snapshot totals.
+pub const SNAPSHOT_EXCLUSIONS: Map<&Addr, ()> = Map::new("snapshot_exclusions");
+/// Yield-only shares per holder. Shares are a claim on the csINJ the hub holds for
+/// yield-only mode, which stays out of the draws.
+pub const YIELD_ONLY_SHARES: Map<&Addr, Uint128> = Map::new("yield_only_shares");
+pub const YIELD_ONLY_TOTALS: Item<YieldOnlyTotals> = Item::new("yield_only_totals");
+/// Sponsor shares per sponsor. Shares are a claim on the sponsored INJ, which is
+/// delegated like staked INJ but backs no csINJ.
+pub const SPONSOR_SHARES: Map<&Addr, Uint128> = Map::new("sponsor_shares");
+pub const SPONSOR_TOTALS: Item<SponsorTotals> = Item::new("sponsor_totals");
+/// Addresses that have ever sponsored. Never cleared: sponsors stay excluded from
+/// draw snapshots after withdrawing their sponsorship.
+pub const SPONSORS: Map<&Addr, ()> = Map::new("sponsors");
+/// Running totals of INJ donated through Donate, per target
+pub const DONATION_TOTALS: Item<DonationTotals> = Item::new("donation_totals");
+/// Record of each finished epoch, keyed by epoch number. Written by DistributeRewards
+/// and AdvanceEpoch.
+pub const EPOCH_HISTORY: Map<u64, EpochRecord> = Map::new("epoch_history");
+/// Operations currently halted by the guardian or admin.
+pub const PAUSED_OPERATIONS: Item<Vec<PausableOperation>> = Item::new("paused_operations");
 
 #[cw_serde]
 pub struct Config {
     pub admin: Addr,
     pub operator: Addr,
+    /// Can pause operations during an incident. Only the admin can unpause.
+    pub guardian: Option<Addr>,
+    /// Proposed admin, waiting for AcceptAdmin
+    pub pending_admin: Option<PendingTransfer>,
+    /// Proposed operator, waiting for AcceptOperator
+    pub pending_operator: Option<PendingTransfer>,
     pub reward_distributor: Addr,
     pub drand_oracle: Addr,
     /// Token Factory denom: "factory/{this_contract}/csINJ"
     pub csinj_denom: String,
     pub validators: Vec<String>,
+    /// Target delegation weight per validator, index-aligned with `validators`.
+    /// Weights are relative (e.g. [1, 1, 2] = 25%/25%/50%).
+    #[serde(default)]
+    pub validator_weights: Vec<u64>,
     pub epoch_duration_seconds: u64,
     /// Protocol treasury fee in basis points (500 = 5%)
     pub protocol_fee_bps: u16,
@@ -42,6 +110,51 @@ pub struct Config {
     pub min_epochs_big: u64,
     /// Minimum INJ amount required per stake transaction (0 = no minimum)
     pub min_stake_amount: Uint128,
+    /// Delay between scheduling and executing a parameter change (0 = no timelock)
+    #[serde(default)]
+    pub timelock_seconds: u64,
+    /// How far a snapshot's total_weight may exceed the non-excluded csINJ supply,
+    /// in basis points. Covers supply drops between building the snapshot off-chain
+    /// and submitting it.
+    #[serde(default = "default_snapshot_tolerance_bps")]
+    pub snapshot_tolerance_bps: u16,
+    /// Share of the treasury fee paid to a keeper that advances the epoch after the
+    /// operator's grace period, in basis points
+    #[serde(default = "default_keeper_fee_bps")]
+    pub keeper_fee_bps: u16,
+    /// How long after an epoch ends the operator still has to take its snapshot and
+    /// advance it. After that anyone can submit the snapshot and keepers are paid for
+    /// advancing, so draws don't stall without the operator, provided the distributor
+    /// has a challenge period to catch bad snapshots.
+    #[serde(default = "default_snapshot_grace_seconds")]
+    pub snapshot_grace_seconds: u64,
+}
+
+// Defaults for Config fields added after the first deployment, so configs stored
+// by older versions still load.
+fn default_snapshot_tolerance_bps() -> u16 {
+    crate::execute::DEFAULT_SNAPSHOT_TOLERANCE_BPS
+}
+
+fn default_keeper_fee_bps() -> u16 {
+    crate::execute::DEFAULT_KEEPER_FEE_BPS
+}
+
+fn default_snapshot_grace_seconds() -> u64 {
+    crate::execute::DEFAULT_SNAPSHOT_GRACE_SECONDS
+}
+
+/// Operations the guardian can pause independently. Claims of unlocked
+/// unstake requests are never pausable.
+#[cw_serde]
+pub enum PausableOperation {
+    /// Stake, Sponsor, Donate and EnterYieldOnly
+    Stake,
+    /// Unstake, WithdrawSponsorship and ExitYieldOnly
+    Unstake,
+    /// ClaimRewards, DistributeRewards and AdvanceEpoch
+    EpochAdvance,
+    TakeSnapshot,
 }
 
 #[cw_serde]
@@ -54,12 +167,168 @@ pub struct EpochState {
     pub snapshot_total_weight: Uint128,
     pub snapshot_num_holders: u32,
     pub snapshot_uri: Option<String>,
+    /// Bounds the current snapshot's totals were checked against
+    pub snapshot_bounds: Option<SnapshotBounds>,
+}
+
+#[cw_serde]
+pub struct PendingEpochAdvance {
+    /// Hub INJ balance before the reward withdrawals
+    pub balance_before: Uint128,
+    /// Caller to pay the keeper fee, unless it was the operator
+    pub keeper: Option<Addr>,
+}
+
+#[cw_serde]
+pub struct SnapshotBounds {
+    /// TOTAL_CSINJ_SUPPLY when the snapshot was submitted
+    pub csinj_supply: Uint128,
+    /// Addresses excluded from the snapshot
+    pub excluded_addresses: Vec<Addr>,
+    /// Combined csINJ balance of the excluded addresses
+    pub excluded_balance: Uint128,
+    /// csINJ held for yield-only holders
+    pub yield_only_csinj: Uint128,
+    /// csINJ held by sponsors
+    pub sponsor_csinj: Uint128,
+    pub max_weight: Uint128,
+}
+
+/// Rewards, splits and exchange rate of a finished epoch, with the snapshot taken
+/// during it.
+#[cw_serde]
+pub struct EpochRecord {
+    pub epoch: u64,
+    pub start_time: Timestamp,
+    /// When DistributeRewards closed the epoch
+    pub end_time: Timestamp,
+    pub total_rewards: Uint128,
+    /// Rewards earned by sponsored INJ, all sent to the prize pools
+    pub sponsor_rewards: Uint128,
+    pub regular_pool_amount: Uint128,
+    pub big_pool_amount: Uint128,
+    pub base_yield: Uint128,
+    /// Part of the prize pool allocation restaked for yield-only holders
+    pub yield_only_amount: Uint128,
+    /// csINJ minted into the yield-only holdings for `yield_only_amount`
+    pub yield_only_minted: Uint128,
+    pub treasury_fee: Uint128,
+    /// Part of the treasury share paid to the keeper that advanced the epoch
+    pub keeper_fee: Uint128,
+    /// INJ that arrived in the hub since the last epoch beyond pending unstake claims
+    /// and reward withdrawals (direct transfers, ...). Reported by AdvanceEpoch, not
+    /// distributed.
+    pub unexplained_surplus: Uint128,
+    /// TOTAL_INJ_BACKING after the base yield was added
+    pub total_inj_backing: Uint128,
+    pub total_csinj_supply: Uint128,
+    /// Exchange rate after the base yield was added
+    pub exchange_rate: Decimal,
+    pub snapshot_merkle_root: Option<String>,
+    pub snapshot_total_weight: Uint128,
+    pub snapshot_num_holders: u32,
+    pub snapshot_uri: Option<String>,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct YieldOnlyTotals {
+    pub shares: Uint128,
+    /// csINJ held by the hub for yield-only holders, including the csINJ minted from
+    /// their share of the prize pool allocation
+    pub csinj: Uint128,
+}
+
+/// Where a donation goes. Donations skip the treasury fee.
+#[cw_serde]
+pub enum DonationTarget {
+    RegularPool,
+    BigPool,
+    /// Delegated and added to the backing, raising the csINJ exchange rate
+    BaseYield,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct DonationTotals {
+    pub regular_pool: Uint128,
+    pub big_pool: Uint128,
+    pub base_yield: Uint128,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct SponsorTotals {
+    pub shares: Uint128,
+    /// Sponsored INJ still delegated, after any slashing
+    pub inj: Uint128,
+    /// csINJ held by sponsor addresses, which stays out of the draws
+    pub csinj: Uint128,
 }
 
 #[cw_serde]
 pub struct UnstakeRequest {
+    pub inj_amount: Uint128,
+    pub csinj_burned: Uint128,
+    /// Unbonding batch this request was added to. The request unlocks with its batch.
+    pub batch_id: u64,
+    /// Claim on the batch's post-slash INJ. Equals `inj_amount` unless the request
+    /// joined an open batch that had already been slashed.
+    pub batch_shares: Uint128,
+    pub claimed: bool,
+}
+
+/// Unstake request layout before unbonding batches, when each request was
+/// undelegated on its own and carried its own unlock time.
+#[cw_serde]
+pub struct LegacyUnstakeRequest {
     pub inj_amount: Uint128,
     pub csinj_burned: Uint128,
     pub unlock_time: Timestamp,
     pub claimed: bool,
 }
+
+#[cw_serde]
+pub struct UnbondingBatch {
+    pub id: u64,
+    /// Total INJ owed to the unstake requests in this batch, before slashing
+    pub total_inj: Uint128,
+    /// INJ lost to slashing while the batch was still delegated or unbonding.
+    /// Requests are paid pro rata out of `total_inj - slashed_inj`.
+    pub slashed_inj: Uint128,
+    /// Sum of `batch_shares` over the batch's requests
+    pub total_shares: Uint128,
+    pub num_requests: u32,
+    /// When the batch was undelegated (None while the batch is still open)
+    pub submitted_at: Option<Timestamp>,
+    /// When the undelegated INJ becomes claimable (None while the batch is still open)
+    pub unlock_time: Option<Timestamp>,
+    /// Per-validator split chosen when the batch was undelegated, reduced by any
+    /// slashing applied while unbonding
+    pub undelegations: Vec<BatchUndelegation>,
+}
+
+#[cw_serde]
+pub struct PendingChange {
+    pub id: u64,
+    pub change: ParamChange,
+    pub scheduled_at: Timestamp,
+    /// Earliest time the change can be executed
+    pub executable_at: Timestamp,
+}
+
+#[cw_serde]
+pub struct TwabCheckpoint {
+    /// Unix seconds of the balance change
+    pub timestamp: u64,
+    /// csINJ balance from `timestamp` until the next checkpoint
+    pub balance: Uint128,
+    /// Integral of the balance over time (csINJ-seconds) up to `timestamp`
+    pub cumulative: Uint128,
+}
+
+#[cw_serde]
+pub struct BatchUndelegation {
+    pub validator: String,
+    pub amount: Uint128,
+}
@@ -2,4 +2,4 @@ pub mod merkle;
 pub mod types;
 
 pub use merkle::{compute_leaf_hash, verify_merkle_proof};
-pub use types::{DrawStatus, DrawType, SnapshotEntry};
+pub use types::{DrawStatus, DrawType, PendingTransfer, Role, SnapshotEntry};
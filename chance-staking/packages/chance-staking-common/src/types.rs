@@ -1,5 +1,5 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Uint128;
+use cosmwasm_std::{Addr, Timestamp, Uint128};
 
 /// The type of draw: regular or big. Both are weighted by csINJ balance.
 #[cw_serde]
@@ -25,3 +25,24 @@ pub struct SnapshotEntry {
     pub cumulative_start: Uint128,
     pub cumulative_end: Uint128,
 }
+
+/// A role that is handed over with a propose/accept flow.
+#[cw_serde]
+pub enum Role {
+    Admin,
+    Operator,
+}
+
+/// A proposed handover of a role, waiting for the new address to accept it.
+#[cw_serde]
+pub struct PendingTransfer {
+    pub new_address: Addr,
+    /// The proposal can no longer be accepted after this time (None = no expiry)
+    pub expires_at: Option<Timestamp>,
+}
+
+impl PendingTransfer {
+    pub fn is_expired(&self, now: Timestamp) -> bool {
+        self.expires_at.is_some_and(|t| now >= t)
+    }
+}
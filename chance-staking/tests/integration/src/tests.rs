@@ -90,6 +90,10 @@ fn hub_instantiate_msg() -> chance_staking_hub::msg::InstantiateMsg {
         min_epochs_regular: 0,
         min_epochs_big: 0,
         min_stake_amount: Uint128::zero(),
+        snapshot_tolerance_bps: None,
+        keeper_fee_bps: None,
+        snapshot_grace_seconds: None,
+        timelock_seconds: None,
     }
 }
 
@@ -100,6 +104,87 @@ fn setup_hub(deps: &mut OwnedDeps<cosmwasm_std::MemoryStorage, MockApi, MockQuer
     chance_staking_hub::contract::instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
 }
 
+/// Mock the hub's on-chain delegations to match its accounting: csINJ backing plus
+/// the INJ still delegated for unsubmitted unbonding batches, split evenly.
+fn mock_hub_delegations(deps: &mut OwnedDeps<cosmwasm_std::MemoryStorage, MockApi, MockQuerier>) {
+    let config: chance_staking_hub::state::Config = from_json(
+        chance_staking_hub::contract::query(
+            deps.as_ref(),
+            mock_env(),
+            chance_staking_hub::msg::QueryMsg::Config {},
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    let rate: chance_staking_hub::msg::ExchangeRateResponse = from_json(
+        chance_staking_hub::contract::query(
+            deps.as_ref(),
+            mock_env(),
+            chance_staking_hub::msg::QueryMsg::ExchangeRate {},
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    let batches: Vec<chance_staking_hub::state::UnbondingBatch> = from_json(
+        chance_staking_hub::contract::query(
+            deps.as_ref(),
+            mock_env(),
+            chance_staking_hub::msg::QueryMsg::UnbondingBatches {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    let open_batches: Uint128 = batches
+        .iter()
+        .filter(|b| b.submitted_at.is_none())
+        .map(|b| b.total_inj - b.slashed_inj)
+        .sum();
+
+    let total = rate.total_inj_backing + open_batches;
+    let per_validator = total.u128().div_ceil(config.validators.len() as u128);
+    let contract_addr = mock_env().contract.address;
+    let delegations: Vec<cosmwasm_std::FullDelegation> = config
+        .validators
+        .iter()
+        .map(|validator| {
+            cosmwasm_std::FullDelegation::create(
+                contract_addr.clone(),
+                validator.clone(),
+                Coin::new(per_validator, "inj"),
+                Coin::new(per_validator, "inj"),
+                vec![],
+            )
+        })
+        .collect();
+    deps.querier.staking.update("inj", &[], &delegations);
+}
+
+/// Advance the hub one epoch via DistributeRewards, which submits the open
+/// unbonding batch. Returns the env the batch was submitted at.
+fn submit_unbonding_batch(
+    deps: &mut OwnedDeps<cosmwasm_std::MemoryStorage, MockApi, MockQuerier>,
+) -> cosmwasm_std::Env {
+    mock_hub_delegations(deps);
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(86400);
+    let operator = deps.api.addr_make("operator");
+    let res = chance_staking_hub::contract::execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&operator, &[]),
+        chance_staking_hub::msg::ExecuteMsg::DistributeRewards {},
+    )
+    .unwrap();
+    assert!(res
+        .events
+        .iter()
+        .any(|e| e.ty == "chance_unbonding_batch_submitted"));
+    env
+}
+
 // ─── Distributor helpers ───
 
 fn distributor_instantiate_msg() -> chance_reward_distributor::msg::InstantiateMsg {
@@ -111,6 +196,10 @@ fn distributor_instantiate_msg() -> chance_reward_distributor::msg::InstantiateM
         reveal_deadline_seconds: 3600,
         epochs_between_regular: 1,
         epochs_between_big: 7,
+        challenge_period_seconds: None,
+        regular_prize_tiers: None,
+        big_prize_tiers: None,
+        timelock_seconds: None,
     }
 }
 
@@ -235,7 +324,11 @@ fn test_unstake_flow() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_staking_hub::msg::ExecuteMsg::Stake {},
+        chance_staking_hub::msg::ExecuteMsg::Stake {
+            recipient: None,
+            min_csinj_out: None,
+            deadline: None,
+        },
     )
     .unwrap();
     // Should have mint + delegation messages
@@ -262,10 +355,14 @@ fn test_unstake_flow() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_staking_hub::msg::ExecuteMsg::Unstake {},
+        chance_staking_hub::msg::ExecuteMsg::Unstake {
+            recipient: None,
+            min_inj_out: None,
+            deadline: None,
+        },
     )
     .unwrap();
-    assert!(res.messages.len() >= 2); // burn + undelegation
+    assert_eq!(res.messages.len(), 1); // burn only, undelegation is batched
 
     // 3. Verify unstake request created with correct INJ amount
     let requests: Vec<chance_staking_hub::msg::UnstakeRequestEntry> = from_json(
@@ -297,6 +394,26 @@ fn test_unstake_flow() {
         info,
         chance_staking_hub::msg::ExecuteMsg::ClaimUnstaked {
             request_ids: vec![0],
+            recipient: None,
+        },
+    )
+    .unwrap_err();
+    assert!(
+        format!("{:?}", err).contains("UnstakeBatchPending"),
+        "Expected batch pending error, got: {:?}",
+        err
+    );
+
+    // 5. Batch is submitted at the end of the epoch, then unbonds for 21 days
+    let mut env = submit_unbonding_batch(&mut deps);
+    let info = message_info(&user1, &[]);
+    let err = chance_staking_hub::contract::execute(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        chance_staking_hub::msg::ExecuteMsg::ClaimUnstaked {
+            request_ids: vec![0],
+            recipient: None,
         },
     )
     .unwrap_err();
@@ -305,10 +422,7 @@ fn test_unstake_flow() {
         "Expected unlock error, got: {:?}",
         err
     );
-
-    // 5. Fast forward past 21 days
-    let mut env = mock_env();
-    env.block.time = Timestamp::from_seconds(env.block.time.seconds() + 22 * 24 * 60 * 60);
+    env.block.time = env.block.time.plus_seconds(21 * 24 * 60 * 60);
 
     // 6. Claim → should succeed
     let user1 = deps.api.addr_make("user1");
@@ -319,6 +433,7 @@ fn test_unstake_flow() {
         info,
         chance_staking_hub::msg::ExecuteMsg::ClaimUnstaked {
             request_ids: vec![0],
+            recipient: None,
         },
     )
     .unwrap();
@@ -382,7 +497,11 @@ fn test_exchange_rate_appreciation() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_staking_hub::msg::ExecuteMsg::Stake {},
+        chance_staking_hub::msg::ExecuteMsg::Stake {
+            recipient: None,
+            min_csinj_out: None,
+            deadline: None,
+        },
     )
     .unwrap();
 
@@ -446,7 +565,11 @@ fn test_exchange_rate_appreciation() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_staking_hub::msg::ExecuteMsg::Stake {},
+        chance_staking_hub::msg::ExecuteMsg::Stake {
+            recipient: None,
+            min_csinj_out: None,
+            deadline: None,
+        },
     )
     .unwrap();
 
@@ -479,7 +602,11 @@ fn test_exchange_rate_appreciation() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_staking_hub::msg::ExecuteMsg::Unstake {},
+        chance_staking_hub::msg::ExecuteMsg::Unstake {
+            recipient: None,
+            min_inj_out: None,
+            deadline: None,
+        },
     )
     .unwrap();
 
@@ -705,6 +832,7 @@ fn test_full_stake_and_draw_cycle() {
                         let info = chance_reward_distributor::msg::StakerInfoResponse {
                             address,
                             stake_epoch: Some(0),
+                            excluded: false,
                         };
                         SystemResult::Ok(ContractResult::Ok(to_json_binary(&info).unwrap()))
                     }
@@ -852,10 +980,12 @@ fn test_full_stake_and_draw_cycle() {
         chance_reward_distributor::msg::ExecuteMsg::RevealDraw {
             draw_id: 0,
             operator_secret_hex: hex::encode(secret),
-            winner_address: winner_addr.clone(),
-            winner_cumulative_start: winner_start,
-            winner_cumulative_end: winner_end,
-            merkle_proof: proof,
+            winners: vec![chance_reward_distributor::msg::SnapshotLeaf {
+                address: winner_addr.clone(),
+                cumulative_start: winner_start,
+                cumulative_end: winner_end,
+                merkle_proof: proof,
+            }],
         },
     )
     .unwrap();
@@ -991,6 +1121,7 @@ fn test_multiple_draws_across_epochs() {
                     let info = chance_reward_distributor::msg::StakerInfoResponse {
                         address,
                         stake_epoch: Some(0),
+                        excluded: false,
                     };
                     SystemResult::Ok(ContractResult::Ok(to_json_binary(&info).unwrap()))
                 }
@@ -1116,10 +1247,12 @@ fn test_multiple_draws_across_epochs() {
             chance_reward_distributor::msg::ExecuteMsg::RevealDraw {
                 draw_id: draw_num,
                 operator_secret_hex: hex::encode(secret_bytes),
-                winner_address: winner_addr,
-                winner_cumulative_start: winner_start,
-                winner_cumulative_end: winner_end,
-                merkle_proof: proof,
+                winners: vec![chance_reward_distributor::msg::SnapshotLeaf {
+                    address: winner_addr,
+                    cumulative_start: winner_start,
+                    cumulative_end: winner_end,
+                    merkle_proof: proof,
+                }],
             },
         )
         .unwrap();
@@ -1181,7 +1314,11 @@ fn test_slashing_detection_via_sync_delegations() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_staking_hub::msg::ExecuteMsg::Stake {},
+        chance_staking_hub::msg::ExecuteMsg::Stake {
+            recipient: None,
+            min_csinj_out: None,
+            deadline: None,
+        },
     )
     .unwrap();
 
@@ -1238,6 +1375,230 @@ fn test_slashing_detection_via_sync_delegations() {
     eprintln!("test_slashing_detection_via_sync_delegations passed");
 }
 
+#[test]
+fn test_slashing_reconciled_automatically_in_distribute_rewards() {
+    // Slashing is booked by distribute_rewards without an explicit SyncDelegations call,
+    // so later unstakes are priced at the post-slash rate.
+    let mut deps = mock_dependencies();
+    setup_hub(&mut deps);
+
+    let config: chance_staking_hub::state::Config = from_json(
+        chance_staking_hub::contract::query(
+            deps.as_ref(),
+            mock_env(),
+            chance_staking_hub::msg::QueryMsg::Config {},
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    let user1 = deps.api.addr_make("user1");
+
+    // 1. Stake 100 INJ, split 50/50 across both validators
+    chance_staking_hub::contract::execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user1, &[Coin::new(100_000_000u128, "inj")]),
+        chance_staking_hub::msg::ExecuteMsg::Stake {
+            recipient: None,
+            min_csinj_out: None,
+            deadline: None,
+        },
+    )
+    .unwrap();
+
+    // 2. First validator is slashed by 5 INJ
+    let contract_addr = mock_env().contract.address;
+    let delegations: Vec<cosmwasm_std::FullDelegation> = [45_000_000u128, 50_000_000u128]
+        .iter()
+        .zip(&config.validators)
+        .map(|(amount, validator)| {
+            cosmwasm_std::FullDelegation::create(
+                contract_addr.clone(),
+                validator.clone(),
+                Coin::new(*amount, "inj"),
+                Coin::new(*amount, "inj"),
+                vec![],
+            )
+        })
+        .collect();
+    deps.querier.staking.update("inj", &[], &delegations);
+
+    // 3. Epoch advance detects and books the loss
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(86400);
+    let operator = deps.api.addr_make("operator");
+    let res = chance_staking_hub::contract::execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&operator, &[]),
+        chance_staking_hub::msg::ExecuteMsg::DistributeRewards {},
+    )
+    .unwrap();
+    let event = res
+        .events
+        .iter()
+        .find(|e| e.ty == "chance_slashing_detected")
+        .expect("slashing event");
+    assert!(event
+        .attributes
+        .iter()
+        .any(|a| a.key == "losses" && a.value == format!("{}:5000000", config.validators[0])));
+
+    let rate: chance_staking_hub::msg::ExchangeRateResponse = from_json(
+        chance_staking_hub::contract::query(
+            deps.as_ref(),
+            env.clone(),
+            chance_staking_hub::msg::QueryMsg::ExchangeRate {},
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(rate.total_inj_backing, Uint128::from(95_000_000u128));
+    assert_eq!(rate.rate, Decimal::percent(95));
+
+    // 4. Unstaking 10 csINJ now owes 9.5 INJ
+    chance_staking_hub::contract::execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&user1, &[Coin::new(10_000_000u128, &config.csinj_denom)]),
+        chance_staking_hub::msg::ExecuteMsg::Unstake {
+            recipient: None,
+            min_inj_out: None,
+            deadline: None,
+        },
+    )
+    .unwrap();
+    let requests: Vec<chance_staking_hub::msg::UnstakeRequestEntry> = from_json(
+        chance_staking_hub::contract::query(
+            deps.as_ref(),
+            env,
+            chance_staking_hub::msg::QueryMsg::UnstakeRequests {
+                address: user1.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(requests[0].request.inj_amount, Uint128::from(9_500_000u128));
+
+    eprintln!("test_slashing_reconciled_automatically_in_distribute_rewards passed");
+}
+
+#[test]
+fn test_slashing_during_unbonding_reduces_claim() {
+    // A slash that hits a batch while it is unbonding is borne by its unstake
+    // requests, not by the remaining csINJ holders.
+    let mut deps = mock_dependencies();
+    setup_hub(&mut deps);
+
+    let config: chance_staking_hub::state::Config = from_json(
+        chance_staking_hub::contract::query(
+            deps.as_ref(),
+            mock_env(),
+            chance_staking_hub::msg::QueryMsg::Config {},
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    let user1 = deps.api.addr_make("user1");
+
+    // 1. Stake 100 INJ and unstake 40 csINJ
+    chance_staking_hub::contract::execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user1, &[Coin::new(100_000_000u128, "inj")]),
+        chance_staking_hub::msg::ExecuteMsg::Stake {
+            recipient: None,
+            min_csinj_out: None,
+            deadline: None,
+        },
+    )
+    .unwrap();
+    chance_staking_hub::contract::execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user1, &[Coin::new(40_000_000u128, &config.csinj_denom)]),
+        chance_staking_hub::msg::ExecuteMsg::Unstake {
+            recipient: None,
+            min_inj_out: None,
+            deadline: None,
+        },
+    )
+    .unwrap();
+
+    // 2. Submit the batch, then halve both remaining 30 INJ delegations while it unbonds
+    let mut env = submit_unbonding_batch(&mut deps);
+    let contract_addr = mock_env().contract.address;
+    let delegations: Vec<cosmwasm_std::FullDelegation> = config
+        .validators
+        .iter()
+        .map(|validator| {
+            cosmwasm_std::FullDelegation::create(
+                contract_addr.clone(),
+                validator.clone(),
+                Coin::new(15_000_000u128, "inj"),
+                Coin::new(15_000_000u128, "inj"),
+                vec![],
+            )
+        })
+        .collect();
+    deps.querier.staking.update("inj", &[], &delegations);
+
+    // 3. The next epoch advance books the loss against backing and the unbonding batch
+    env.block.time = env.block.time.plus_seconds(86400);
+    let operator = deps.api.addr_make("operator");
+    chance_staking_hub::contract::execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&operator, &[]),
+        chance_staking_hub::msg::ExecuteMsg::DistributeRewards {},
+    )
+    .unwrap();
+
+    let requests: Vec<chance_staking_hub::msg::UnstakeRequestEntry> = from_json(
+        chance_staking_hub::contract::query(
+            deps.as_ref(),
+            env.clone(),
+            chance_staking_hub::msg::QueryMsg::UnstakeRequests {
+                address: user1.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        requests[0].request.inj_amount,
+        Uint128::from(40_000_000u128)
+    );
+    assert_eq!(
+        requests[0].adjusted_inj_amount,
+        Uint128::from(20_000_000u128)
+    );
+
+    // 4. Claiming after unlock pays the adjusted amount
+    env.block.time = env.block.time.plus_seconds(22 * 86400);
+    let res = chance_staking_hub::contract::execute(
+        deps.as_mut(),
+        env,
+        message_info(&user1, &[]),
+        chance_staking_hub::msg::ExecuteMsg::ClaimUnstaked {
+            request_ids: vec![0],
+            recipient: None,
+        },
+    )
+    .unwrap();
+    assert!(res
+        .attributes
+        .iter()
+        .any(|a| a.key == "total_claimed" && a.value == "20000000"));
+
+    eprintln!("test_slashing_during_unbonding_reduces_claim passed");
+}
+
 #[test]
 fn test_multi_epoch_base_yield_no_double_counting() {
     // C-01: Verify that base yield doesn't double-count across multiple epochs.
@@ -1254,7 +1615,11 @@ fn test_multi_epoch_base_yield_no_double_counting() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_staking_hub::msg::ExecuteMsg::Stake {},
+        chance_staking_hub::msg::ExecuteMsg::Stake {
+            recipient: None,
+            min_csinj_out: None,
+            deadline: None,
+        },
     )
     .unwrap();
 
@@ -1356,8 +1721,6 @@ fn test_bps_sum_validation_in_update_config() {
         mock_env(),
         info,
         chance_staking_hub::msg::ExecuteMsg::UpdateConfig {
-            admin: None,
-            operator: None,
             protocol_fee_bps: Some(1000), // This breaks the sum
             base_yield_bps: None,
             regular_pool_bps: None,
@@ -1365,6 +1728,9 @@ fn test_bps_sum_validation_in_update_config() {
             min_epochs_regular: None,
             min_epochs_big: None,
             min_stake_amount: None,
+            snapshot_tolerance_bps: None,
+            keeper_fee_bps: None,
+            snapshot_grace_seconds: None,
         },
     )
     .unwrap_err();
@@ -1383,8 +1749,6 @@ fn test_bps_sum_validation_in_update_config() {
         mock_env(),
         info,
         chance_staking_hub::msg::ExecuteMsg::UpdateConfig {
-            admin: None,
-            operator: None,
             protocol_fee_bps: Some(1000),
             base_yield_bps: None,
             regular_pool_bps: Some(6500),
@@ -1392,6 +1756,9 @@ fn test_bps_sum_validation_in_update_config() {
             min_epochs_regular: None,
             min_epochs_big: None,
             min_stake_amount: None,
+            snapshot_tolerance_bps: None,
+            keeper_fee_bps: None,
+            snapshot_grace_seconds: None,
         },
     );
     assert!(res.is_ok(), "Valid BPS sum should succeed");
@@ -1543,7 +1910,11 @@ fn test_large_stake_amounts_no_overflow() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_staking_hub::msg::ExecuteMsg::Stake {},
+        chance_staking_hub::msg::ExecuteMsg::Stake {
+            recipient: None,
+            min_csinj_out: None,
+            deadline: None,
+        },
     );
 
     // Should succeed without overflow
@@ -1592,7 +1963,11 @@ fn test_cross_contract_balance_reconciliation() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_staking_hub::msg::ExecuteMsg::Stake {},
+        chance_staking_hub::msg::ExecuteMsg::Stake {
+            recipient: None,
+            min_csinj_out: None,
+            deadline: None,
+        },
     )
     .unwrap();
 
@@ -1613,7 +1988,11 @@ fn test_cross_contract_balance_reconciliation() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_staking_hub::msg::ExecuteMsg::Stake {},
+        chance_staking_hub::msg::ExecuteMsg::Stake {
+            recipient: None,
+            min_csinj_out: None,
+            deadline: None,
+        },
     )
     .unwrap();
 
@@ -1634,7 +2013,11 @@ fn test_cross_contract_balance_reconciliation() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_staking_hub::msg::ExecuteMsg::Unstake {},
+        chance_staking_hub::msg::ExecuteMsg::Unstake {
+            recipient: None,
+            min_inj_out: None,
+            deadline: None,
+        },
     )
     .unwrap();
 
@@ -1653,9 +2036,9 @@ fn test_cross_contract_balance_reconciliation() {
     assert_eq!(rate3.total_inj_backing, Uint128::from(120_000_000u128));
     assert_eq!(rate3.total_csinj_supply, Uint128::from(120_000_000u128));
 
-    // 4. Advance time and claim
-    let mut env = mock_env();
-    env.block.time = Timestamp::from_seconds(env.block.time.seconds() + 22 * 24 * 60 * 60);
+    // 4. Submit the unbonding batch, advance time and claim
+    let mut env = submit_unbonding_batch(&mut deps);
+    env.block.time = env.block.time.plus_seconds(21 * 24 * 60 * 60);
 
     let info = message_info(&user1, &[]);
     chance_staking_hub::contract::execute(
@@ -1664,6 +2047,7 @@ fn test_cross_contract_balance_reconciliation() {
         info,
         chance_staking_hub::msg::ExecuteMsg::ClaimUnstaked {
             request_ids: vec![0],
+            recipient: None,
         },
     )
     .unwrap();
@@ -1737,6 +2121,7 @@ fn test_reveal_draw_rejects_ineligible_winner() {
                         let info = chance_reward_distributor::msg::StakerInfoResponse {
                             address,
                             stake_epoch: Some(1),
+                            excluded: false,
                         };
                         SystemResult::Ok(ContractResult::Ok(to_json_binary(&info).unwrap()))
                     }
@@ -1821,10 +2206,12 @@ fn test_reveal_draw_rejects_ineligible_winner() {
         chance_reward_distributor::msg::ExecuteMsg::RevealDraw {
             draw_id: 0,
             operator_secret_hex: hex::encode(secret),
-            winner_address: addr_a.clone(),
-            winner_cumulative_start: Uint128::zero(),
-            winner_cumulative_end: Uint128::from(100u128),
-            merkle_proof: vec![], // single leaf = empty proof
+            winners: vec![chance_reward_distributor::msg::SnapshotLeaf {
+                address: addr_a.clone(),
+                cumulative_start: Uint128::zero(),
+                cumulative_end: Uint128::from(100u128),
+                merkle_proof: vec![],
+            }], // single leaf = empty proof
         },
     )
     .unwrap_err();
@@ -1839,28 +2226,23 @@ fn test_reveal_draw_rejects_ineligible_winner() {
 }
 
 #[test]
-fn test_reveal_draw_accepts_eligible_winner() {
-    // Test that reveal_draw succeeds when winner meets min_epochs eligibility.
-    // Scenario: min_epochs_regular=2, winner staked at epoch 1, draw is for epoch 3
-    // → epochs_staked = 3 - 1 = 2, which is >= 2 → should succeed.
-
+fn test_reveal_draw_rejects_excluded_winner() {
+    // A winner on the hub's snapshot exclusion list is rejected even if the
+    // snapshot (wrongly) gave them a leaf.
     let mut oracle_deps = mock_dependencies();
     setup_oracle(&mut oracle_deps);
-
     let operator = oracle_deps.api.addr_make("operator");
-    let info = message_info(&operator, &[]);
     chance_drand_oracle::contract::execute(
         oracle_deps.as_mut(),
         mock_env(),
-        info,
+        message_info(&operator, &[]),
         chance_drand_oracle::msg::ExecuteMsg::SubmitBeacon {
             round: TEST_ROUND,
             signature_hex: TEST_SIG_HEX.to_string(),
         },
     )
     .unwrap();
-
-    let beacon_query_res = chance_drand_oracle::contract::query(
+    let beacon_binary = chance_drand_oracle::contract::query(
         oracle_deps.as_ref(),
         mock_env(),
         chance_drand_oracle::msg::QueryMsg::Beacon { round: TEST_ROUND },
@@ -1868,31 +2250,211 @@ fn test_reveal_draw_accepts_eligible_winner() {
     .unwrap();
 
     let mut dist_deps = mock_dependencies();
-    let beacon_binary = beacon_query_res.clone();
-    dist_deps.querier.update_wasm(move |query| {
-        match query {
-            WasmQuery::Smart { msg, .. } => {
-                let parsed: Result<chance_reward_distributor::msg::StakingHubQueryMsg, _> =
-                    from_json(msg);
-                match parsed {
-                    Ok(chance_reward_distributor::msg::StakingHubQueryMsg::Config {}) => {
-                        let config = chance_reward_distributor::msg::StakingHubConfigResponse {
-                            min_epochs_regular: 2,
-                            min_epochs_big: 6,
-                        };
-                        SystemResult::Ok(ContractResult::Ok(to_json_binary(&config).unwrap()))
-                    }
-                    Ok(chance_reward_distributor::msg::StakingHubQueryMsg::StakerInfo {
-                        address,
-                    }) => {
-                        // Winner staked at epoch 1
-                        let info = chance_reward_distributor::msg::StakerInfoResponse {
-                            address,
-                            stake_epoch: Some(1),
-                        };
-                        SystemResult::Ok(ContractResult::Ok(to_json_binary(&info).unwrap()))
-                    }
-                    _ => SystemResult::Ok(ContractResult::Ok(beacon_binary.clone())),
+    dist_deps.querier.update_wasm(move |query| match query {
+        WasmQuery::Smart { msg, .. } => {
+            let parsed: Result<chance_reward_distributor::msg::StakingHubQueryMsg, _> =
+                from_json(msg);
+            match parsed {
+                Ok(chance_reward_distributor::msg::StakingHubQueryMsg::Config {}) => {
+                    let config = chance_reward_distributor::msg::StakingHubConfigResponse {
+                        min_epochs_regular: 0,
+                        min_epochs_big: 0,
+                    };
+                    SystemResult::Ok(ContractResult::Ok(to_json_binary(&config).unwrap()))
+                }
+                Ok(chance_reward_distributor::msg::StakingHubQueryMsg::StakerInfo { address }) => {
+                    let info = chance_reward_distributor::msg::StakerInfoResponse {
+                        address,
+                        stake_epoch: Some(0),
+                        excluded: true,
+                    };
+                    SystemResult::Ok(ContractResult::Ok(to_json_binary(&info).unwrap()))
+                }
+                _ => SystemResult::Ok(ContractResult::Ok(beacon_binary.clone())),
+            }
+        }
+        _ => SystemResult::Err(cosmwasm_std::SystemError::InvalidRequest {
+            error: "Only smart queries supported".to_string(),
+            request: Default::default(),
+        }),
+    });
+    setup_distributor(&mut dist_deps);
+
+    let staking_hub = dist_deps.api.addr_make("staking_hub");
+    chance_reward_distributor::contract::execute(
+        dist_deps.as_mut(),
+        mock_env(),
+        message_info(&staking_hub, &[Coin::new(50_000_000u128, "inj")]),
+        chance_reward_distributor::msg::ExecuteMsg::FundRegularPool {},
+    )
+    .unwrap();
+
+    let treasury = dist_deps.api.addr_make("treasury").to_string();
+    let root_hex = hex::encode(compute_leaf_hash(&treasury, 0, 100));
+    chance_reward_distributor::contract::execute(
+        dist_deps.as_mut(),
+        mock_env(),
+        message_info(&staking_hub, &[]),
+        chance_reward_distributor::msg::ExecuteMsg::SetSnapshot {
+            epoch: 1,
+            merkle_root: root_hex,
+            total_weight: Uint128::from(100u128),
+            num_holders: 1,
+        },
+    )
+    .unwrap();
+
+    let secret = b"exclusion_test_secret";
+    let commit: [u8; 32] = Sha256::digest(secret).into();
+    let operator = dist_deps.api.addr_make("operator");
+    chance_reward_distributor::contract::execute(
+        dist_deps.as_mut(),
+        mock_env(),
+        message_info(&operator, &[]),
+        chance_reward_distributor::msg::ExecuteMsg::CommitDraw {
+            draw_type: chance_staking_common::types::DrawType::Regular,
+            operator_commit: hex::encode(commit),
+            target_drand_round: TEST_ROUND,
+            epoch: 1,
+        },
+    )
+    .unwrap();
+
+    let env = mock_env();
+    dist_deps.querier.bank.update_balance(
+        &env.contract.address,
+        vec![Coin::new(50_000_000u128, "inj")],
+    );
+    let treasury_leaf = chance_reward_distributor::msg::SnapshotLeaf {
+        address: treasury,
+        cumulative_start: Uint128::zero(),
+        cumulative_end: Uint128::from(100u128),
+        merkle_proof: vec![],
+    };
+    let err = chance_reward_distributor::contract::execute(
+        dist_deps.as_mut(),
+        env.clone(),
+        message_info(&operator, &[]),
+        chance_reward_distributor::msg::ExecuteMsg::RevealDraw {
+            draw_id: 0,
+            operator_secret_hex: hex::encode(secret),
+            winners: vec![treasury_leaf.clone()],
+        },
+    )
+    .unwrap_err();
+    assert!(
+        format!("{:?}", err).contains("WinnerExcluded"),
+        "Should reject excluded winner, got: {:?}",
+        err
+    );
+
+    // Every redraw lands on the only holder too: the prize goes back to the pool
+    let res = chance_reward_distributor::contract::execute(
+        dist_deps.as_mut(),
+        env,
+        message_info(&operator, &[]),
+        chance_reward_distributor::msg::ExecuteMsg::RevealDraw {
+            draw_id: 0,
+            operator_secret_hex: hex::encode(secret),
+            winners: vec![
+                treasury_leaf;
+                1 + chance_reward_distributor::execute::MAX_PRIZE_REDRAWS as usize
+            ],
+        },
+    )
+    .unwrap();
+    assert!(res.messages.is_empty(), "nothing is paid");
+    assert_eq!(
+        res.events
+            .iter()
+            .filter(|e| e.ty == "chance_prize_redrawn")
+            .count(),
+        1 + chance_reward_distributor::execute::MAX_PRIZE_REDRAWS as usize
+    );
+    assert!(res.events.iter().any(|e| e.ty == "chance_prize_forfeited"));
+
+    let draw: chance_reward_distributor::state::Draw = from_json(
+        chance_reward_distributor::contract::query(
+            dist_deps.as_ref(),
+            mock_env(),
+            chance_reward_distributor::msg::QueryMsg::Draw { draw_id: 0 },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(draw.winner, None);
+    assert!(draw.winners.is_empty());
+    let state: chance_reward_distributor::state::DrawStateInfo = from_json(
+        chance_reward_distributor::contract::query(
+            dist_deps.as_ref(),
+            mock_env(),
+            chance_reward_distributor::msg::QueryMsg::DrawState {},
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(state.regular_pool_balance, Uint128::from(50_000_000u128));
+    assert_eq!(state.total_rewards_distributed, Uint128::zero());
+
+    eprintln!("test_reveal_draw_rejects_excluded_winner passed");
+}
+
+#[test]
+fn test_reveal_draw_accepts_eligible_winner() {
+    // Test that reveal_draw succeeds when winner meets min_epochs eligibility.
+    // Scenario: min_epochs_regular=2, winner staked at epoch 1, draw is for epoch 3
+    // → epochs_staked = 3 - 1 = 2, which is >= 2 → should succeed.
+
+    let mut oracle_deps = mock_dependencies();
+    setup_oracle(&mut oracle_deps);
+
+    let operator = oracle_deps.api.addr_make("operator");
+    let info = message_info(&operator, &[]);
+    chance_drand_oracle::contract::execute(
+        oracle_deps.as_mut(),
+        mock_env(),
+        info,
+        chance_drand_oracle::msg::ExecuteMsg::SubmitBeacon {
+            round: TEST_ROUND,
+            signature_hex: TEST_SIG_HEX.to_string(),
+        },
+    )
+    .unwrap();
+
+    let beacon_query_res = chance_drand_oracle::contract::query(
+        oracle_deps.as_ref(),
+        mock_env(),
+        chance_drand_oracle::msg::QueryMsg::Beacon { round: TEST_ROUND },
+    )
+    .unwrap();
+
+    let mut dist_deps = mock_dependencies();
+    let beacon_binary = beacon_query_res.clone();
+    dist_deps.querier.update_wasm(move |query| {
+        match query {
+            WasmQuery::Smart { msg, .. } => {
+                let parsed: Result<chance_reward_distributor::msg::StakingHubQueryMsg, _> =
+                    from_json(msg);
+                match parsed {
+                    Ok(chance_reward_distributor::msg::StakingHubQueryMsg::Config {}) => {
+                        let config = chance_reward_distributor::msg::StakingHubConfigResponse {
+                            min_epochs_regular: 2,
+                            min_epochs_big: 6,
+                        };
+                        SystemResult::Ok(ContractResult::Ok(to_json_binary(&config).unwrap()))
+                    }
+                    Ok(chance_reward_distributor::msg::StakingHubQueryMsg::StakerInfo {
+                        address,
+                    }) => {
+                        // Winner staked at epoch 1
+                        let info = chance_reward_distributor::msg::StakerInfoResponse {
+                            address,
+                            stake_epoch: Some(1),
+                            excluded: false,
+                        };
+                        SystemResult::Ok(ContractResult::Ok(to_json_binary(&info).unwrap()))
+                    }
+                    _ => SystemResult::Ok(ContractResult::Ok(beacon_binary.clone())),
                 }
             }
             _ => SystemResult::Err(cosmwasm_std::SystemError::InvalidRequest {
@@ -1936,6 +2498,34 @@ fn test_reveal_draw_accepts_eligible_winner() {
     )
     .unwrap();
 
+    // A bonus prize in two accepted denoms
+    let admin = dist_deps.api.addr_make("admin");
+    chance_reward_distributor::contract::execute(
+        dist_deps.as_mut(),
+        mock_env(),
+        message_info(&admin, &[]),
+        chance_reward_distributor::msg::ExecuteMsg::UpdateBonusDenoms {
+            add: vec![Coin::new(1u128, "uatom"), Coin::new(1u128, "peggy0xusdt")],
+            remove: vec![],
+        },
+    )
+    .unwrap();
+    let sponsor = dist_deps.api.addr_make("sponsor");
+    let bonus = vec![
+        Coin::new(1_000u128, "peggy0xusdt"),
+        Coin::new(500u128, "uatom"),
+    ];
+    chance_reward_distributor::contract::execute(
+        dist_deps.as_mut(),
+        mock_env(),
+        message_info(&sponsor, &bonus),
+        chance_reward_distributor::msg::ExecuteMsg::AddBonusPrize {
+            draw_type: chance_staking_common::types::DrawType::Regular,
+            target_epoch: Some(3),
+        },
+    )
+    .unwrap();
+
     // Commit draw for epoch 3
     let secret = b"eligibility_test_secret";
     let commit: [u8; 32] = Sha256::digest(secret).into();
@@ -1971,19 +2561,43 @@ fn test_reveal_draw_accepts_eligible_winner() {
         chance_reward_distributor::msg::ExecuteMsg::RevealDraw {
             draw_id: 0,
             operator_secret_hex: hex::encode(secret),
-            winner_address: addr_a.clone(),
-            winner_cumulative_start: Uint128::zero(),
-            winner_cumulative_end: Uint128::from(100u128),
-            merkle_proof: vec![],
+            winners: vec![chance_reward_distributor::msg::SnapshotLeaf {
+                address: addr_a.clone(),
+                cumulative_start: Uint128::zero(),
+                cumulative_end: Uint128::from(100u128),
+                merkle_proof: vec![],
+            }],
         },
     );
 
     assert!(
         res.is_ok(),
         "Eligible winner should succeed, got: {:?}",
-        res.unwrap_err()
+        res.as_ref().unwrap_err()
     );
 
+    // Each bonus denom is paid on its own, and a failed payout doesn't revert the reveal
+    let bonus_payouts: Vec<_> = res
+        .unwrap()
+        .messages
+        .into_iter()
+        .filter(|m| m.reply_on == cosmwasm_std::ReplyOn::Error)
+        .collect();
+    assert_eq!(bonus_payouts.len(), 2);
+    for (payout, coin) in bonus_payouts.iter().zip(&bonus) {
+        assert_eq!(
+            payout.id,
+            chance_reward_distributor::execute::BONUS_PAYOUT_REPLY_ID
+        );
+        assert_eq!(
+            payout.msg,
+            cosmwasm_std::CosmosMsg::Bank(cosmwasm_std::BankMsg::Send {
+                to_address: addr_a.clone(),
+                amount: vec![coin.clone()],
+            })
+        );
+    }
+
     eprintln!("test_reveal_draw_accepts_eligible_winner passed");
 }
 
@@ -2037,6 +2651,7 @@ fn test_big_pool_draw_cycle() {
                     let info = chance_reward_distributor::msg::StakerInfoResponse {
                         address,
                         stake_epoch: Some(0),
+                        excluded: false,
                     };
                     SystemResult::Ok(ContractResult::Ok(to_json_binary(&info).unwrap()))
                 }
@@ -2142,10 +2757,12 @@ fn test_big_pool_draw_cycle() {
         chance_reward_distributor::msg::ExecuteMsg::RevealDraw {
             draw_id: 0,
             operator_secret_hex: hex::encode(secret),
-            winner_address: addr_a.clone(),
-            winner_cumulative_start: Uint128::zero(),
-            winner_cumulative_end: Uint128::from(100u128),
-            merkle_proof: vec![],
+            winners: vec![chance_reward_distributor::msg::SnapshotLeaf {
+                address: addr_a.clone(),
+                cumulative_start: Uint128::zero(),
+                cumulative_end: Uint128::from(100u128),
+                merkle_proof: vec![],
+            }],
         },
     );
     assert!(res.is_ok(), "Big pool reveal should succeed");
@@ -2399,6 +3016,7 @@ fn test_invalid_merkle_proof_rejected() {
                     let info = chance_reward_distributor::msg::StakerInfoResponse {
                         address,
                         stake_epoch: Some(0),
+                        excluded: false,
                     };
                     SystemResult::Ok(ContractResult::Ok(to_json_binary(&info).unwrap()))
                 }
@@ -2507,10 +3125,12 @@ fn test_invalid_merkle_proof_rejected() {
         chance_reward_distributor::msg::ExecuteMsg::RevealDraw {
             draw_id: 0,
             operator_secret_hex: hex::encode(secret),
-            winner_address: winner_addr,
-            winner_cumulative_start: winner_start,
-            winner_cumulative_end: winner_end,
-            merkle_proof: fake_proof,
+            winners: vec![chance_reward_distributor::msg::SnapshotLeaf {
+                address: winner_addr,
+                cumulative_start: winner_start,
+                cumulative_end: winner_end,
+                merkle_proof: fake_proof,
+            }],
         },
     )
     .unwrap_err();
@@ -2570,6 +3190,7 @@ fn test_winning_ticket_out_of_range_rejected() {
                     let info = chance_reward_distributor::msg::StakerInfoResponse {
                         address,
                         stake_epoch: Some(0),
+                        excluded: false,
                     };
                     SystemResult::Ok(ContractResult::Ok(to_json_binary(&info).unwrap()))
                 }
@@ -2649,10 +3270,12 @@ fn test_winning_ticket_out_of_range_rejected() {
         chance_reward_distributor::msg::ExecuteMsg::RevealDraw {
             draw_id: 0,
             operator_secret_hex: hex::encode(secret),
-            winner_address: addr_a.clone(),
-            winner_cumulative_start: Uint128::from(999u128),
-            winner_cumulative_end: Uint128::from(1000u128),
-            merkle_proof: vec![],
+            winners: vec![chance_reward_distributor::msg::SnapshotLeaf {
+                address: addr_a.clone(),
+                cumulative_start: Uint128::from(999u128),
+                cumulative_end: Uint128::from(1000u128),
+                merkle_proof: vec![],
+            }],
         },
     );
 
@@ -2699,6 +3322,7 @@ fn test_beacon_not_found_during_reveal() {
                         let info = chance_reward_distributor::msg::StakerInfoResponse {
                             address,
                             stake_epoch: Some(0),
+                            excluded: false,
                         };
                         SystemResult::Ok(ContractResult::Ok(to_json_binary(&info).unwrap()))
                     }
@@ -2785,10 +3409,12 @@ fn test_beacon_not_found_during_reveal() {
         chance_reward_distributor::msg::ExecuteMsg::RevealDraw {
             draw_id: 0,
             operator_secret_hex: hex::encode(secret),
-            winner_address: addr_a,
-            winner_cumulative_start: Uint128::zero(),
-            winner_cumulative_end: Uint128::from(100u128),
-            merkle_proof: vec![],
+            winners: vec![chance_reward_distributor::msg::SnapshotLeaf {
+                address: addr_a,
+                cumulative_start: Uint128::zero(),
+                cumulative_end: Uint128::from(100u128),
+                merkle_proof: vec![],
+            }],
         },
     )
     .unwrap_err();
@@ -2827,7 +3453,11 @@ fn test_multiple_unstake_requests_per_user() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_staking_hub::msg::ExecuteMsg::Stake {},
+        chance_staking_hub::msg::ExecuteMsg::Stake {
+            recipient: None,
+            min_csinj_out: None,
+            deadline: None,
+        },
     )
     .unwrap();
 
@@ -2839,7 +3469,11 @@ fn test_multiple_unstake_requests_per_user() {
             deps.as_mut(),
             mock_env(),
             info,
-            chance_staking_hub::msg::ExecuteMsg::Unstake {},
+            chance_staking_hub::msg::ExecuteMsg::Unstake {
+                recipient: None,
+                min_inj_out: None,
+                deadline: None,
+            },
         )
         .unwrap();
     }
@@ -2862,10 +3496,13 @@ fn test_multiple_unstake_requests_per_user() {
     assert_eq!(requests[0].id, 0);
     assert_eq!(requests[1].id, 1);
     assert_eq!(requests[2].id, 2);
+    // All three requests share the open batch
+    assert!(requests.iter().all(|r| r.request.batch_id == 1));
+    assert!(requests.iter().all(|r| r.unlock_time.is_none()));
 
-    // Fast forward past 21 days
-    let mut env = mock_env();
-    env.block.time = Timestamp::from_seconds(env.block.time.seconds() + 22 * 24 * 60 * 60);
+    // Submit the batch and fast forward past 21 days
+    let mut env = submit_unbonding_batch(&mut deps);
+    env.block.time = env.block.time.plus_seconds(21 * 24 * 60 * 60);
 
     // Claim just request 1
     let user = deps.api.addr_make("user");
@@ -2876,6 +3513,7 @@ fn test_multiple_unstake_requests_per_user() {
         info,
         chance_staking_hub::msg::ExecuteMsg::ClaimUnstaked {
             request_ids: vec![1],
+            recipient: None,
         },
     )
     .unwrap();
@@ -2890,6 +3528,7 @@ fn test_multiple_unstake_requests_per_user() {
         info,
         chance_staking_hub::msg::ExecuteMsg::ClaimUnstaked {
             request_ids: vec![0, 2],
+            recipient: None,
         },
     )
     .unwrap();
@@ -2954,7 +3593,11 @@ fn test_zero_rewards_distribution() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_staking_hub::msg::ExecuteMsg::Stake {},
+        chance_staking_hub::msg::ExecuteMsg::Stake {
+            recipient: None,
+            min_csinj_out: None,
+            deadline: None,
+        },
     )
     .unwrap();
 
@@ -2965,11 +3608,16 @@ fn test_zero_rewards_distribution() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_staking_hub::msg::ExecuteMsg::Unstake {},
+        chance_staking_hub::msg::ExecuteMsg::Unstake {
+            recipient: None,
+            min_inj_out: None,
+            deadline: None,
+        },
     )
     .unwrap();
 
     // Set contract balance = pending unstake total (50M), so surplus = 0
+    mock_hub_delegations(&mut deps);
     let mut env = mock_env();
     env.block.time = env.block.time.plus_seconds(86400);
     deps.querier.bank.update_balance(
@@ -3058,7 +3706,11 @@ fn test_exchange_rate_rounding_no_value_extraction() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_staking_hub::msg::ExecuteMsg::Stake {},
+        chance_staking_hub::msg::ExecuteMsg::Stake {
+            recipient: None,
+            min_csinj_out: None,
+            deadline: None,
+        },
     )
     .unwrap();
 
@@ -3099,7 +3751,11 @@ fn test_exchange_rate_rounding_no_value_extraction() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_staking_hub::msg::ExecuteMsg::Stake {},
+        chance_staking_hub::msg::ExecuteMsg::Stake {
+            recipient: None,
+            min_csinj_out: None,
+            deadline: None,
+        },
     )
     .unwrap();
 
@@ -3124,7 +3780,11 @@ fn test_exchange_rate_rounding_no_value_extraction() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_staking_hub::msg::ExecuteMsg::Unstake {},
+        chance_staking_hub::msg::ExecuteMsg::Unstake {
+            recipient: None,
+            min_inj_out: None,
+            deadline: None,
+        },
     )
     .unwrap();
 
@@ -3201,6 +3861,7 @@ fn test_concurrent_regular_and_big_draw() {
                     let info = chance_reward_distributor::msg::StakerInfoResponse {
                         address,
                         stake_epoch: Some(0),
+                        excluded: false,
                     };
                     SystemResult::Ok(ContractResult::Ok(to_json_binary(&info).unwrap()))
                 }
@@ -3326,10 +3987,12 @@ fn test_concurrent_regular_and_big_draw() {
             chance_reward_distributor::msg::ExecuteMsg::RevealDraw {
                 draw_id,
                 operator_secret_hex: hex::encode(secret),
-                winner_address: addr_a.clone(),
-                winner_cumulative_start: Uint128::zero(),
-                winner_cumulative_end: Uint128::from(100u128),
-                merkle_proof: vec![],
+                winners: vec![chance_reward_distributor::msg::SnapshotLeaf {
+                    address: addr_a.clone(),
+                    cumulative_start: Uint128::zero(),
+                    cumulative_end: Uint128::from(100u128),
+                    merkle_proof: vec![],
+                }],
             },
         );
         assert!(
@@ -3384,7 +4047,11 @@ fn test_double_claim_unstake_rejected() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_staking_hub::msg::ExecuteMsg::Stake {},
+        chance_staking_hub::msg::ExecuteMsg::Stake {
+            recipient: None,
+            min_csinj_out: None,
+            deadline: None,
+        },
     )
     .unwrap();
 
@@ -3395,13 +4062,17 @@ fn test_double_claim_unstake_rejected() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_staking_hub::msg::ExecuteMsg::Unstake {},
+        chance_staking_hub::msg::ExecuteMsg::Unstake {
+            recipient: None,
+            min_inj_out: None,
+            deadline: None,
+        },
     )
     .unwrap();
 
-    // Fast forward past unbonding
-    let mut env = mock_env();
-    env.block.time = Timestamp::from_seconds(env.block.time.seconds() + 22 * 24 * 60 * 60);
+    // Submit the batch and fast forward past unbonding
+    let mut env = submit_unbonding_batch(&mut deps);
+    env.block.time = env.block.time.plus_seconds(21 * 24 * 60 * 60);
 
     // Claim once — should succeed
     let user = deps.api.addr_make("user");
@@ -3412,6 +4083,7 @@ fn test_double_claim_unstake_rejected() {
         info,
         chance_staking_hub::msg::ExecuteMsg::ClaimUnstaked {
             request_ids: vec![0],
+            recipient: None,
         },
     )
     .unwrap();
@@ -3425,6 +4097,7 @@ fn test_double_claim_unstake_rejected() {
         info,
         chance_staking_hub::msg::ExecuteMsg::ClaimUnstaked {
             request_ids: vec![0],
+            recipient: None,
         },
     )
     .unwrap_err();
@@ -3460,7 +4133,11 @@ fn test_min_stake_amount_enforcement() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_staking_hub::msg::ExecuteMsg::Stake {},
+        chance_staking_hub::msg::ExecuteMsg::Stake {
+            recipient: None,
+            min_csinj_out: None,
+            deadline: None,
+        },
     )
     .unwrap_err();
     assert!(
@@ -3476,7 +4153,11 @@ fn test_min_stake_amount_enforcement() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_staking_hub::msg::ExecuteMsg::Stake {},
+        chance_staking_hub::msg::ExecuteMsg::Stake {
+            recipient: None,
+            min_csinj_out: None,
+            deadline: None,
+        },
     )
     .unwrap();
 
@@ -3487,7 +4168,11 @@ fn test_min_stake_amount_enforcement() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_staking_hub::msg::ExecuteMsg::Stake {},
+        chance_staking_hub::msg::ExecuteMsg::Stake {
+            recipient: None,
+            min_csinj_out: None,
+            deadline: None,
+        },
     )
     .unwrap();
 
@@ -3508,7 +4193,11 @@ fn test_min_stake_amount_update_via_config() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_staking_hub::msg::ExecuteMsg::Stake {},
+        chance_staking_hub::msg::ExecuteMsg::Stake {
+            recipient: None,
+            min_csinj_out: None,
+            deadline: None,
+        },
     )
     .unwrap();
 
@@ -3520,8 +4209,6 @@ fn test_min_stake_amount_update_via_config() {
         mock_env(),
         info,
         chance_staking_hub::msg::ExecuteMsg::UpdateConfig {
-            admin: None,
-            operator: None,
             protocol_fee_bps: None,
             base_yield_bps: None,
             regular_pool_bps: None,
@@ -3529,6 +4216,9 @@ fn test_min_stake_amount_update_via_config() {
             min_epochs_regular: None,
             min_epochs_big: None,
             min_stake_amount: Some(Uint128::new(500_000)),
+            snapshot_tolerance_bps: None,
+            keeper_fee_bps: None,
+            snapshot_grace_seconds: None,
         },
     )
     .unwrap();
@@ -3540,7 +4230,11 @@ fn test_min_stake_amount_update_via_config() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_staking_hub::msg::ExecuteMsg::Stake {},
+        chance_staking_hub::msg::ExecuteMsg::Stake {
+            recipient: None,
+            min_csinj_out: None,
+            deadline: None,
+        },
     )
     .unwrap_err();
     assert!(
@@ -3556,7 +4250,11 @@ fn test_min_stake_amount_update_via_config() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_staking_hub::msg::ExecuteMsg::Stake {},
+        chance_staking_hub::msg::ExecuteMsg::Stake {
+            recipient: None,
+            min_csinj_out: None,
+            deadline: None,
+        },
     )
     .unwrap();
 
@@ -3619,6 +4317,7 @@ fn setup_distributor_with_mocks(
                     let info = chance_reward_distributor::msg::StakerInfoResponse {
                         address,
                         stake_epoch,
+                        excluded: false,
                     };
                     SystemResult::Ok(ContractResult::Ok(to_json_binary(&info).unwrap()))
                 }
@@ -3638,6 +4337,7 @@ fn setup_distributor_with_mocks(
 
 /// Helper: commit a draw and reveal it, returning the draw_id.
 /// Assumes pool is already funded and snapshot is already set.
+#[allow(clippy::too_many_arguments)]
 fn commit_and_reveal_draw(
     deps: &mut OwnedDeps<cosmwasm_std::MemoryStorage, MockApi, MockQuerier>,
     epoch: u64,
@@ -3733,10 +4433,12 @@ fn commit_and_reveal_draw(
         chance_reward_distributor::msg::ExecuteMsg::RevealDraw {
             draw_id,
             operator_secret_hex: hex::encode(secret),
-            winner_address: winner_addr,
-            winner_cumulative_start: winner_start,
-            winner_cumulative_end: winner_end,
-            merkle_proof: proof,
+            winners: vec![chance_reward_distributor::msg::SnapshotLeaf {
+                address: winner_addr,
+                cumulative_start: winner_start,
+                cumulative_end: winner_end,
+                merkle_proof: proof,
+            }],
         },
     )
     .unwrap();
@@ -3761,7 +4463,11 @@ fn test_staking_hub_stake_error_paths() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_staking_hub::msg::ExecuteMsg::Stake {},
+        chance_staking_hub::msg::ExecuteMsg::Stake {
+            recipient: None,
+            min_csinj_out: None,
+            deadline: None,
+        },
     )
     .unwrap_err();
     assert!(
@@ -3783,7 +4489,11 @@ fn test_staking_hub_stake_error_paths() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_staking_hub::msg::ExecuteMsg::Stake {},
+        chance_staking_hub::msg::ExecuteMsg::Stake {
+            recipient: None,
+            min_csinj_out: None,
+            deadline: None,
+        },
     )
     .unwrap_err();
     assert!(
@@ -3799,7 +4509,11 @@ fn test_staking_hub_stake_error_paths() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_staking_hub::msg::ExecuteMsg::Stake {},
+        chance_staking_hub::msg::ExecuteMsg::Stake {
+            recipient: None,
+            min_csinj_out: None,
+            deadline: None,
+        },
     )
     .unwrap_err();
     assert!(
@@ -3833,7 +4547,11 @@ fn test_staking_hub_unstake_error_paths() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_staking_hub::msg::ExecuteMsg::Stake {},
+        chance_staking_hub::msg::ExecuteMsg::Stake {
+            recipient: None,
+            min_csinj_out: None,
+            deadline: None,
+        },
     )
     .unwrap();
 
@@ -3844,7 +4562,11 @@ fn test_staking_hub_unstake_error_paths() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_staking_hub::msg::ExecuteMsg::Unstake {},
+        chance_staking_hub::msg::ExecuteMsg::Unstake {
+            recipient: None,
+            min_inj_out: None,
+            deadline: None,
+        },
     )
     .unwrap_err();
     assert!(
@@ -3860,7 +4582,11 @@ fn test_staking_hub_unstake_error_paths() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_staking_hub::msg::ExecuteMsg::Unstake {},
+        chance_staking_hub::msg::ExecuteMsg::Unstake {
+            recipient: None,
+            min_inj_out: None,
+            deadline: None,
+        },
     )
     .unwrap_err();
     assert!(
@@ -3880,7 +4606,11 @@ fn test_staking_hub_unstake_error_paths() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_staking_hub::msg::ExecuteMsg::Unstake {},
+        chance_staking_hub::msg::ExecuteMsg::Unstake {
+            recipient: None,
+            min_inj_out: None,
+            deadline: None,
+        },
     )
     .unwrap_err();
     assert!(
@@ -3907,6 +4637,7 @@ fn test_staking_hub_claim_unstaked_error_paths() {
         info,
         chance_staking_hub::msg::ExecuteMsg::ClaimUnstaked {
             request_ids: vec![99],
+            recipient: None,
         },
     )
     .unwrap_err();
@@ -4025,7 +4756,19 @@ fn test_staking_hub_take_snapshot_invalid_merkle_root() {
         err
     );
 
-    // 3. Valid 64-char hex root → success
+    // 3. Valid 64-char hex root → success (the weight must match the csINJ supply)
+    let user = deps.api.addr_make("user");
+    chance_staking_hub::contract::execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user, &[Coin::new(1000u128, "inj")]),
+        chance_staking_hub::msg::ExecuteMsg::Stake {
+            recipient: None,
+            min_csinj_out: None,
+            deadline: None,
+        },
+    )
+    .unwrap();
     let operator = deps.api.addr_make("operator");
     let info = message_info(&operator, &[]);
     chance_staking_hub::contract::execute(
@@ -4050,24 +4793,24 @@ fn test_staking_hub_update_config_and_validators() {
     setup_hub(&mut deps);
 
     let admin = deps.api.addr_make("admin");
-    let new_operator = deps.api.addr_make("new_operator");
 
-    // 1. UpdateConfig: change only operator, verify only operator changed
+    // 1. UpdateConfig: change only min_stake_amount, verify only it changed
     let info = message_info(&admin, &[]);
     chance_staking_hub::contract::execute(
         deps.as_mut(),
         mock_env(),
         info,
         chance_staking_hub::msg::ExecuteMsg::UpdateConfig {
-            admin: None,
-            operator: Some(new_operator.to_string()),
             protocol_fee_bps: None,
             base_yield_bps: None,
             regular_pool_bps: None,
             big_pool_bps: None,
             min_epochs_regular: None,
             min_epochs_big: None,
-            min_stake_amount: None,
+            min_stake_amount: Some(Uint128::new(5_000)),
+            snapshot_tolerance_bps: None,
+            keeper_fee_bps: None,
+            snapshot_grace_seconds: None,
         },
     )
     .unwrap();
@@ -4081,7 +4824,8 @@ fn test_staking_hub_update_config_and_validators() {
         .unwrap(),
     )
     .unwrap();
-    assert_eq!(config.operator, new_operator);
+    assert_eq!(config.min_stake_amount, Uint128::new(5_000));
+    assert_eq!(config.operator, deps.api.addr_make("operator")); // unchanged
     assert_eq!(config.admin, admin); // unchanged
     assert_eq!(config.protocol_fee_bps, 500); // unchanged
 
@@ -4093,8 +4837,6 @@ fn test_staking_hub_update_config_and_validators() {
         mock_env(),
         info,
         chance_staking_hub::msg::ExecuteMsg::UpdateConfig {
-            admin: None,
-            operator: None,
             protocol_fee_bps: Some(10001),
             base_yield_bps: None,
             regular_pool_bps: None,
@@ -4102,6 +4844,9 @@ fn test_staking_hub_update_config_and_validators() {
             min_epochs_regular: None,
             min_epochs_big: None,
             min_stake_amount: None,
+            snapshot_tolerance_bps: None,
+            keeper_fee_bps: None,
+            snapshot_grace_seconds: None,
         },
     )
     .unwrap_err();
@@ -4119,8 +4864,6 @@ fn test_staking_hub_update_config_and_validators() {
         mock_env(),
         info,
         chance_staking_hub::msg::ExecuteMsg::UpdateConfig {
-            admin: None,
-            operator: None,
             protocol_fee_bps: None,
             base_yield_bps: None,
             regular_pool_bps: None,
@@ -4128,6 +4871,9 @@ fn test_staking_hub_update_config_and_validators() {
             min_epochs_regular: None,
             min_epochs_big: None,
             min_stake_amount: None,
+            snapshot_tolerance_bps: None,
+            keeper_fee_bps: None,
+            snapshot_grace_seconds: None,
         },
     )
     .unwrap_err();
@@ -4147,6 +4893,7 @@ fn test_staking_hub_update_config_and_validators() {
         chance_staking_hub::msg::ExecuteMsg::UpdateValidators {
             add: vec!["injvaloper1newvalidatoraddressforintegration".to_string()],
             remove: vec![],
+            weights: None,
         },
     )
     .unwrap();
@@ -4172,6 +4919,7 @@ fn test_staking_hub_update_config_and_validators() {
         chance_staking_hub::msg::ExecuteMsg::UpdateValidators {
             add: vec!["injvaloper1anothervalidatoraddresstest1234".to_string()],
             remove: vec![],
+            weights: None,
         },
     )
     .unwrap_err();
@@ -4191,6 +4939,7 @@ fn test_staking_hub_update_config_and_validators() {
         chance_staking_hub::msg::ExecuteMsg::UpdateValidators {
             add: vec!["cosmos1invalidaddress".to_string()],
             remove: vec![],
+            weights: None,
         },
     )
     .unwrap_err();
@@ -4214,6 +4963,7 @@ fn test_staking_hub_update_config_and_validators() {
                 "injvaloper1xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx".to_string(),
                 "injvaloper1newvalidatoraddressforintegration".to_string(),
             ],
+            weights: None,
         },
     )
     .unwrap_err();
@@ -4480,10 +5230,12 @@ fn test_distributor_reveal_draw_error_paths() {
         chance_reward_distributor::msg::ExecuteMsg::RevealDraw {
             draw_id: 0,
             operator_secret_hex: hex::encode(secret),
-            winner_address: winner.to_string(),
-            winner_cumulative_start: Uint128::zero(),
-            winner_cumulative_end: Uint128::from(1000u128),
-            merkle_proof: vec![],
+            winners: vec![chance_reward_distributor::msg::SnapshotLeaf {
+                address: winner.to_string(),
+                cumulative_start: Uint128::zero(),
+                cumulative_end: Uint128::from(1000u128),
+                merkle_proof: vec![],
+            }],
         },
     )
     .unwrap_err();
@@ -4517,10 +5269,12 @@ fn test_distributor_reveal_draw_error_paths() {
         chance_reward_distributor::msg::ExecuteMsg::RevealDraw {
             draw_id: 0,
             operator_secret_hex: hex::encode(secret),
-            winner_address: winner.to_string(),
-            winner_cumulative_start: Uint128::zero(),
-            winner_cumulative_end: Uint128::from(1000u128),
-            merkle_proof: vec![],
+            winners: vec![chance_reward_distributor::msg::SnapshotLeaf {
+                address: winner.to_string(),
+                cumulative_start: Uint128::zero(),
+                cumulative_end: Uint128::from(1000u128),
+                merkle_proof: vec![],
+            }],
         },
     )
     .unwrap_err();
@@ -4589,10 +5343,12 @@ fn test_distributor_reveal_draw_error_paths() {
         chance_reward_distributor::msg::ExecuteMsg::RevealDraw {
             draw_id: 1,
             operator_secret_hex: hex::encode(b"wrong_secret_completely"),
-            winner_address: winner.to_string(),
-            winner_cumulative_start: Uint128::zero(),
-            winner_cumulative_end: Uint128::from(1000u128),
-            merkle_proof: vec![],
+            winners: vec![chance_reward_distributor::msg::SnapshotLeaf {
+                address: winner.to_string(),
+                cumulative_start: Uint128::zero(),
+                cumulative_end: Uint128::from(1000u128),
+                merkle_proof: vec![],
+            }],
         },
     )
     .unwrap_err();
@@ -4697,10 +5453,12 @@ fn test_distributor_reveal_draw_error_paths() {
         chance_reward_distributor::msg::ExecuteMsg::RevealDraw {
             draw_id: 2,
             operator_secret_hex: hex::encode(secret3),
-            winner_address: addr_a.clone(),
-            winner_cumulative_start: Uint128::zero(),
-            winner_cumulative_end: Uint128::from(1000u128),
-            merkle_proof: vec![],
+            winners: vec![chance_reward_distributor::msg::SnapshotLeaf {
+                address: addr_a.clone(),
+                cumulative_start: Uint128::zero(),
+                cumulative_end: Uint128::from(1000u128),
+                merkle_proof: vec![],
+            }],
         },
     )
     .unwrap_err();
@@ -4728,11 +5486,13 @@ fn test_distributor_update_config_errors() {
         mock_env(),
         info,
         chance_reward_distributor::msg::ExecuteMsg::UpdateConfig {
-            operator: None,
             staking_hub: None,
             reveal_deadline_seconds: None,
             epochs_between_regular: None,
             epochs_between_big: None,
+            challenge_period_seconds: None,
+            regular_prize_tiers: None,
+            big_prize_tiers: None,
         },
     )
     .unwrap_err();
@@ -4749,11 +5509,13 @@ fn test_distributor_update_config_errors() {
         mock_env(),
         info,
         chance_reward_distributor::msg::ExecuteMsg::UpdateConfig {
-            operator: None,
             staking_hub: None,
             reveal_deadline_seconds: Some(100),
             epochs_between_regular: None,
             epochs_between_big: None,
+            challenge_period_seconds: None,
+            regular_prize_tiers: None,
+            big_prize_tiers: None,
         },
     )
     .unwrap_err();
@@ -4771,11 +5533,13 @@ fn test_distributor_update_config_errors() {
         mock_env(),
         info,
         chance_reward_distributor::msg::ExecuteMsg::UpdateConfig {
-            operator: None,
             staking_hub: None,
             reveal_deadline_seconds: Some(100_000),
             epochs_between_regular: None,
             epochs_between_big: None,
+            challenge_period_seconds: None,
+            regular_prize_tiers: None,
+            big_prize_tiers: None,
         },
     )
     .unwrap_err();
@@ -4787,7 +5551,6 @@ fn test_distributor_update_config_errors() {
 
     // 4. Valid update: all fields
     let admin = deps.api.addr_make("admin");
-    let new_operator = deps.api.addr_make("new_op");
     let new_hub = deps.api.addr_make("new_hub");
     let info = message_info(&admin, &[]);
     chance_reward_distributor::contract::execute(
@@ -4795,11 +5558,13 @@ fn test_distributor_update_config_errors() {
         mock_env(),
         info,
         chance_reward_distributor::msg::ExecuteMsg::UpdateConfig {
-            operator: Some(new_operator.to_string()),
             staking_hub: Some(new_hub.to_string()),
             reveal_deadline_seconds: Some(7200),
             epochs_between_regular: Some(2),
             epochs_between_big: Some(14),
+            challenge_period_seconds: None,
+            regular_prize_tiers: None,
+            big_prize_tiers: None,
         },
     )
     .unwrap();
@@ -4814,7 +5579,6 @@ fn test_distributor_update_config_errors() {
         .unwrap(),
     )
     .unwrap();
-    assert_eq!(config.operator, new_operator);
     assert_eq!(config.staking_hub, new_hub);
     assert_eq!(config.reveal_deadline_seconds, 7200);
     assert_eq!(config.epochs_between_regular, 2);
@@ -5082,7 +5846,11 @@ fn test_staking_hub_staker_info_and_unstake_pagination() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_staking_hub::msg::ExecuteMsg::Stake {},
+        chance_staking_hub::msg::ExecuteMsg::Stake {
+            recipient: None,
+            min_csinj_out: None,
+            deadline: None,
+        },
     )
     .unwrap();
 
@@ -5107,7 +5875,11 @@ fn test_staking_hub_staker_info_and_unstake_pagination() {
             deps.as_mut(),
             mock_env(),
             info,
-            chance_staking_hub::msg::ExecuteMsg::Unstake {},
+            chance_staking_hub::msg::ExecuteMsg::Unstake {
+                recipient: None,
+                min_inj_out: None,
+                deadline: None,
+            },
         )
         .unwrap();
     }
@@ -5283,7 +6055,7 @@ fn test_audit_edge_cases() {
         );
     }
 
-    // --- Re-staking resets eligibility (V2-I-01) ---
+    // --- Re-staking blends eligibility by balance (V2-I-01) ---
     {
         let mut deps = mock_dependencies();
         setup_hub(&mut deps);
@@ -5296,7 +6068,11 @@ fn test_audit_edge_cases() {
             deps.as_mut(),
             mock_env(),
             info,
-            chance_staking_hub::msg::ExecuteMsg::Stake {},
+            chance_staking_hub::msg::ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
         )
         .unwrap();
 
@@ -5328,14 +6104,26 @@ fn test_audit_edge_cases() {
             .save(deps.as_mut().storage, &epoch_state)
             .unwrap();
 
-        // Re-stake → epoch resets to 5
+        // Re-stake 1 INJ on top of 100 INJ held since epoch 1 → epoch moves to
+        // ceil((100M * 1 + 1M * 5) / 101M) = 2 rather than resetting to 5
         let user = deps.api.addr_make("user");
+        let csinj_denom = chance_staking_hub::state::CONFIG
+            .load(deps.as_ref().storage)
+            .unwrap()
+            .csinj_denom;
+        deps.querier
+            .bank
+            .update_balance(&user, vec![Coin::new(100_000_000u128, &csinj_denom)]);
         let info = message_info(&user, &[Coin::new(1_000_000u128, "inj")]);
         chance_staking_hub::contract::execute(
             deps.as_mut(),
             mock_env(),
             info,
-            chance_staking_hub::msg::ExecuteMsg::Stake {},
+            chance_staking_hub::msg::ExecuteMsg::Stake {
+                recipient: None,
+                min_csinj_out: None,
+                deadline: None,
+            },
         )
         .unwrap();
 
@@ -5352,8 +6140,8 @@ fn test_audit_edge_cases() {
         .unwrap();
         assert_eq!(
             staker_info.stake_epoch,
-            Some(5),
-            "Re-staking should reset stake_epoch to current epoch"
+            Some(2),
+            "Re-staking should move stake_epoch in proportion to the amount added"
         );
     }
 
@@ -5555,7 +6343,7 @@ fn test_oracle_integration_coverage() {
         err
     );
 
-    // 3. UpdateAdmin: rotate admin
+    // 3. ProposeAdmin + AcceptAdmin: rotate admin
     let admin = deps.api.addr_make("admin");
     let new_admin = deps.api.addr_make("new_admin");
     let info = message_info(&admin, &[]);
@@ -5563,11 +6351,20 @@ fn test_oracle_integration_coverage() {
         deps.as_mut(),
         mock_env(),
         info,
-        chance_drand_oracle::msg::ExecuteMsg::UpdateAdmin {
+        chance_drand_oracle::msg::ExecuteMsg::ProposeAdmin {
             new_admin: new_admin.to_string(),
+            expires_in_seconds: None,
         },
     )
     .unwrap();
+    let info = message_info(&new_admin, &[]);
+    chance_drand_oracle::contract::execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        chance_drand_oracle::msg::ExecuteMsg::AcceptAdmin {},
+    )
+    .unwrap();
 
     // Old admin locked out
     let old_admin = deps.api.addr_make("admin");
@@ -5622,3 +6419,1167 @@ fn test_oracle_integration_coverage() {
 
     eprintln!("test_oracle_integration_coverage passed");
 }
+
+#[test]
+fn test_snapshot_challenge_window() {
+    use chance_reward_distributor::msg::{
+        ExecuteMsg, QueryMsg, SnapshotChallenge, SnapshotLeaf, StakerInfoResponse,
+        StakingHubConfigResponse, StakingHubQueryMsg, TwabResponse,
+    };
+    use chance_reward_distributor::state::SnapshotDispute;
+
+    let mut dist_deps = mock_dependencies();
+    let addr_a = dist_deps.api.addr_make("user_a").to_string();
+    let addr_b = dist_deps.api.addr_make("user_b").to_string();
+
+    // user_a and the excluded pool kept at least 400 csINJ over every snapshot window,
+    // nobody else held any
+    let holder = addr_a.clone();
+    let pool = dist_deps.api.addr_make("dex_pool").to_string();
+    let excluded_pool = pool.clone();
+    dist_deps.querier.update_wasm(move |query| match query {
+        WasmQuery::Smart { msg, .. } => match from_json(msg) {
+            Ok(StakingHubQueryMsg::TwabBetween { address, .. }) => {
+                let min_balance = if address == holder || address == excluded_pool {
+                    400u128
+                } else {
+                    0
+                };
+                let twab = TwabResponse {
+                    address,
+                    min_balance: Uint128::from(min_balance),
+                };
+                SystemResult::Ok(ContractResult::Ok(to_json_binary(&twab).unwrap()))
+            }
+            Ok(StakingHubQueryMsg::Config {}) => {
+                let config = StakingHubConfigResponse {
+                    min_epochs_regular: 0,
+                    min_epochs_big: 0,
+                };
+                SystemResult::Ok(ContractResult::Ok(to_json_binary(&config).unwrap()))
+            }
+            Ok(StakingHubQueryMsg::StakerInfo { address }) => {
+                let info = StakerInfoResponse {
+                    excluded: address == excluded_pool,
+                    address,
+                    stake_epoch: Some(0),
+                };
+                SystemResult::Ok(ContractResult::Ok(to_json_binary(&info).unwrap()))
+            }
+            _ => SystemResult::Err(cosmwasm_std::SystemError::InvalidRequest {
+                error: "unexpected query".to_string(),
+                request: Default::default(),
+            }),
+        },
+        _ => SystemResult::Err(cosmwasm_std::SystemError::InvalidRequest {
+            error: "Only smart queries supported".to_string(),
+            request: Default::default(),
+        }),
+    });
+
+    let admin = dist_deps.api.addr_make("admin");
+    let mut msg = distributor_instantiate_msg();
+    msg.challenge_period_seconds = Some(600);
+    chance_reward_distributor::contract::instantiate(
+        dist_deps.as_mut(),
+        mock_env(),
+        message_info(&admin, &[]),
+        msg,
+    )
+    .unwrap();
+
+    let staking_hub = dist_deps.api.addr_make("staking_hub");
+    let operator = dist_deps.api.addr_make("operator");
+    let user_a = dist_deps.api.addr_make("user_a");
+    let anyone = dist_deps.api.addr_make("anyone");
+    let t0 = mock_env().block.time;
+    let env_at = |seconds: u64| {
+        let mut env = mock_env();
+        env.block.time = t0.plus_seconds(seconds);
+        env
+    };
+    let two_leaf_tree = |split: u128| {
+        let leaf_a = compute_leaf_hash(&addr_a, 0, split);
+        let leaf_b = compute_leaf_hash(&addr_b, 500, 1000);
+        let root_hex = hex::encode(sorted_hash(&leaf_a, &leaf_b));
+        let a = SnapshotLeaf {
+            address: addr_a.clone(),
+            cumulative_start: Uint128::zero(),
+            cumulative_end: Uint128::from(split),
+            merkle_proof: vec![hex::encode(leaf_b)],
+        };
+        let b = SnapshotLeaf {
+            address: addr_b.clone(),
+            cumulative_start: Uint128::from(500u128),
+            cumulative_end: Uint128::from(1000u128),
+            merkle_proof: vec![hex::encode(leaf_a)],
+        };
+        (root_hex, a, b)
+    };
+
+    chance_reward_distributor::contract::execute(
+        dist_deps.as_mut(),
+        mock_env(),
+        message_info(&staking_hub, &[Coin::new(10_000_000u128, "inj")]),
+        ExecuteMsg::FundRegularPool {},
+    )
+    .unwrap();
+
+    // ── Epoch 1: a correct snapshot ──
+    let (root_1, leaf_a, leaf_b) = two_leaf_tree(500);
+    chance_reward_distributor::contract::execute(
+        dist_deps.as_mut(),
+        mock_env(),
+        message_info(&staking_hub, &[]),
+        ExecuteMsg::SetSnapshot {
+            epoch: 1,
+            merkle_root: root_1,
+            total_weight: Uint128::from(1000u128),
+            num_holders: 2,
+        },
+    )
+    .unwrap();
+
+    let commit: [u8; 32] = Sha256::digest(b"secret").into();
+    let commit_msg = |epoch: u64| ExecuteMsg::CommitDraw {
+        draw_type: chance_staking_common::types::DrawType::Regular,
+        operator_commit: hex::encode(commit),
+        target_drand_round: TEST_ROUND,
+        epoch,
+    };
+    let err = chance_reward_distributor::contract::execute(
+        dist_deps.as_mut(),
+        mock_env(),
+        message_info(&operator, &[]),
+        commit_msg(1),
+    )
+    .unwrap_err();
+    assert!(
+        format!("{:?}", err).contains("ChallengeWindowOpen"),
+        "Expected ChallengeWindowOpen, got: {:?}",
+        err
+    );
+
+    // Honest leaves don't overlap and aren't underweight
+    let challenge = |challenge: SnapshotChallenge| ExecuteMsg::ChallengeSnapshot {
+        epoch: 1,
+        challenge,
+    };
+    for bad_challenge in [
+        SnapshotChallenge::OverlappingLeaves {
+            first: leaf_a.clone(),
+            second: leaf_b.clone(),
+        },
+        SnapshotChallenge::UnderweightLeaf {
+            leaf: leaf_a.clone(),
+        },
+    ] {
+        let err = chance_reward_distributor::contract::execute(
+            dist_deps.as_mut(),
+            mock_env(),
+            message_info(&anyone, &[]),
+            challenge(bad_challenge),
+        )
+        .unwrap_err();
+        assert!(
+            format!("{:?}", err).contains("ChallengeRejected"),
+            "Expected ChallengeRejected, got: {:?}",
+            err
+        );
+    }
+
+    // Neither a non-holder nor an excluded holder can claim a missing leaf
+    for sender in [anyone.clone(), cosmwasm_std::Addr::unchecked(pool)] {
+        let err = chance_reward_distributor::contract::execute(
+            dist_deps.as_mut(),
+            mock_env(),
+            message_info(&sender, &[]),
+            challenge(SnapshotChallenge::MissingLeaf {}),
+        )
+        .unwrap_err();
+        assert!(format!("{:?}", err).contains("ChallengeRejected"));
+    }
+
+    // user_a disputes their leaf; draws are blocked until it is answered
+    chance_reward_distributor::contract::execute(
+        dist_deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        challenge(SnapshotChallenge::MissingLeaf {}),
+    )
+    .unwrap();
+    let disputes: Vec<SnapshotDispute> = from_json(
+        chance_reward_distributor::contract::query(
+            dist_deps.as_ref(),
+            mock_env(),
+            QueryMsg::SnapshotDisputes {
+                epoch: 1,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(disputes.len(), 1);
+    assert_eq!(disputes[0].min_balance, Uint128::from(400u128));
+
+    let err = chance_reward_distributor::contract::execute(
+        dist_deps.as_mut(),
+        env_at(600),
+        message_info(&anyone, &[]),
+        challenge(SnapshotChallenge::MissingLeaf {}),
+    )
+    .unwrap_err();
+    assert!(format!("{:?}", err).contains("ChallengeWindowClosed"));
+    let err = chance_reward_distributor::contract::execute(
+        dist_deps.as_mut(),
+        env_at(600),
+        message_info(&operator, &[]),
+        commit_msg(1),
+    )
+    .unwrap_err();
+    assert!(
+        format!("{:?}", err).contains("SnapshotDisputed"),
+        "Expected SnapshotDisputed, got: {:?}",
+        err
+    );
+
+    // Anyone can answer with the disputer's leaf, after which the draw goes ahead
+    chance_reward_distributor::contract::execute(
+        dist_deps.as_mut(),
+        env_at(600),
+        message_info(&anyone, &[]),
+        ExecuteMsg::AnswerDispute {
+            epoch: 1,
+            leaf: leaf_a,
+        },
+    )
+    .unwrap();
+    chance_reward_distributor::contract::execute(
+        dist_deps.as_mut(),
+        env_at(600),
+        message_info(&operator, &[]),
+        commit_msg(1),
+    )
+    .unwrap();
+
+    // ── Epochs 2 and 3: overlapping and underweight leaves invalidate the snapshot ──
+    for (epoch, split, expected_reason) in [
+        (2u64, 600u128, "overlapping_leaves"),
+        (3, 300, "underweight_leaf"),
+    ] {
+        let (root, leaf_a, leaf_b) = two_leaf_tree(split);
+        let submitted = 1000 * epoch;
+        chance_reward_distributor::contract::execute(
+            dist_deps.as_mut(),
+            env_at(submitted),
+            message_info(&staking_hub, &[]),
+            ExecuteMsg::SetSnapshot {
+                epoch,
+                merkle_root: root,
+                total_weight: Uint128::from(1000u128),
+                num_holders: 2,
+            },
+        )
+        .unwrap();
+
+        let proof = if expected_reason == "overlapping_leaves" {
+            SnapshotChallenge::OverlappingLeaves {
+                first: leaf_a,
+                second: leaf_b,
+            }
+        } else {
+            SnapshotChallenge::UnderweightLeaf { leaf: leaf_a }
+        };
+        let res = chance_reward_distributor::contract::execute(
+            dist_deps.as_mut(),
+            env_at(submitted + 10),
+            message_info(&anyone, &[]),
+            ExecuteMsg::ChallengeSnapshot {
+                epoch,
+                challenge: proof,
+            },
+        )
+        .unwrap();
+        let event = res
+            .events
+            .iter()
+            .find(|e| e.ty == "chance_snapshot_invalidated")
+            .unwrap();
+        assert!(event
+            .attributes
+            .iter()
+            .any(|a| a.key == "reason" && a.value == expected_reason));
+
+        let err = chance_reward_distributor::contract::execute(
+            dist_deps.as_mut(),
+            env_at(submitted + 600),
+            message_info(&operator, &[]),
+            commit_msg(epoch),
+        )
+        .unwrap_err();
+        assert!(
+            format!("{:?}", err).contains("SnapshotInvalidated"),
+            "Expected SnapshotInvalidated, got: {:?}",
+            err
+        );
+    }
+
+    // ── Epoch 4: the admin resolves disputes nobody answers ──
+    let (root_4, _, _) = two_leaf_tree(500);
+    chance_reward_distributor::contract::execute(
+        dist_deps.as_mut(),
+        env_at(4000),
+        message_info(&staking_hub, &[]),
+        ExecuteMsg::SetSnapshot {
+            epoch: 4,
+            merkle_root: root_4,
+            total_weight: Uint128::from(1000u128),
+            num_holders: 2,
+        },
+    )
+    .unwrap();
+    let dispute_4 = ExecuteMsg::ChallengeSnapshot {
+        epoch: 4,
+        challenge: SnapshotChallenge::MissingLeaf {},
+    };
+    let open_disputes = |deps: &cosmwasm_std::OwnedDeps<_, _, _>| {
+        let disputes: Vec<SnapshotDispute> = from_json(
+            chance_reward_distributor::contract::query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::SnapshotDisputes {
+                    epoch: 4,
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        disputes.len()
+    };
+    chance_reward_distributor::contract::execute(
+        dist_deps.as_mut(),
+        env_at(4010),
+        message_info(&user_a, &[]),
+        dispute_4.clone(),
+    )
+    .unwrap();
+
+    // Only the admin can dismiss a dispute
+    let dismiss = ExecuteMsg::DismissDispute {
+        epoch: 4,
+        disputer: user_a.to_string(),
+    };
+    let err = chance_reward_distributor::contract::execute(
+        dist_deps.as_mut(),
+        env_at(4020),
+        message_info(&anyone, &[]),
+        dismiss.clone(),
+    )
+    .unwrap_err();
+    assert!(format!("{:?}", err).contains("Unauthorized"));
+    chance_reward_distributor::contract::execute(
+        dist_deps.as_mut(),
+        env_at(4020),
+        message_info(&admin, &[]),
+        dismiss,
+    )
+    .unwrap();
+    assert_eq!(open_disputes(&dist_deps), 0);
+
+    // Invalidating the snapshot closes its disputes and keeps draws off it
+    chance_reward_distributor::contract::execute(
+        dist_deps.as_mut(),
+        env_at(4030),
+        message_info(&user_a, &[]),
+        dispute_4,
+    )
+    .unwrap();
+    let res = chance_reward_distributor::contract::execute(
+        dist_deps.as_mut(),
+        env_at(4040),
+        message_info(&admin, &[]),
+        ExecuteMsg::InvalidateSnapshot { epoch: 4 },
+    )
+    .unwrap();
+    assert!(res
+        .attributes
+        .iter()
+        .any(|a| a.key == "disputes_closed" && a.value == "1"));
+    assert_eq!(open_disputes(&dist_deps), 0);
+    let err = chance_reward_distributor::contract::execute(
+        dist_deps.as_mut(),
+        env_at(4600),
+        message_info(&operator, &[]),
+        commit_msg(4),
+    )
+    .unwrap_err();
+    assert!(
+        format!("{:?}", err).contains("SnapshotInvalidated"),
+        "Expected SnapshotInvalidated, got: {:?}",
+        err
+    );
+
+    eprintln!("test_snapshot_challenge_window passed");
+}
+
+#[test]
+fn test_invalidated_snapshot_retaken() {
+    // A snapshot the distributor invalidates can be taken again on the hub, and the
+    // replacement gets a fresh challenge window before draws can use it.
+    use chance_reward_distributor::msg::ExecuteMsg as DistributorMsg;
+    use chance_staking_hub::msg::{DistributorConfigResponse, DistributorQueryMsg};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let mut hub_deps = mock_dependencies();
+    setup_hub(&mut hub_deps);
+    let mut dist_deps = mock_dependencies();
+    setup_distributor(&mut dist_deps);
+
+    // The hub reads the distributor's snapshot through this response
+    let dist_snapshot = Rc::new(RefCell::new(to_json_binary(&None::<()>).unwrap()));
+    let snapshot_response = dist_snapshot.clone();
+    hub_deps.querier.update_wasm(move |query| match query {
+        WasmQuery::Smart { msg, .. } => match from_json(msg).unwrap() {
+            DistributorQueryMsg::Config {} => SystemResult::Ok(ContractResult::Ok(
+                to_json_binary(&DistributorConfigResponse {
+                    challenge_period_seconds: 600,
+                })
+                .unwrap(),
+            )),
+            DistributorQueryMsg::Snapshot { .. } => {
+                SystemResult::Ok(ContractResult::Ok(snapshot_response.borrow().clone()))
+            }
+        },
+        _ => SystemResult::Err(cosmwasm_std::SystemError::InvalidRequest {
+            error: "Only smart queries supported".to_string(),
+            request: Default::default(),
+        }),
+    });
+    let sync_snapshot = |dist_deps: &OwnedDeps<_, _, _>| {
+        *dist_snapshot.borrow_mut() = chance_reward_distributor::contract::query(
+            dist_deps.as_ref(),
+            mock_env(),
+            chance_reward_distributor::msg::QueryMsg::Snapshot { epoch: 1 },
+        )
+        .unwrap();
+    };
+
+    let user = hub_deps.api.addr_make("user1");
+    chance_staking_hub::contract::execute(
+        hub_deps.as_mut(),
+        mock_env(),
+        message_info(&user, &[Coin::new(100_000_000u128, "inj")]),
+        chance_staking_hub::msg::ExecuteMsg::Stake {
+            recipient: None,
+            min_csinj_out: None,
+            deadline: None,
+        },
+    )
+    .unwrap();
+    let staking_hub = dist_deps.api.addr_make("staking_hub");
+    chance_reward_distributor::contract::execute(
+        dist_deps.as_mut(),
+        mock_env(),
+        message_info(&staking_hub, &[Coin::new(50_000_000u128, "inj")]),
+        DistributorMsg::FundRegularPool {},
+    )
+    .unwrap();
+
+    // Take a snapshot on the hub and deliver its SetSnapshot to the distributor
+    let operator = hub_deps.api.addr_make("operator");
+    let take_snapshot = |hub_deps: &mut OwnedDeps<_, _, _>,
+                         dist_deps: &mut OwnedDeps<_, _, _>,
+                         merkle_root: &str| {
+        let res = chance_staking_hub::contract::execute(
+            hub_deps.as_mut(),
+            mock_env(),
+            message_info(&operator, &[]),
+            chance_staking_hub::msg::ExecuteMsg::TakeSnapshot {
+                merkle_root: merkle_root.repeat(32),
+                total_weight: Uint128::from(100_000_000u128),
+                num_holders: 1,
+                snapshot_uri: "ipfs://snapshot".to_string(),
+            },
+        )?;
+        let cosmwasm_std::CosmosMsg::Wasm(cosmwasm_std::WasmMsg::Execute { msg, .. }) =
+            &res.messages[0].msg
+        else {
+            panic!("expected the SetSnapshot message");
+        };
+        chance_reward_distributor::contract::execute(
+            dist_deps.as_mut(),
+            mock_env(),
+            message_info(&staking_hub, &[]),
+            from_json::<DistributorMsg>(msg).unwrap(),
+        )
+        .unwrap();
+        Ok::<_, chance_staking_hub::error::ContractError>(res)
+    };
+
+    take_snapshot(&mut hub_deps, &mut dist_deps, "aa").unwrap();
+    sync_snapshot(&dist_deps);
+
+    // A valid snapshot can't be replaced
+    let err = take_snapshot(&mut hub_deps, &mut dist_deps, "bb").unwrap_err();
+    assert!(matches!(
+        err,
+        chance_staking_hub::error::ContractError::SnapshotAlreadyTaken
+    ));
+
+    // Invalidated on the distributor, it blocks draws until the hub retakes it
+    let admin = dist_deps.api.addr_make("admin");
+    chance_reward_distributor::contract::execute(
+        dist_deps.as_mut(),
+        mock_env(),
+        message_info(&admin, &[]),
+        DistributorMsg::InvalidateSnapshot { epoch: 1 },
+    )
+    .unwrap();
+    sync_snapshot(&dist_deps);
+    let commit = DistributorMsg::CommitDraw {
+        draw_type: chance_staking_common::types::DrawType::Regular,
+        operator_commit: hex::encode(Sha256::digest(b"retake_secret")),
+        target_drand_round: TEST_ROUND,
+        epoch: 1,
+    };
+    let err = chance_reward_distributor::contract::execute(
+        dist_deps.as_mut(),
+        mock_env(),
+        message_info(&operator, &[]),
+        commit.clone(),
+    )
+    .unwrap_err();
+    assert!(matches!(
+        err,
+        chance_reward_distributor::error::ContractError::SnapshotInvalidated { epoch: 1 }
+    ));
+
+    let res = take_snapshot(&mut hub_deps, &mut dist_deps, "bb").unwrap();
+    assert!(res.events.iter().any(|e| e.ty == "chance_snapshot_taken"
+        && e.attributes
+            .iter()
+            .any(|a| a.key == "retaken" && a.value == "true")));
+    let snapshot: Option<chance_reward_distributor::state::Snapshot> = from_json(
+        chance_reward_distributor::contract::query(
+            dist_deps.as_ref(),
+            mock_env(),
+            chance_reward_distributor::msg::QueryMsg::Snapshot { epoch: 1 },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    let snapshot = snapshot.unwrap();
+    assert_eq!(snapshot.merkle_root, "bb".repeat(32));
+    assert!(!snapshot.invalidated);
+
+    chance_reward_distributor::contract::execute(
+        dist_deps.as_mut(),
+        mock_env(),
+        message_info(&operator, &[]),
+        commit,
+    )
+    .unwrap();
+
+    eprintln!("test_invalidated_snapshot_retaken passed");
+}
+
+#[test]
+fn test_expected_value() {
+    use chance_reward_distributor::msg::{
+        ExecuteMsg, ExpectedValueResponse, QueryMsg, StakerInfoResponse, StakingHubConfigResponse,
+        StakingHubQueryMsg, TwabResponse,
+    };
+
+    let mut dist_deps = mock_dependencies();
+    let addr_a = dist_deps.api.addr_make("user_a").to_string();
+    let pool = dist_deps.api.addr_make("dex_pool").to_string();
+
+    // user_a held 250 csINJ throughout and joined in epoch 1; the excluded pool
+    // held just as much
+    let excluded_pool = pool.clone();
+    dist_deps.querier.update_wasm(move |query| match query {
+        WasmQuery::Smart { msg, .. } => match from_json(msg) {
+            Ok(StakingHubQueryMsg::TwabBetween { address, .. }) => {
+                let twab = TwabResponse {
+                    address,
+                    min_balance: Uint128::from(250u128),
+                };
+                SystemResult::Ok(ContractResult::Ok(to_json_binary(&twab).unwrap()))
+            }
+            Ok(StakingHubQueryMsg::Config {}) => {
+                let config = StakingHubConfigResponse {
+                    min_epochs_regular: 0,
+                    min_epochs_big: 1,
+                };
+                SystemResult::Ok(ContractResult::Ok(to_json_binary(&config).unwrap()))
+            }
+            Ok(StakingHubQueryMsg::StakerInfo { address }) => {
+                let info = StakerInfoResponse {
+                    excluded: address == excluded_pool,
+                    address,
+                    stake_epoch: Some(1),
+                };
+                SystemResult::Ok(ContractResult::Ok(to_json_binary(&info).unwrap()))
+            }
+            _ => SystemResult::Err(cosmwasm_std::SystemError::InvalidRequest {
+                error: "unexpected query".to_string(),
+                request: Default::default(),
+            }),
+        },
+        _ => SystemResult::Err(cosmwasm_std::SystemError::InvalidRequest {
+            error: "Only smart queries supported".to_string(),
+            request: Default::default(),
+        }),
+    });
+    setup_distributor(&mut dist_deps);
+
+    let expected_value = |deps: cosmwasm_std::Deps, address: &str| -> ExpectedValueResponse {
+        from_json(
+            chance_reward_distributor::contract::query(
+                deps,
+                mock_env(),
+                QueryMsg::ExpectedValue {
+                    address: address.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    };
+
+    // No snapshot yet
+    let ev = expected_value(dist_deps.as_ref(), &addr_a);
+    assert_eq!(ev.snapshot_epoch, None);
+    assert_eq!(ev.expected_per_epoch, Uint128::zero());
+
+    let staking_hub = dist_deps.api.addr_make("staking_hub");
+    for (msg, amount) in [
+        (ExecuteMsg::FundRegularPool {}, 70_000_000u128),
+        (ExecuteMsg::FundBigPool {}, 21_000_000u128),
+    ] {
+        chance_reward_distributor::contract::execute(
+            dist_deps.as_mut(),
+            mock_env(),
+            message_info(&staking_hub, &[Coin::new(amount, "inj")]),
+            msg,
+        )
+        .unwrap();
+    }
+    chance_reward_distributor::contract::execute(
+        dist_deps.as_mut(),
+        mock_env(),
+        message_info(&staking_hub, &[]),
+        ExecuteMsg::SetSnapshot {
+            epoch: 1,
+            merkle_root: hex::encode([1u8; 32]),
+            total_weight: Uint128::from(1000u128),
+            num_holders: 4,
+        },
+    )
+    .unwrap();
+
+    // 25% of the weight: a quarter of the regular pool every epoch, but not yet
+    // staked long enough for big draws
+    let ev = expected_value(dist_deps.as_ref(), &addr_a);
+    assert_eq!(ev.snapshot_epoch, Some(1));
+    assert_eq!(ev.weight, Uint128::from(250u128));
+    assert_eq!(ev.total_weight, Uint128::from(1000u128));
+    assert_eq!(ev.regular_win_probability, Decimal::percent(25));
+    assert_eq!(ev.big_win_probability, Decimal::zero());
+    assert_eq!(ev.regular_expected_per_epoch, Uint128::from(17_500_000u128));
+    assert_eq!(ev.big_expected_per_epoch, Uint128::zero());
+    assert_eq!(ev.expected_per_epoch, Uint128::from(17_500_000u128));
+
+    // Excluded addresses can't win anything
+    let ev = expected_value(dist_deps.as_ref(), &pool);
+    assert_eq!(ev.weight, Uint128::zero());
+    assert_eq!(ev.expected_per_epoch, Uint128::zero());
+
+    eprintln!("test_expected_value passed");
+}
+
+#[test]
+fn test_tiered_prize_draw() {
+    // A draw with a 1 x 50% + 5 x 10% prize table pays six prizes, each to the
+    // holder of its own derived ticket, with rounding dust on the first prize.
+    use chance_reward_distributor::msg::{ExecuteMsg, QueryMsg, SnapshotLeaf};
+    use chance_reward_distributor::state::PrizeTier;
+
+    let mut deps = mock_dependencies();
+    setup_distributor_with_mocks(&mut deps, 0, 0, Some(0));
+
+    let admin = deps.api.addr_make("admin");
+    let update = |tiers: Vec<PrizeTier>| ExecuteMsg::UpdateConfig {
+        staking_hub: None,
+        reveal_deadline_seconds: None,
+        epochs_between_regular: None,
+        epochs_between_big: None,
+        challenge_period_seconds: None,
+        regular_prize_tiers: Some(tiers),
+        big_prize_tiers: None,
+    };
+
+    // Shares must add up to 100%
+    let err = chance_reward_distributor::contract::execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&admin, &[]),
+        update(vec![PrizeTier {
+            count: 5,
+            share_bps: 1000,
+        }]),
+    )
+    .unwrap_err();
+    assert!(
+        format!("{:?}", err).contains("InvalidPrizeTiers"),
+        "Expected InvalidPrizeTiers, got: {:?}",
+        err
+    );
+
+    let tiers = vec![
+        PrizeTier {
+            count: 1,
+            share_bps: 5000,
+        },
+        PrizeTier {
+            count: 5,
+            share_bps: 1000,
+        },
+    ];
+    chance_reward_distributor::contract::execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&admin, &[]),
+        update(tiers.clone()),
+    )
+    .unwrap();
+
+    // Fund an amount that doesn't split evenly
+    let reward = 10_000_003u128;
+    let staking_hub = deps.api.addr_make("staking_hub");
+    chance_reward_distributor::contract::execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&staking_hub, &[Coin::new(reward, "inj")]),
+        ExecuteMsg::FundRegularPool {},
+    )
+    .unwrap();
+
+    let addr_a = deps.api.addr_make("user_a").to_string();
+    let addr_b = deps.api.addr_make("user_b").to_string();
+    let leaf_a = compute_leaf_hash(&addr_a, 0, 500);
+    let leaf_b = compute_leaf_hash(&addr_b, 500, 1000);
+    let root = sorted_hash(&leaf_a, &leaf_b);
+
+    chance_reward_distributor::contract::execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&staking_hub, &[]),
+        ExecuteMsg::SetSnapshot {
+            epoch: 1,
+            merkle_root: hex::encode(root),
+            total_weight: Uint128::from(1000u128),
+            num_holders: 2,
+        },
+    )
+    .unwrap();
+
+    let secret = b"tiered_secret";
+    let commit: [u8; 32] = Sha256::digest(secret).into();
+    let operator = deps.api.addr_make("operator");
+    chance_reward_distributor::contract::execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&operator, &[]),
+        ExecuteMsg::CommitDraw {
+            draw_type: chance_staking_common::types::DrawType::Regular,
+            operator_commit: hex::encode(commit),
+            target_drand_round: TEST_ROUND,
+            epoch: 1,
+        },
+    )
+    .unwrap();
+
+    // Config changes after commit don't touch the committed draw's table
+    chance_reward_distributor::contract::execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&admin, &[]),
+        update(vec![PrizeTier {
+            count: 1,
+            share_bps: 10_000,
+        }]),
+    )
+    .unwrap();
+    let draw: chance_reward_distributor::state::Draw = from_json(
+        chance_reward_distributor::contract::query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Draw { draw_id: 0 },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(draw.prize_tiers, tiers);
+
+    // Slot 0 uses final[0..16]; slot n uses sha256(final || n)[0..16]
+    let drand_randomness = hex::decode(TEST_RANDOMNESS_HEX).unwrap();
+    let secret_hash: [u8; 32] = Sha256::digest(secret).into();
+    let mut final_rand = [0u8; 32];
+    for i in 0..32 {
+        final_rand[i] = drand_randomness[i] ^ secret_hash[i];
+    }
+    let tickets: Vec<u128> = (0..6u32)
+        .map(|slot| {
+            let bytes: [u8; 32] = if slot == 0 {
+                final_rand
+            } else {
+                Sha256::new()
+                    .chain_update(final_rand)
+                    .chain_update(slot.to_be_bytes())
+                    .finalize()
+                    .into()
+            };
+            u128::from_be_bytes(bytes[0..16].try_into().unwrap()) % 1000
+        })
+        .collect();
+    let winner_of = |ticket: u128| -> SnapshotLeaf {
+        if ticket < 500 {
+            SnapshotLeaf {
+                address: addr_a.clone(),
+                cumulative_start: Uint128::zero(),
+                cumulative_end: Uint128::from(500u128),
+                merkle_proof: vec![hex::encode(leaf_b)],
+            }
+        } else {
+            SnapshotLeaf {
+                address: addr_b.clone(),
+                cumulative_start: Uint128::from(500u128),
+                cumulative_end: Uint128::from(1000u128),
+                merkle_proof: vec![hex::encode(leaf_a)],
+            }
+        }
+    };
+    let winners: Vec<SnapshotLeaf> = tickets.iter().map(|t| winner_of(*t)).collect();
+
+    let env = mock_env();
+    deps.querier
+        .bank
+        .update_balance(&env.contract.address, vec![Coin::new(reward, "inj")]);
+
+    // One winner per prize
+    let err = chance_reward_distributor::contract::execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&operator, &[]),
+        ExecuteMsg::RevealDraw {
+            draw_id: 0,
+            operator_secret_hex: hex::encode(secret),
+            winners: winners[..1].to_vec(),
+        },
+    )
+    .unwrap_err();
+    assert!(
+        format!("{:?}", err).contains("WinnerCountMismatch"),
+        "Expected WinnerCountMismatch, got: {:?}",
+        err
+    );
+
+    let res = chance_reward_distributor::contract::execute(
+        deps.as_mut(),
+        env,
+        message_info(&operator, &[]),
+        ExecuteMsg::RevealDraw {
+            draw_id: 0,
+            operator_secret_hex: hex::encode(secret),
+            winners: winners.clone(),
+        },
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 6, "one bank send per prize");
+
+    let draw: chance_reward_distributor::state::Draw = from_json(
+        chance_reward_distributor::contract::query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Draw { draw_id: 0 },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(draw.winners.len(), 6);
+    assert_eq!(
+        draw.winners.iter().map(|w| w.tier).collect::<Vec<_>>(),
+        vec![0, 1, 1, 1, 1, 1]
+    );
+    // 50% = 5_000_001, 10% = 1_000_000 each, 2 of dust to the first prize
+    assert_eq!(draw.winners[0].amount, Uint128::from(5_000_003u128));
+    for (prize, (ticket, leaf)) in draw.winners.iter().zip(tickets.iter().zip(&winners)) {
+        assert_eq!(prize.winning_ticket, Uint128::from(*ticket));
+        assert_eq!(prize.address.as_str(), leaf.address);
+        if prize.tier == 1 {
+            assert_eq!(prize.amount, Uint128::from(1_000_000u128));
+        }
+    }
+    assert_eq!(draw.winner, Some(draw.winners[0].address.clone()));
+
+    // Per-user totals add up to the whole reward; each winner counts the draw once
+    let mut total_won = Uint128::zero();
+    for addr in [&addr_a, &addr_b] {
+        let wins: chance_reward_distributor::msg::UserWinsResponse = from_json(
+            chance_reward_distributor::contract::query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::UserWins {
+                    address: addr.clone(),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        let won: Uint128 = draw
+            .winners
+            .iter()
+            .filter(|w| w.address.as_str() == addr.as_str())
+            .map(|w| w.amount)
+            .sum();
+        assert_eq!(wins.total_won_amount, won);
+        assert_eq!(wins.total_wins, u32::from(!won.is_zero()));
+        total_won += wins.total_won_amount;
+    }
+    assert_eq!(total_won, Uint128::from(reward));
+
+    eprintln!("test_tiered_prize_draw passed");
+}
+
+#[test]
+fn test_reveal_draw_redraws_ineligible_prize() {
+    // One of two holders is excluded on the hub. Prizes whose ticket lands on it
+    // are redrawn onto the eligible holder instead of failing the whole reveal.
+    use chance_reward_distributor::msg::{ExecuteMsg, QueryMsg, SnapshotLeaf};
+    use chance_reward_distributor::state::PrizeTier;
+
+    let mut deps = mock_dependencies();
+    let addr_a = deps.api.addr_make("user_a").to_string();
+    let addr_b = deps.api.addr_make("user_b").to_string();
+    let beacon_binary = get_test_beacon_binary();
+    let excluded = addr_b.clone();
+    deps.querier.update_wasm(move |query| match query {
+        WasmQuery::Smart { msg, .. } => {
+            let parsed: Result<chance_reward_distributor::msg::OracleQueryMsg, _> = from_json(msg);
+            if let Ok(chance_reward_distributor::msg::OracleQueryMsg::Beacon { .. }) = parsed {
+                return SystemResult::Ok(ContractResult::Ok(beacon_binary.clone()));
+            }
+            let parsed: Result<chance_reward_distributor::msg::StakingHubQueryMsg, _> =
+                from_json(msg);
+            match parsed {
+                Ok(chance_reward_distributor::msg::StakingHubQueryMsg::Config {}) => {
+                    let config = chance_reward_distributor::msg::StakingHubConfigResponse {
+                        min_epochs_regular: 0,
+                        min_epochs_big: 0,
+                    };
+                    SystemResult::Ok(ContractResult::Ok(to_json_binary(&config).unwrap()))
+                }
+                Ok(chance_reward_distributor::msg::StakingHubQueryMsg::StakerInfo { address }) => {
+                    let info = chance_reward_distributor::msg::StakerInfoResponse {
+                        excluded: address == excluded,
+                        address,
+                        stake_epoch: Some(0),
+                    };
+                    SystemResult::Ok(ContractResult::Ok(to_json_binary(&info).unwrap()))
+                }
+                _ => SystemResult::Err(cosmwasm_std::SystemError::InvalidRequest {
+                    error: "Unknown query".to_string(),
+                    request: Default::default(),
+                }),
+            }
+        }
+        _ => SystemResult::Err(cosmwasm_std::SystemError::InvalidRequest {
+            error: "Only smart queries supported".to_string(),
+            request: Default::default(),
+        }),
+    });
+    setup_distributor(&mut deps);
+
+    // 1 x 50% + 5 x 10%
+    let admin = deps.api.addr_make("admin");
+    chance_reward_distributor::contract::execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&admin, &[]),
+        ExecuteMsg::UpdateConfig {
+            staking_hub: None,
+            reveal_deadline_seconds: None,
+            epochs_between_regular: None,
+            epochs_between_big: None,
+            challenge_period_seconds: None,
+            regular_prize_tiers: Some(vec![
+                PrizeTier {
+                    count: 1,
+                    share_bps: 5000,
+                },
+                PrizeTier {
+                    count: 5,
+                    share_bps: 1000,
+                },
+            ]),
+            big_prize_tiers: None,
+        },
+    )
+    .unwrap();
+
+    let reward = 10_000_000u128;
+    let staking_hub = deps.api.addr_make("staking_hub");
+    chance_reward_distributor::contract::execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&staking_hub, &[Coin::new(reward, "inj")]),
+        ExecuteMsg::FundRegularPool {},
+    )
+    .unwrap();
+
+    let leaf_a = compute_leaf_hash(&addr_a, 0, 500);
+    let leaf_b = compute_leaf_hash(&addr_b, 500, 1000);
+    chance_reward_distributor::contract::execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&staking_hub, &[]),
+        ExecuteMsg::SetSnapshot {
+            epoch: 1,
+            merkle_root: hex::encode(sorted_hash(&leaf_a, &leaf_b)),
+            total_weight: Uint128::from(1000u128),
+            num_holders: 2,
+        },
+    )
+    .unwrap();
+
+    let secret = b"redraw_secret";
+    let operator = deps.api.addr_make("operator");
+    chance_reward_distributor::contract::execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&operator, &[]),
+        ExecuteMsg::CommitDraw {
+            draw_type: chance_staking_common::types::DrawType::Regular,
+            operator_commit: hex::encode(Sha256::digest(secret)),
+            target_drand_round: TEST_ROUND,
+            epoch: 1,
+        },
+    )
+    .unwrap();
+
+    let drand_randomness = hex::decode(TEST_RANDOMNESS_HEX).unwrap();
+    let secret_hash: [u8; 32] = Sha256::digest(secret).into();
+    let mut final_rand = [0u8; 32];
+    for i in 0..32 {
+        final_rand[i] = drand_randomness[i] ^ secret_hash[i];
+    }
+    let leaf_of = |ticket: u128| -> SnapshotLeaf {
+        if ticket < 500 {
+            SnapshotLeaf {
+                address: addr_a.clone(),
+                cumulative_start: Uint128::zero(),
+                cumulative_end: Uint128::from(500u128),
+                merkle_proof: vec![hex::encode(leaf_b)],
+            }
+        } else {
+            SnapshotLeaf {
+                address: addr_b.clone(),
+                cumulative_start: Uint128::from(500u128),
+                cumulative_end: Uint128::from(1000u128),
+                merkle_proof: vec![hex::encode(leaf_a)],
+            }
+        }
+    };
+
+    // Per prize: the leaves of each redraw until the ticket lands on user_a
+    let mut leaves = vec![];
+    let mut paid_tickets = vec![];
+    let mut redraws = 0;
+    for slot in 0..6u32 {
+        for redraw in 0.. {
+            let ticket =
+                chance_reward_distributor::execute::prize_ticket(&final_rand, slot, redraw, 1000);
+            leaves.push(leaf_of(ticket));
+            if ticket < 500 {
+                paid_tickets.push(ticket);
+                break;
+            }
+            redraws += 1;
+        }
+    }
+    assert!(redraws > 0, "some prize must land on the excluded holder");
+
+    let env = mock_env();
+    deps.querier
+        .bank
+        .update_balance(&env.contract.address, vec![Coin::new(reward, "inj")]);
+
+    // A leftover leaf is rejected
+    let mut extra = leaves.clone();
+    extra.push(leaves[0].clone());
+    let err = chance_reward_distributor::contract::execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&operator, &[]),
+        ExecuteMsg::RevealDraw {
+            draw_id: 0,
+            operator_secret_hex: hex::encode(secret),
+            winners: extra,
+        },
+    )
+    .unwrap_err();
+    assert!(
+        format!("{:?}", err).contains("UnusedWinners"),
+        "Expected UnusedWinners, got: {:?}",
+        err
+    );
+
+    let res = chance_reward_distributor::contract::execute(
+        deps.as_mut(),
+        env,
+        message_info(&operator, &[]),
+        ExecuteMsg::RevealDraw {
+            draw_id: 0,
+            operator_secret_hex: hex::encode(secret),
+            winners: leaves,
+        },
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 6, "every prize is paid");
+    assert_eq!(
+        res.events
+            .iter()
+            .filter(|e| e.ty == "chance_prize_redrawn")
+            .count(),
+        redraws
+    );
+
+    let draw: chance_reward_distributor::state::Draw = from_json(
+        chance_reward_distributor::contract::query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Draw { draw_id: 0 },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(draw.winners.len(), 6);
+    for (prize, ticket) in draw.winners.iter().zip(&paid_tickets) {
+        assert_eq!(prize.address.as_str(), addr_a);
+        assert_eq!(prize.winning_ticket, Uint128::from(*ticket));
+    }
+    assert_eq!(
+        draw.winners.iter().map(|w| w.amount).sum::<Uint128>(),
+        Uint128::from(reward)
+    );
+
+    eprintln!("test_reveal_draw_redraws_ineligible_prize passed");
+}
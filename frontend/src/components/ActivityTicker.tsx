@@ -2,6 +2,7 @@ import React, { useMemo } from 'react'
 import { Trophy } from 'lucide-react'
 import { useStore } from '../store/useStore'
 import { formatInj } from '../utils/formatNumber'
+import { firstPrizeAmount } from '../services/contracts'
 import { colors } from '../theme'
 
 function truncateAddr(addr: string): string {
@@ -57,7 +58,7 @@ export default function ActivityTicker() {
                   ...styles.amount,
                   color: isBig ? '#f472b6' : '#22c55e',
                 }}>
-                  {formatInj(draw.reward_amount)} INJ
+                  {formatInj(firstPrizeAmount(draw))} INJ
                 </span>
                 <span style={styles.time}>
                   {timeAgo(draw.revealed_at || draw.created_at)}
@@ -128,12 +128,25 @@ export default function DrawDetail({ drawId }: { drawId: number }) {
               <div style={styles.winnerCard}>
                 <div style={styles.winnerCardHeader}>
                   <Trophy size={20} color="#f59e0b" />
-                  <span style={styles.winnerCardTitle}>Winner</span>
+                  <span style={styles.winnerCardTitle}>
+                    {draw.winners.length > 1 ? `Winners (${draw.winners.length} prizes)` : 'Winner'}
+                  </span>
                 </div>
                 <div style={styles.winnerAddress}>{draw.winner}</div>
                 <div style={styles.winnerReward}>
-                  +{formatInj(draw.reward_amount, 4)} INJ
+                  +{formatInj(contractsService.firstPrizeAmount(draw), 4)} INJ
                 </div>
+                {draw.winners.length > 1 && (
+                  <div style={styles.prizeTable}>
+                    {draw.winners.slice(1).map((w, i) => (
+                      <div key={i} style={styles.prizeRow}>
+                        <span style={styles.prizeTier}>Tier {w.tier + 1}</span>
+                        <span style={styles.prizeAddress}>{w.address}</span>
+                        <span style={styles.prizeAmount}>+{formatInj(w.amount, 4)} INJ</span>
+                      </div>
+                    ))}
+                  </div>
+                )}
               </div>
             )}
 
@@ -219,7 +232,8 @@ export default function DrawDetail({ drawId }: { drawId: number }) {
                     Final Randomness (drand XOR secret)
                   </div>
                   <div style={styles.stepDescription}>
-                    Combined randomness used to select winner: winning_ticket = final_randomness[0..16] mod total_weight
+                    Combined randomness used to select winners: the first prize's ticket = final_randomness[0..16] mod total_weight,
+                    prize n's ticket = sha256(final_randomness || n)[0..16] mod total_weight
                   </div>
                   {finalRandomnessHex ? (
                     <div style={styles.hexRow}>
@@ -402,6 +416,35 @@ const styles: Record<string, React.CSSProperties> = {
     color: '#22c55e',
     letterSpacing: '-0.02em',
   },
+  prizeTable: {
+    marginTop: 16,
+    borderTop: '1px solid rgba(245, 158, 11, 0.2)',
+    paddingTop: 12,
+  },
+  prizeRow: {
+    display: 'flex',
+    alignItems: 'center',
+    gap: 12,
+    padding: '6px 0',
+  },
+  prizeTier: {
+    fontSize: 12,
+    fontWeight: 600,
+    color: '#f59e0b',
+    minWidth: 48,
+  },
+  prizeAddress: {
+    flex: 1,
+    fontSize: 13,
+    color: '#F0F0F5',
+    fontFamily: "'JetBrains Mono', monospace",
+    wordBreak: 'break-all' as const,
+  },
+  prizeAmount: {
+    fontSize: 14,
+    fontWeight: 700,
+    color: '#22c55e',
+  },
   verifySection: {
     background: '#1A1A22',
     border: '1px solid #2A2A38',
@@ -2,6 +2,7 @@ import React, { useState } from 'react'
 import { Trophy, Clock, ChevronRight, Gift, Users, Coins, Radio } from 'lucide-react'
 import { useStore } from '../store/useStore'
 import { formatInj } from '../utils/formatNumber'
+import { firstPrizeAmount } from '../services/contracts'
 import { colors } from '../theme'
 
 function truncateAddr(addr: string): string {
@@ -223,6 +224,7 @@ export default function DrawsSection({ fullPage = false }: DrawsSectionProps) {
                   </div>
                   <div style={styles.drawMeta}>
                     Epoch {draw.epoch} · drand #{draw.target_drand_round} · {timeAgo(draw.revealed_at || draw.created_at)}
+                    {draw.winners.length > 1 && ` · +${draw.winners.length - 1} more prizes`}
                   </div>
                 </div>
               </div>
@@ -231,7 +233,7 @@ export default function DrawsSection({ fullPage = false }: DrawsSectionProps) {
                   ...styles.drawReward,
                   color: draw.draw_type === 'big' ? '#f472b6' : '#22c55e',
                 }}>
-                  +{formatInj(draw.reward_amount)} INJ
+                  +{formatInj(firstPrizeAmount(draw))} INJ
                 </div>
                 <ChevronRight size={14} color="#8E8EA0" />
               </div>
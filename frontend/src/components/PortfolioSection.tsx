@@ -36,7 +36,7 @@ export default function PortfolioSection() {
   const now = Date.now() * 1e6
   const pendingRequests = unstakeRequests.filter((r) => !r.request.claimed)
   const claimableRequests = pendingRequests.filter(
-    (r) => parseInt(r.request.unlock_time) <= now / 1e6,
+    (r) => r.unlock_time !== null && parseInt(r.unlock_time) <= now / 1e6,
   )
 
   const handleClaim = async (ids: number[]) => {
@@ -247,8 +247,8 @@ export default function PortfolioSection() {
               </button>
             )}
             {pendingRequests.map((entry) => {
-              const unlockTime = parseInt(entry.request.unlock_time) / 1e6
-              const isClaimable = unlockTime <= Date.now()
+              const unlockTime = entry.unlock_time !== null ? parseInt(entry.unlock_time) / 1e6 : null
+              const isClaimable = unlockTime !== null && unlockTime <= Date.now()
               const isClaiming = claiming === entry.id
 
               return (
@@ -264,13 +264,15 @@ export default function PortfolioSection() {
                     </div>
                     <div>
                       <div style={styles.unstakeAmount}>
-                        {formatInj(entry.request.inj_amount)} INJ
+                        {formatInj(entry.adjusted_inj_amount)} INJ
                       </div>
                       <div style={styles.unstakeMeta}>
                         {isClaimable ? (
                           <span style={{ color: '#22c55e' }}>Ready to claim</span>
+                        ) : entry.unlock_time !== null ? (
+                          <span>Unlocks {formatTimestamp(entry.unlock_time)}</span>
                         ) : (
-                          <span>Unlocks {formatTimestamp(entry.request.unlock_time)}</span>
+                          <span>Waiting for unbonding batch</span>
                         )}
                       </div>
                     </div>
@@ -129,7 +129,7 @@ const v1Findings: Finding[] = [
     title: 'Direct INJ transfers inflate next epoch\'s rewards',
     description: 'Any INJ sent directly to the contract (outside staking flow) is treated as staking rewards in the next epoch.',
     impact: 'Could be used to manipulate exchange rate or prize pool sizes. However, this effectively donates to stakers.',
-    fix: 'Documented as an intentional feature — allows voluntary contributions to the reward pool.',
+    fix: 'Donations now go through donate, which routes them in full to a chosen pool or the base yield. advance_epoch only distributes the rewards its withdrawals bring in and reports any other surplus separately as unexplained_surplus.',
   },
   {
     id: 'L-04', severity: 'low', contract: 'staking-hub',
@@ -195,7 +195,7 @@ const v2Findings: Finding[] = [
     title: 'Re-staking resets eligibility clock entirely',
     description: 'Any new stake overwrites USER_STAKE_EPOCH with the current epoch, restarting the eligibility countdown for both regular and big draws.',
     impact: 'Informational — by design, but could frustrate users unaware of this behavior.',
-    fix: 'Documented as intentional behavior. Frontend warns users before additional stakes.',
+    fix: 'USER_STAKE_EPOCH is now a balance-weighted average entry epoch. Top-ups and incoming transfers move it in proportion to the amount added; partial unstakes leave it unchanged.',
   },
 ]
 
@@ -208,13 +208,13 @@ const tradeoffs = [
   },
   {
     title: 'Direct INJ Transfers (L-03)',
-    description: 'Sending INJ directly to the staking-hub contract (outside normal staking flow) will cause that INJ to be distributed as rewards in the next epoch.',
-    implication: 'Direct transfers are treated as additional staking rewards. The INJ is split according to BPS configuration. This is by design and allows voluntary contributions to the reward pool.',
+    description: 'INJ sent directly to the staking-hub contract (outside donate and the staking flow) is not distributed by advance_epoch. It is reported once as unexplained_surplus on chance_epoch_advanced and in the epoch record of the epoch it arrived in, and the admin can send it to the treasury with sweep_surplus. Only the legacy distribute_rewards still sweeps it in as rewards.',
+    implication: 'Use donate to contribute to the regular pool, big pool or base yield. Donations skip the treasury fee and are counted separately from staking rewards.',
   },
   {
-    title: 'Re-staking Resets Eligibility (V2-I-01)',
-    description: 'Any new stake resets the user\'s epoch eligibility timer. Adding more INJ restarts the min_epochs_regular / min_epochs_big countdown.',
-    implication: 'Users who want to remain eligible for draws should avoid staking more until after a draw. The frontend warns users about this behavior.',
+    title: 'Weighted Stake Age (V2-I-01)',
+    description: 'Adding csINJ moves the holder\'s entry epoch towards the current epoch in proportion to the amount added (rounded up). A small top-up costs at most one epoch of age.',
+    implication: 'Large top-ups relative to an existing position noticeably reduce draw eligibility age until the new funds have aged.',
   },
   {
     title: 'No Minimum Stake by Default (V2-L-03)',
@@ -199,7 +199,9 @@ function StakingHubSection() {
       </Paragraph>
       <Paragraph>
         <strong style={{ color: '#F0F0F5' }}>Eligibility:</strong> Users must hold csINJ for <InlineCode>min_epochs_regular</InlineCode> epochs
-        for regular draws and <InlineCode>min_epochs_big</InlineCode> epochs for big draws. Re-staking resets the eligibility timer.
+        for regular draws and <InlineCode>min_epochs_big</InlineCode> epochs for big draws. Age is a balance-weighted average entry epoch: adding csINJ by staking
+        or by transfer (the hub is the csINJ before-send hook) moves it towards the current epoch in proportion to the amount added,
+        and partial unstakes leave it unchanged.
       </Paragraph>
     </div>
   )
@@ -212,20 +214,59 @@ function StakingHubExecuteSection() {
 
       <MsgCard
         title="Stake"
-        description="Stake INJ to receive csINJ. The protocol delegates INJ across validators. csINJ minted = inj_amount / exchange_rate. Rejects if amount < min_stake_amount. Resets user's epoch eligibility timer."
-      >{`{ "stake": {} }
+        description="Stake INJ to receive csINJ. The protocol delegates INJ across validators. csINJ minted = inj_amount / exchange_rate. Rejects if amount < min_stake_amount. Moves the recipient's weighted entry epoch towards the current epoch in proportion to the amount added. Optional recipient (defaults to sender) receives the csINJ. Optional min_csinj_out and deadline (unix seconds) reject the stake with SlippageExceeded / DeadlineExceeded if the rate moves or the tx lands late."
+      >{`{ "stake": {
+  "recipient": "inj1...",
+  "min_csinj_out": "990000000000000000",
+  "deadline": 1735689600
+} }
 // funds: [{ "denom": "inj", "amount": "1000000000000000000" }]`}</MsgCard>
 
       <MsgCard
         title="Unstake"
-        description="Burn csINJ to begin unbonding. INJ owed = csinj_amount * exchange_rate. Subject to 21-day unbonding period."
-      >{`{ "unstake": {} }
+        description="Burn csINJ to begin unbonding. INJ owed = csinj_amount * exchange_rate. Subject to 21-day unbonding period. Optional recipient (defaults to sender) owns the unstake request. Optional min_inj_out and deadline (unix seconds) reject the unstake with SlippageExceeded / DeadlineExceeded."
+      >{`{ "unstake": {
+  "recipient": "inj1...",
+  "min_inj_out": "1000000",
+  "deadline": 1735689600
+} }
 // funds: [{ "denom": "factory/{contract}/csINJ", "amount": "1000000" }]`}</MsgCard>
 
       <MsgCard
         title="Claim Unstaked"
-        description="Claim unlocked unstake requests after the 21-day unbonding period."
-      >{`{ "claim_unstaked": { "request_ids": [0, 1] } }`}</MsgCard>
+        description="Claim the sender's unlocked unstake requests after the 21-day unbonding period. Optional recipient (defaults to sender) receives the INJ."
+      >{`{ "claim_unstaked": { "request_ids": [0, 1], "recipient": "inj1..." } }`}</MsgCard>
+
+      <MsgCard
+        title="Enter Yield Only"
+        description="Send csINJ to hold it in yield-only mode. The hub keeps the csINJ out of the draws and credits shares to the recipient (defaults to sender). Each epoch, the yield-only csINJ's pro-rata share of the prize pool allocation is restaked and minted into it as csINJ, so each share redeems for more csINJ over time."
+      >{`{ "enter_yield_only": { "recipient": "inj1..." } }
+
+// Send with funds: [{ "denom": "factory/.../csINJ", "amount": "1000000" }]`}</MsgCard>
+
+      <MsgCard
+        title="Exit Yield Only"
+        description="Redeem yield-only shares (all of the sender's by default) for csINJ, sent to the recipient (defaults to sender). The csINJ enters the draws from the current epoch, like freshly staked csINJ."
+      >{`{ "exit_yield_only": { "shares": "1000000" | null, "recipient": "inj1..." } }`}</MsgCard>
+
+      <MsgCard
+        title="Sponsor"
+        description="Send INJ to sponsor the prize pools. The INJ is delegated like staked INJ but mints no csINJ, so the sponsor keeps their principal and never plays the draws. Sponsor addresses stay excluded from snapshots for good, also after withdrawing, and any csINJ they hold is left out of the snapshot totals. Each epoch, the sponsored INJ's share of the staking rewards (of all delegated INJ, including the open unbonding batch) goes entirely to the regular and big pools, in the ratio of their splits."
+      >{`{ "sponsor": {} }
+
+// Send with funds: [{ "denom": "inj", "amount": "1000000" }]`}</MsgCard>
+
+      <MsgCard
+        title="Donate"
+        description="Send INJ to the regular pool, the big pool or the base yield. The whole amount goes to the target with no treasury fee: pool donations are forwarded to the reward-distributor right away, and base yield donations are delegated and raise the exchange rate (rejected while no csINJ exists). Emits chance_donation with the target's running total."
+      >{`{ "donate": { "target": "regular_pool" | "big_pool" | "base_yield" } }
+
+// Send with funds: [{ "denom": "inj", "amount": "1000000" }]`}</MsgCard>
+
+      <MsgCard
+        title="Withdraw Sponsorship"
+        description="Withdraw the sender's whole sponsorship. The INJ (less any slashing) is added to the open unbonding batch as an unstake request and is claimed with claim_unstaked once the batch unlocks."
+      >{`{ "withdraw_sponsorship": {} }`}</MsgCard>
 
       <MsgCard
         title="Claim Rewards (operator)"
@@ -237,9 +278,14 @@ function StakingHubExecuteSection() {
         description="Distributes claimed rewards and advances the epoch. Enforces epoch_duration_seconds has elapsed. Splits surplus according to BPS config."
       >{`{ "distribute_rewards": {} }`}</MsgCard>
 
+      <MsgCard
+        title="Advance Epoch"
+        description="Claims and distributes rewards in one transaction. The reward withdrawals run as submessages and the distribution runs in the reply to the last one. Only the INJ the withdrawals add to the hub's balance is distributed, plus the rewards the SDK paid out when the hub changed a delegation during the epoch, so direct transfers and unbonded funds are never counted as rewards; other INJ beyond pending unstake claims that arrived since the last epoch is reported as unexplained_surplus. Anyone can call once epoch_duration_seconds has elapsed; callers other than the operator are paid keeper_fee_bps of the treasury fee once snapshot_grace_seconds have passed since the epoch ended."
+      >{`{ "advance_epoch": {} }`}</MsgCard>
+
       <MsgCard
         title="Take Snapshot (operator)"
-        description="Submit snapshot merkle root for the current epoch. Forwards the snapshot to the reward-distributor. Merkle root must be exactly 64 hex characters."
+        description="Submit snapshot merkle root for the current epoch. Operator only until snapshot_grace_seconds after the epoch ends, then anyone as long as the reward-distributor has a non-zero challenge_period_seconds, so draws don't stall without the operator but unchallengeable snapshots stay with the operator. Forwards the snapshot to the reward-distributor. A snapshot the reward-distributor invalidated can be taken again, which replaces it there with a fresh challenge window; draws committed on the old one can no longer be revealed. Merkle root must be exactly 64 hex characters. total_weight can exceed the csINJ supply minus the balances of excluded addresses, the yield-only csINJ and the csINJ held by sponsors by at most snapshot_tolerance_bps, and num_holders must be between 1 and total_weight (0 for an empty snapshot). The bounds are stored in the epoch state and emitted on chance_snapshot_taken."
       >{`{ "take_snapshot": {
   "merkle_root": "abcdef...",
   "total_weight": "1000000",
@@ -249,22 +295,31 @@ function StakingHubExecuteSection() {
 
       <MsgCard
         title="Update Config (admin)"
-        description="Update protocol configuration. BPS fields must sum to 10000."
+        description="Update protocol configuration. BPS fields must sum to 10000. While a timelock is set, every field but min_stake_amount can only be changed through schedule_change. The operator changes through propose_operator and accept_operator only."
       >{`{ "update_config": {
-  "admin": "inj1...",
-  "operator": "inj1...",
   "protocol_fee_bps": 500,
   "base_yield_bps": 500,
   "regular_pool_bps": 7000,
   "big_pool_bps": 2000,
   "min_epochs_regular": 1,
   "min_epochs_big": 4,
-  "min_stake_amount": "1000"
+  "min_stake_amount": "1000",
+  "snapshot_tolerance_bps": 100,
+  "keeper_fee_bps": 1000,
+  "snapshot_grace_seconds": 3600
+} }`}</MsgCard>
+
+      <MsgCard
+        title="Update Snapshot Exclusions (admin)"
+        description="Add or remove addresses whose csINJ is left out of snapshots (treasury, DEX pools, ...). Their balances are subtracted from the supply when take_snapshot checks totals, and they can't win draws. At most 50 exclusions."
+      >{`{ "update_snapshot_exclusions": {
+  "add": ["inj1..."],
+  "remove": []
 } }`}</MsgCard>
 
       <MsgCard
         title="Update Validators (admin)"
-        description="Add or remove validators. Removed validators are automatically redelegated. Addresses must start with 'injvaloper'."
+        description="Add or remove validators. Removed validators are automatically redelegated. Addresses must start with 'injvaloper'. Rejected while a timelock is set; use schedule_change instead."
       >{`{ "update_validators": {
   "add": ["injvaloper1..."],
   "remove": []
@@ -274,6 +329,86 @@ function StakingHubExecuteSection() {
         title="Sync Delegations (operator)"
         description="Sync backing with actual validator delegations after slashing. Updates TOTAL_INJ_BACKING, EPOCH_STATE.total_staked, and exchange rate."
       >{`{ "sync_delegations": {} }`}</MsgCard>
+
+      <MsgCard
+        title="Sweep Surplus (admin)"
+        description="Send the INJ in the hub beyond pending unstake claims and rewards awaiting the next epoch (the unexplained surplus advance_epoch reports) to the treasury."
+      >{`{ "sweep_surplus": {} }`}</MsgCard>
+
+      <MsgCard
+        title="Propose Admin (admin)"
+        description="Propose a new admin. Control only moves once the proposed address sends accept_admin. Optional expiry in seconds."
+      >{`{ "propose_admin": {
+  "new_admin": "inj1...",
+  "expires_in_seconds": 86400
+} }`}</MsgCard>
+
+      <MsgCard
+        title="Accept Admin (proposed admin)"
+        description="Accept a pending admin proposal before it expires."
+      >{`{ "accept_admin": {} }`}</MsgCard>
+
+      <MsgCard
+        title="Propose Operator (admin)"
+        description="Propose a new operator. The current operator stays active until the proposed address sends accept_operator. Optional expiry in seconds."
+      >{`{ "propose_operator": {
+  "new_operator": "inj1...",
+  "expires_in_seconds": 86400
+} }`}</MsgCard>
+
+      <MsgCard
+        title="Accept Operator (proposed operator)"
+        description="Accept a pending operator proposal before it expires."
+      >{`{ "accept_operator": {} }`}</MsgCard>
+
+      <MsgCard
+        title="Cancel Proposal (admin)"
+        description="Withdraw a pending admin or operator proposal."
+      >{`{ "cancel_proposal": { "role": "operator" } }`}</MsgCard>
+
+      <MsgCard
+        title="Schedule Change (admin)"
+        description="Queue a parameter change. It becomes executable timelock_seconds after scheduling. Changes: update_config (BPS splits, min_epochs, snapshot_tolerance_bps, keeper_fee_bps, snapshot_grace_seconds), update_validators, set_timelock."
+      >{`{ "schedule_change": {
+  "change": {
+    "update_config": {
+      "protocol_fee_bps": 500,
+      "base_yield_bps": 500,
+      "regular_pool_bps": 7000,
+      "big_pool_bps": 2000,
+      "min_epochs_regular": null,
+      "min_epochs_big": null,
+      "snapshot_tolerance_bps": null,
+      "keeper_fee_bps": null,
+      "snapshot_grace_seconds": null
+    }
+  }
+} }`}</MsgCard>
+
+      <MsgCard
+        title="Execute Change (anyone)"
+        description="Apply a scheduled change once its executable_at time has passed."
+      >{`{ "execute_change": { "id": 0 } }`}</MsgCard>
+
+      <MsgCard
+        title="Cancel Change (admin)"
+        description="Drop a scheduled change before it is executed."
+      >{`{ "cancel_change": { "id": 0 } }`}</MsgCard>
+
+      <MsgCard
+        title="Update Guardian (admin)"
+        description="Set or clear the guardian, who can pause operations during an incident."
+      >{`{ "update_guardian": { "guardian": "inj1..." } }`}</MsgCard>
+
+      <MsgCard
+        title="Pause (guardian or admin)"
+        description="Pause operations independently. Pausable: stake, unstake, epoch_advance (claim_rewards, distribute_rewards and advance_epoch), take_snapshot. Claim Unstaked is never paused. Paused messages are rejected with OperationPaused."
+      >{`{ "pause": { "operations": ["stake", "unstake"] } }`}</MsgCard>
+
+      <MsgCard
+        title="Unpause (admin)"
+        description="Resume paused operations."
+      >{`{ "unpause": { "operations": ["stake", "unstake"] } }`}</MsgCard>
     </div>
   )
 }
@@ -292,6 +427,12 @@ function StakingHubQuerySection() {
 {
   "admin": "inj1...",
   "operator": "inj1...",
+  "guardian": "inj1..." | null,
+  "pending_admin": null,
+  "pending_operator": {
+    "new_address": "inj1...",
+    "expires_at": "1234567890.000000000" | null
+  },
   "reward_distributor": "inj1...",
   "drand_oracle": "inj1...",
   "csinj_denom": "factory/inj1.../csINJ",
@@ -304,7 +445,10 @@ function StakingHubQuerySection() {
   "big_pool_bps": 2000,
   "min_epochs_regular": 1,
   "min_epochs_big": 4,
-  "min_stake_amount": "1000"
+  "min_stake_amount": "1000",
+  "snapshot_tolerance_bps": 100,
+  "keeper_fee_bps": 1000,
+  "snapshot_grace_seconds": 3600
 }`}</MsgCard>
 
       <MsgCard
@@ -321,9 +465,70 @@ function StakingHubQuerySection() {
   "snapshot_finalized": true,
   "snapshot_total_weight": "100000000",
   "snapshot_num_holders": 42,
+  "snapshot_uri": "https://..." | null,
+  "snapshot_bounds": {
+    "csinj_supply": "100000000",
+    "excluded_addresses": ["inj1..."],
+    "excluded_balance": "2000000",
+    "yield_only_csinj": "0",
+    "sponsor_csinj": "0",
+    "max_weight": "98980000"
+  } | null
+}`}</MsgCard>
+
+      <MsgCard
+        title="Epoch Record"
+        description="Rewards, pool splits, backing, supply and exchange rate of a finished epoch, with the snapshot taken during it. Written when distribute_rewards or advance_epoch closes the epoch."
+      >{`{ "epoch_record": { "epoch": 4 } }
+
+// Returns: EpochRecord
+{
+  "epoch": 4,
+  "start_time": "1234481490.000000000",
+  "end_time": "1234567890.000000000",
+  "total_rewards": "1000000",
+  "sponsor_rewards": "0",
+  "regular_pool_amount": "700000",
+  "big_pool_amount": "200000",
+  "base_yield": "50000",
+  "yield_only_amount": "0",
+  "yield_only_minted": "0",
+  "treasury_fee": "50000",
+  "keeper_fee": "0",
+  "unexplained_surplus": "0",
+  "total_inj_backing": "100050000",
+  "total_csinj_supply": "100000000",
+  "exchange_rate": "1.0005",
+  "snapshot_merkle_root": "abcdef..." | null,
+  "snapshot_total_weight": "100000000",
+  "snapshot_num_holders": 42,
   "snapshot_uri": "https://..." | null
 }`}</MsgCard>
 
+      <MsgCard
+        title="Epoch History"
+        description="Records of finished epochs, oldest first. Compare exchange_rate across records to compute the base-yield APR."
+      >{`{ "epoch_history": { "start_after": null, "limit": 50 } }
+
+// Returns: EpochRecord[]`}</MsgCard>
+
+      <MsgCard
+        title="Yield Stats"
+        description="Base-yield APR and APY over the last lookback_epochs finished epochs, from the exchange rate history. APY compounds the APR once per epoch. num_epochs is lower than requested while the history is shorter."
+      >{`{ "yield_stats": { "lookback_epochs": 30 } }
+
+// Returns: YieldStatsResponse
+{
+  "num_epochs": 30,
+  "from_epoch": 12,
+  "to_epoch": 41,
+  "start_rate": "1.012",
+  "end_rate": "1.016",
+  "elapsed_seconds": 2592000,
+  "base_apr": "0.048102766798418972",
+  "base_apy": "0.049269637505318354"
+}`}</MsgCard>
+
       <MsgCard
         title="Exchange Rate"
         description="Returns the current exchange rate and backing totals."
@@ -347,20 +552,114 @@ function StakingHubQuerySection() {
   "request": {
     "inj_amount": "50000000",
     "csinj_burned": "47619047",
-    "unlock_time": "1234567890.000000000",
+    "batch_id": 3,
+    "batch_shares": "50000000",
     "claimed": false
-  }
+  },
+  "adjusted_inj_amount": "50000000",
+  "unlock_time": "1234567890.000000000"
 }]`}</MsgCard>
 
       <MsgCard
         title="Staker Info"
-        description="Returns eligibility info for a staker — the epoch of their most recent stake."
+        description="Returns eligibility info for a staker — the balance-weighted average epoch their csINJ entered their wallet, and whether they are excluded from snapshots (exclusion list or sponsor)."
       >{`{ "staker_info": { "address": "inj1..." } }
 
 // Returns: StakerInfoResponse
 {
   "address": "inj1...",
-  "stake_epoch": 5 | null
+  "stake_epoch": 5 | null,
+  "excluded": false
+}`}</MsgCard>
+
+      <MsgCard
+        title="Snapshot Exclusions"
+        description="Addresses on the snapshot exclusion list. The operator node leaves them out of snapshots and the reward distributor rejects them as winners."
+      >{`{ "snapshot_exclusions": { "start_after": null, "limit": 50 } }
+
+// Returns: string[]`}</MsgCard>
+
+      <MsgCard
+        title="Yield Only Position"
+        description="Yield-only shares of an address and the csINJ (and INJ at the current rate) they redeem for."
+      >{`{ "yield_only_position": { "address": "inj1..." } }
+
+// Returns: YieldOnlyPositionResponse
+{
+  "address": "inj1...",
+  "shares": "1000000",
+  "csinj_amount": "1044887",
+  "inj_value": "1047500"
+}`}</MsgCard>
+
+      <MsgCard
+        title="Yield Only Totals"
+        description="Total yield-only shares and the csINJ the hub holds for them."
+      >{`{ "yield_only_totals": {} }
+
+// Returns: YieldOnlyTotals
+{
+  "shares": "1000000",
+  "csinj": "1044887"
+}`}</MsgCard>
+
+      <MsgCard
+        title="Donation Totals"
+        description="INJ donated through donate so far, per target."
+      >{`{ "donation_totals": {} }
+
+// Returns: DonationTotals
+{
+  "regular_pool": "1000000",
+  "big_pool": "0",
+  "base_yield": "500000"
+}`}</MsgCard>
+
+      <MsgCard
+        title="Sponsors"
+        description="Paginated list of sponsors with their shares and the INJ they currently withdraw for, plus the sponsorship totals."
+      >{`{ "sponsors": { "start_after": "inj1...", "limit": 50 } }
+
+// Returns: SponsorsResponse
+{
+  "sponsors": [
+    { "address": "inj1...", "shares": "1000000", "inj_amount": "1000000" }
+  ],
+  "total_shares": "1000000",
+  "total_sponsored": "1000000"
+}`}</MsgCard>
+
+      <MsgCard
+        title="TWAB Between"
+        description="Time-weighted average csINJ balance of an address and of all holders between two unix timestamps. Checkpoints are written on stake, unstake and csINJ transfers. end is capped at the current block time. min_balance is the lowest balance held in the window; the reward distributor uses it to check snapshot challenges."
+      >{`{ "twab_between": { "address": "inj1...", "start": 1735603200, "end": 1735689600 } }
+
+// Returns: TwabResponse
+{
+  "address": "inj1...",
+  "start": 1735603200,
+  "end": 1735689600,
+  "average_balance": "50000000",
+  "total_average_balance": "66666666",
+  "min_balance": "40000000"
+}`}</MsgCard>
+
+      <MsgCard
+        title="Pending Changes"
+        description="List scheduled parameter changes with their executable_at time."
+      >{`{ "pending_changes": { "start_after": null, "limit": 50 } }
+
+// Returns: PendingChange[]`}</MsgCard>
+
+      <MsgCard
+        title="Pause State"
+        description="Returns the guardian and the currently paused operations."
+      >{`{ "pause_state": {} }
+
+// Returns: PauseStateResponse
+{
+  "guardian": "inj1...",
+  "paused": ["stake"]
 }`}</MsgCard>
     </div>
   )
@@ -399,7 +698,7 @@ function RewardDistributorSection() {
         <div style={styles.deployStep}>
           <span style={styles.deployNum}>4</span>
           <div>
-            <strong style={{ color: '#F0F0F5' }}>Reveal</strong> — After drand beacon, operator reveals secret + computed winner with merkle proof
+            <strong style={{ color: '#F0F0F5' }}>Reveal</strong> — After drand beacon, operator reveals secret + one computed winner per prize, each with a merkle proof
           </div>
         </div>
         <div style={styles.deployStep}>
@@ -437,9 +736,28 @@ function RewardDistributorExecuteSection() {
       >{`{ "fund_big_pool": {} }
 // funds: [{ "denom": "inj", "amount": "..." }]`}</MsgCard>
 
+      <MsgCard
+        title="Add Bonus Prize (anyone)"
+        description="Escrows non-INJ native tokens as a bonus prize for the first draw of the given pool committed at or after target_epoch (defaults to the latest snapshot epoch, at most 52 epochs ahead). Every denom must be on the admin's bonus denom list and meet its minimum amount. Up to 10 denoms per epoch bucket. The winner receives the bonus alongside the INJ prize, each denom as its own transfer; a transfer that fails is kept for the winner to claim with claim_bonus_prize. Expired draws roll the bonus over to the pool's next draw. INJ is rejected — donate it through the staking hub instead."
+      >{`{ "add_bonus_prize": { "draw_type": "regular", "target_epoch": 12 | null } }
+// funds: [{ "denom": "peggy0x...", "amount": "..." }, ...]`}</MsgCard>
+
+      <MsgCard
+        title="Claim Bonus Prize (winner)"
+        description="Claims a bonus prize whose transfer failed when its draw was revealed."
+      >{`{ "claim_bonus_prize": { "denom": "peggy0x..." } }`}</MsgCard>
+
+      <MsgCard
+        title="Update Bonus Denoms (admin)"
+        description="Accepts denoms as bonus prizes, each with the least amount one add_bonus_prize must send, or stops accepting them. Bonus prizes already escrowed are unaffected."
+      >{`{ "update_bonus_denoms": {
+  "add": [{ "denom": "peggy0x...", "amount": "1000000" }],
+  "remove": []
+} }`}</MsgCard>
+
       <MsgCard
         title="Set Snapshot (staking_hub only)"
-        description="Sets the merkle snapshot for an epoch. Cannot overwrite an existing snapshot."
+        description="Sets the merkle snapshot for an epoch. Cannot overwrite an existing snapshot unless it was invalidated; the replacement starts a new challenge window and closes the old disputes."
       >{`{ "set_snapshot": {
   "epoch": 1,
   "merkle_root": "abcdef...",
@@ -449,7 +767,7 @@ function RewardDistributorExecuteSection() {
 
       <MsgCard
         title="Commit Draw (operator)"
-        description="Commits to a draw with a hash of the operator secret and target drand round. Uses the full pool balance as the reward amount. Epoch must match latest snapshot."
+        description="Commits to a draw with a hash of the operator secret and target drand round. Uses the full pool balance as the reward amount and attaches any bonus prizes targeted at this epoch or earlier. Epoch must match latest snapshot, and the snapshot must be past its challenge deadline, not invalidated and free of open disputes."
       >{`{ "commit_draw": {
   "draw_type": "regular",
   "operator_commit": "sha256hex",
@@ -459,31 +777,155 @@ function RewardDistributorExecuteSection() {
 
       <MsgCard
         title="Reveal Draw (operator)"
-        description="Reveals the draw with the operator secret, winner address, and merkle proof. Verifies proof on-chain and pays out the winner."
+        description="Reveals the draw with the operator secret and one snapshot leaf per prize of the draw's prize table, in table order. Each prize has its own ticket: the first prize uses final_randomness[0..16], prize n uses sha256(final_randomness || n as u32)[0..16], both mod total_weight. Verifies every proof on-chain and pays each prize; rounding dust and bonus prizes go to the first prize paid. A ticket landing on an excluded or not yet eligible holder is redrawn: that holder's leaf is followed by the leaf of the redrawn ticket, sha256(final_randomness || n || r as u32)[0..16] for redraw r. A prize still without an eligible winner after 10 redraws goes back to its pool. Fails with WinnerCountMismatch on too few winners, UnusedWinners on leftover leaves, and WinnerExcluded or WinnerNotEligible if an ineligible winner is not followed by its redraw."
       >{`{ "reveal_draw": {
   "draw_id": 0,
   "operator_secret_hex": "hex_of_secret_bytes",
-  "winner_address": "inj1...",
-  "winner_cumulative_start": "100",
-  "winner_cumulative_end": "350",
-  "merkle_proof": ["hex_hash_1", "hex_hash_2"]
+  "winners": [{
+    "address": "inj1...",
+    "cumulative_start": "100",
+    "cumulative_end": "350",
+    "merkle_proof": ["hex_hash_1", "hex_hash_2"]
+  }]
 } }`}</MsgCard>
 
       <MsgCard
         title="Expire Draw (anyone)"
-        description="Expires an unrevealed draw past the reveal deadline. Returns funds to the respective pool."
+        description="Expires an unrevealed draw past the reveal deadline. Returns funds to the respective pool and rolls bonus prizes over to its next draw."
       >{`{ "expire_draw": { "draw_id": 0 } }`}</MsgCard>
 
+      <MsgCard
+        title="Challenge Snapshot (anyone)"
+        description="Submit a fraud proof before the snapshot's challenge_deadline. overlapping_leaves (two distinct leaves with overlapping ranges) and underweight_leaf (a leaf weighted below the holder's lowest csINJ balance since the previous snapshot, from the hub's TWAB checkpoints) invalidate the snapshot. missing_leaf opens a dispute for the sender that blocks commit_draw until it is answered or the admin resolves it; it is rejected for excluded senders and senders below min_epochs_regular."
+      >{`{ "challenge_snapshot": {
+  "epoch": 1,
+  "challenge": {
+    "overlapping_leaves": {
+      "first": {
+        "address": "inj1...",
+        "cumulative_start": "0",
+        "cumulative_end": "600",
+        "merkle_proof": ["hex1"]
+      },
+      "second": { ... }
+    }
+  }
+} }
+
+// or: { "underweight_leaf": { "leaf": { ... } } }
+// or: { "missing_leaf": {} }`}</MsgCard>
+
+      <MsgCard
+        title="Answer Dispute (anyone)"
+        description="Close a missing_leaf dispute by proving the disputer's leaf carries at least their disputed balance. Works after the challenge window has closed."
+      >{`{ "answer_dispute": {
+  "epoch": 1,
+  "leaf": {
+    "address": "inj1...",
+    "cumulative_start": "100",
+    "cumulative_end": "350",
+    "merkle_proof": ["hex1", "hex2"]
+  }
+} }`}</MsgCard>
+
+      <MsgCard
+        title="Dismiss Dispute (admin)"
+        description="Drop a missing_leaf dispute nobody answered, unblocking draws on the snapshot."
+      >{`{ "dismiss_dispute": { "epoch": 1, "disputer": "inj1..." } }`}</MsgCard>
+
+      <MsgCard
+        title="Invalidate Snapshot (admin)"
+        description="Invalidate a snapshot, e.g. when an unanswered dispute holds up, and close its open disputes. No draw can be committed on it afterwards."
+      >{`{ "invalidate_snapshot": { "epoch": 1 } }`}</MsgCard>
+
       <MsgCard
         title="Update Config (admin)"
-        description="Update distributor configuration. Reveal deadline must be between 300-86400 seconds."
+        description="Update distributor configuration. Reveal deadline must be between 300-86400 seconds. Prize tables need count x share_bps summed over the tiers to equal 10000, with at most 50 prizes; a draw keeps the table it was committed with. While a timelock is set, reveal_deadline_seconds, epochs_between_*, challenge_period_seconds and the prize tables can only be changed through schedule_change. The operator changes through propose_operator and accept_operator only."
       >{`{ "update_config": {
-  "operator": "inj1...",
   "staking_hub": "inj1...",
   "reveal_deadline_seconds": 3600,
   "epochs_between_regular": 1,
-  "epochs_between_big": 7
+  "epochs_between_big": 7,
+  "challenge_period_seconds": 3600,
+  "regular_prize_tiers": [
+    { "count": 1, "share_bps": 5000 },
+    { "count": 5, "share_bps": 600 },
+    { "count": 20, "share_bps": 100 }
+  ],
+  "big_prize_tiers": null
 } }`}</MsgCard>
+
+      <MsgCard
+        title="Propose Admin (admin)"
+        description="Propose a new admin. Control only moves once the proposed address sends accept_admin. Optional expiry in seconds."
+      >{`{ "propose_admin": {
+  "new_admin": "inj1...",
+  "expires_in_seconds": 86400
+} }`}</MsgCard>
+
+      <MsgCard
+        title="Accept Admin (proposed admin)"
+        description="Accept a pending admin proposal before it expires."
+      >{`{ "accept_admin": {} }`}</MsgCard>
+
+      <MsgCard
+        title="Propose Operator (admin)"
+        description="Propose a new operator. The current operator stays active until the proposed address sends accept_operator. Optional expiry in seconds."
+      >{`{ "propose_operator": {
+  "new_operator": "inj1...",
+  "expires_in_seconds": 86400
+} }`}</MsgCard>
+
+      <MsgCard
+        title="Accept Operator (proposed operator)"
+        description="Accept a pending operator proposal before it expires."
+      >{`{ "accept_operator": {} }`}</MsgCard>
+
+      <MsgCard
+        title="Cancel Proposal (admin)"
+        description="Withdraw a pending admin or operator proposal."
+      >{`{ "cancel_proposal": { "role": "operator" } }`}</MsgCard>
+
+      <MsgCard
+        title="Schedule Change (admin)"
+        description="Queue a parameter change. It becomes executable timelock_seconds after scheduling. Changes: update_config (reveal_deadline_seconds, epochs_between_*, challenge_period_seconds, *_prize_tiers), set_timelock."
+      >{`{ "schedule_change": {
+  "change": {
+    "update_config": {
+      "reveal_deadline_seconds": null,
+      "epochs_between_regular": 1,
+      "epochs_between_big": 7,
+      "challenge_period_seconds": null,
+      "regular_prize_tiers": null,
+      "big_prize_tiers": null
+    }
+  }
+} }`}</MsgCard>
+
+      <MsgCard
+        title="Execute Change (anyone)"
+        description="Apply a scheduled change once its executable_at time has passed."
+      >{`{ "execute_change": { "id": 0 } }`}</MsgCard>
+
+      <MsgCard
+        title="Cancel Change (admin)"
+        description="Drop a scheduled change before it is executed."
+      >{`{ "cancel_change": { "id": 0 } }`}</MsgCard>
+
+      <MsgCard
+        title="Update Guardian (admin)"
+        description="Set or clear the guardian, who can pause operations during an incident."
+      >{`{ "update_guardian": { "guardian": "inj1..." } }`}</MsgCard>
+
+      <MsgCard
+        title="Pause (guardian or admin)"
+        description="Pause operations independently. Pausable: commit_draw (also add_bonus_prize), reveal_draw. Pool funding, Expire Draw and snapshot challenges stay available. Paused messages are rejected with OperationPaused."
+      >{`{ "pause": { "operations": ["commit_draw"] } }`}</MsgCard>
+
+      <MsgCard
+        title="Unpause (admin)"
+        description="Resume paused operations."
+      >{`{ "unpause": { "operations": ["commit_draw"] } }`}</MsgCard>
     </div>
   )
 }
@@ -502,11 +944,18 @@ function RewardDistributorQuerySection() {
 {
   "admin": "inj1...",
   "operator": "inj1...",
+  "guardian": "inj1..." | null,
+  "pending_admin": null,
+  "pending_operator": null,
   "staking_hub": "inj1...",
   "drand_oracle": "inj1...",
   "reveal_deadline_seconds": 3600,
   "epochs_between_regular": 1,
-  "epochs_between_big": 7
+  "epochs_between_big": 7,
+  "challenge_period_seconds": 3600,
+  "timelock_seconds": 0,
+  "regular_prize_tiers": [{ "count": 1, "share_bps": 10000 }],
+  "big_prize_tiers": [{ "count": 1, "share_bps": 10000 }]
 }`}</MsgCard>
 
       <MsgCard
@@ -539,7 +988,10 @@ function RewardDistributorQuerySection() {
   "operator_commit": "sha256hex",
   "target_drand_round": 1000,
   "winner": "inj1..." | null,
+  "prize_tiers": [{ "count": 1, "share_bps": 5000 }, { "count": 5, "share_bps": 1000 }],
+  "winners": [{ "tier": 0, "address": "inj1...", "amount": "5000000", "winning_ticket": "412" }, ...],
   "reward_amount": "10000000",
+  "bonus_prizes": [{ "denom": "peggy0x...", "amount": "5000000" }],
   "created_at": "1234567890.000000000",
   "revealed_at": "1234567900.000000000" | null,
   "reveal_deadline": "1234571490.000000000"
@@ -554,15 +1006,43 @@ function RewardDistributorQuerySection() {
 
       <MsgCard
         title="Pool Balances"
-        description="Returns current pool balances."
+        description="Returns current INJ pool balances and escrowed bonus prizes not yet attached to a draw."
       >{`{ "pool_balances": {} }
 
 // Returns:
-{ "regular_pool": "40000000", "big_pool": "200000000" }`}</MsgCard>
+{
+  "regular_pool": "40000000",
+  "big_pool": "200000000",
+  "regular_bonus": [{ "denom": "peggy0x...", "amount": "5000000" }],
+  "big_bonus": []
+}`}</MsgCard>
+
+      <MsgCard
+        title="Bonus Prizes"
+        description="Returns escrowed bonus prizes for a pool, by target epoch."
+      >{`{ "bonus_prizes": { "draw_type": "big", "start_after": null, "limit": 20 } }
+
+// Returns: BonusPrizeEntry[]
+[{ "target_epoch": 14, "funds": [{ "denom": "peggy0x...", "amount": "5000000" }] }]`}</MsgCard>
+
+      <MsgCard
+        title="Bonus Denoms"
+        description="Returns the denoms accepted as bonus prizes, with the minimum amount of each."
+      >{`{ "bonus_denoms": {} }
+
+// Returns: Coin[]
+[{ "denom": "peggy0x...", "amount": "1000000" }]`}</MsgCard>
+
+      <MsgCard
+        title="Unclaimed Bonus Prizes"
+        description="Returns the bonus prizes an address won whose transfer failed, waiting for claim_bonus_prize."
+      >{`{ "unclaimed_bonus_prizes": { "address": "inj1..." } }
+
+// Returns: Coin[]`}</MsgCard>
 
       <MsgCard
         title="User Wins"
-        description="Returns a summary of a user's wins."
+        description="Returns a summary of a user's wins. total_wins counts draws won; total_won_amount sums every prize."
       >{`{ "user_wins": { "address": "inj1..." } }
 
 // Returns: UserWinsResponse
@@ -597,7 +1077,58 @@ function RewardDistributorQuerySection() {
   "merkle_root": "abcdef...",
   "total_weight": "1000000",
   "num_holders": 42,
-  "submitted_at": "1234567890.000000000"
+  "submitted_at": "1234567890.000000000",
+  "challenge_deadline": "1234571490.000000000",
+  "invalidated": false
+}`}</MsgCard>
+
+      <MsgCard
+        title="Snapshot Disputes"
+        description="Open missing_leaf disputes against a snapshot. Draws on the snapshot are blocked while any remain."
+      >{`{ "snapshot_disputes": { "epoch": 1, "start_after": null, "limit": 20 } }
+
+// Returns: SnapshotDispute[]
+[{
+  "epoch": 1,
+  "disputer": "inj1...",
+  "min_balance": "400",
+  "opened_at": "1234567890.000000000"
+}]`}</MsgCard>
+
+      <MsgCard
+        title="Expected Value"
+        description="Estimated prize per epoch for an address: its weight in the latest snapshot over the total, times each pool balance, divided by epochs_between_regular / epochs_between_big. The weight is the lowest balance held over the snapshot window, so the estimate is conservative. Zero for excluded addresses and for draw types the address isn't eligible for yet."
+      >{`{ "expected_value": { "address": "inj1..." } }
+
+// Returns: ExpectedValueResponse
+{
+  "address": "inj1...",
+  "snapshot_epoch": 5 | null,
+  "weight": "250",
+  "total_weight": "1000",
+  "regular_win_probability": "0.25",
+  "big_win_probability": "0.25",
+  "regular_expected_per_epoch": "17500000",
+  "big_expected_per_epoch": "750000",
+  "expected_per_epoch": "18250000"
+}`}</MsgCard>
+
+      <MsgCard
+        title="Pending Changes"
+        description="List scheduled parameter changes with their executable_at time."
+      >{`{ "pending_changes": { "start_after": null, "limit": 50 } }
+
+// Returns: PendingChange[]`}</MsgCard>
+
+      <MsgCard
+        title="Pause State"
+        description="Returns the guardian and the currently paused operations."
+      >{`{ "pause_state": {} }
+
+// Returns: PauseStateResponse
+{
+  "guardian": "inj1...",
+  "paused": ["commit_draw"]
 }`}</MsgCard>
     </div>
   )
@@ -642,9 +1173,37 @@ function DrandOracleExecuteSection() {
 } }`}</MsgCard>
 
       <MsgCard
-        title="Update Admin (admin)"
-        description="Transfer admin rights to a new address."
-      >{`{ "update_admin": { "new_admin": "inj1..." } }`}</MsgCard>
+        title="Propose Admin (admin)"
+        description="Propose a new admin. Control only moves once the proposed address sends accept_admin. Optional expiry in seconds."
+      >{`{ "propose_admin": {
+  "new_admin": "inj1...",
+  "expires_in_seconds": 86400
+} }`}</MsgCard>
+
+      <MsgCard
+        title="Accept Admin (proposed admin)"
+        description="Accept a pending admin proposal before it expires."
+      >{`{ "accept_admin": {} }`}</MsgCard>
+
+      <MsgCard
+        title="Cancel Proposal (admin)"
+        description="Withdraw a pending admin proposal."
+      >{`{ "cancel_proposal": {} }`}</MsgCard>
+
+      <MsgCard
+        title="Update Guardian (admin)"
+        description="Set or clear the guardian, who can pause operations during an incident."
+      >{`{ "update_guardian": { "guardian": "inj1..." } }`}</MsgCard>
+
+      <MsgCard
+        title="Pause (guardian or admin)"
+        description="Pause operations independently. Pausable: submit_beacon. Paused messages are rejected with OperationPaused."
+      >{`{ "pause": { "operations": ["submit_beacon"] } }`}</MsgCard>
+
+      <MsgCard
+        title="Unpause (admin)"
+        description="Resume paused operations."
+      >{`{ "unpause": { "operations": ["submit_beacon"] } }`}</MsgCard>
     </div>
   )
 }
@@ -662,6 +1221,8 @@ function DrandOracleQuerySection() {
 // Returns: OracleConfig
 {
   "admin": "inj1...",
+  "guardian": "inj1..." | null,
+  "pending_admin": null,
   "operators": ["inj1..."],
   "quicknet_pubkey": [/* bytes */],
   "chain_hash": "52db...",
@@ -690,6 +1251,17 @@ function DrandOracleQuerySection() {
       >{`{ "latest_round": {} }
 
 // Returns: u64`}</MsgCard>
+
+      <MsgCard
+        title="Pause State"
+        description="Returns the guardian and the currently paused operations."
+      >{`{ "pause_state": {} }
+
+// Returns: PauseStateResponse
+{
+  "guardian": "inj1...",
+  "paused": ["submit_beacon"]
+}`}</MsgCard>
     </div>
   )
 }
@@ -705,24 +1277,17 @@ function InteractionsSection() {
       <SubHeading>Epoch Flow</SubHeading>
       <div style={styles.flowContainer}>
         <div style={styles.flowStep}>
-          <div style={styles.flowLabel}>1. Claim Rewards</div>
-          <div style={styles.flowDesc}>
-            <InlineCode>staking-hub.claim_rewards()</InlineCode>
-            <br />Sends WithdrawDelegatorReward to all validators
-          </div>
-        </div>
-        <ArrowDown size={16} color="#2A2A38" style={{ margin: '8px auto', display: 'block' }} />
-        <div style={styles.flowStep}>
-          <div style={styles.flowLabel}>2. Distribute Rewards</div>
+          <div style={styles.flowLabel}>1. Advance Epoch</div>
           <div style={styles.flowDesc}>
-            <InlineCode>staking-hub.distribute_rewards()</InlineCode>
-            <br />Advances epoch, splits rewards by BPS config
+            <InlineCode>staking-hub.advance_epoch()</InlineCode>
+            <br />Sends WithdrawDelegatorReward to all validators as submessages
+            <br />In the reply: advances epoch, splits the withdrawn rewards by BPS config
             <br />Calls <InlineCode>reward-distributor.fund_regular_pool()</InlineCode> and <InlineCode>reward-distributor.fund_big_pool()</InlineCode>
           </div>
         </div>
         <ArrowDown size={16} color="#2A2A38" style={{ margin: '8px auto', display: 'block' }} />
         <div style={styles.flowStep}>
-          <div style={styles.flowLabel}>3. Take Snapshot</div>
+          <div style={styles.flowLabel}>2. Take Snapshot</div>
           <div style={styles.flowDesc}>
             <InlineCode>staking-hub.take_snapshot()</InlineCode>
             <br />Forwards merkle root to <InlineCode>reward-distributor.set_snapshot()</InlineCode>
@@ -730,7 +1295,7 @@ function InteractionsSection() {
         </div>
         <ArrowDown size={16} color="#2A2A38" style={{ margin: '8px auto', display: 'block' }} />
         <div style={styles.flowStep}>
-          <div style={styles.flowLabel}>4. Commit Draw</div>
+          <div style={styles.flowLabel}>3. Commit Draw</div>
           <div style={styles.flowDesc}>
             <InlineCode>reward-distributor.commit_draw()</InlineCode>
             <br />Operator commits hash + target drand round
@@ -738,7 +1303,7 @@ function InteractionsSection() {
         </div>
         <ArrowDown size={16} color="#2A2A38" style={{ margin: '8px auto', display: 'block' }} />
         <div style={styles.flowStep}>
-          <div style={styles.flowLabel}>5. Submit Beacon</div>
+          <div style={styles.flowLabel}>4. Submit Beacon</div>
           <div style={styles.flowDesc}>
             <InlineCode>drand-oracle.submit_beacon()</InlineCode>
             <br />Beacon verified and stored on-chain
@@ -746,7 +1311,7 @@ function InteractionsSection() {
         </div>
         <ArrowDown size={16} color="#2A2A38" style={{ margin: '8px auto', display: 'block' }} />
         <div style={styles.flowStep}>
-          <div style={styles.flowLabel}>6. Reveal Draw</div>
+          <div style={styles.flowLabel}>5. Reveal Draw</div>
           <div style={styles.flowDesc}>
             <InlineCode>reward-distributor.reveal_draw()</InlineCode>
             <br />Queries <InlineCode>drand-oracle.beacon()</InlineCode> for randomness
@@ -842,6 +1407,7 @@ function ValidationSection() {
           { title: 'BPS Sum', desc: 'regular_pool_bps + big_pool_bps + base_yield_bps + protocol_fee_bps must equal 10000. Enforced at instantiation and update_config.' },
           { title: 'Validator Addresses', desc: 'Must start with "injvaloper" and be reasonable length. Enforced at instantiation and update_validators.' },
           { title: 'Merkle Root', desc: 'Must be exactly 64 hex characters (32 bytes). Validated in take_snapshot.' },
+          { title: 'Snapshot Totals', desc: 'total_weight can exceed the csINJ supply minus excluded balances by at most snapshot_tolerance_bps, and num_holders must fit the weight. Validated in take_snapshot.' },
           { title: 'Reveal Deadline', desc: 'Must be between 300 seconds (5 min) and 86400 seconds (24 hours). Enforced at instantiation and update_config.' },
           { title: 'Epoch Duration', desc: 'distribute_rewards enforces that epoch_duration_seconds has elapsed since epoch start.' },
           { title: 'Snapshot Overwrite', desc: 'Cannot overwrite a snapshot for an epoch that already has one.' },
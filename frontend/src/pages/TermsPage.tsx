@@ -70,8 +70,9 @@ export default function TermsPage() {
         <Section title="7. Prize Draws">
           <P>
             Prize draws are funded by staking rewards, not by user principal. Eligibility for
-            draws requires holding csINJ for a minimum number of epochs. Re-staking resets
-            your eligibility timer. The probability of winning is proportional to your csINJ
+            draws requires holding csINJ for a minimum number of epochs. Adding to your
+            position moves your average holding age towards the present in proportion to the
+            amount added. The probability of winning is proportional to your csINJ
             holdings. Draw outcomes are determined by verifiable randomness from the drand
             network combined with an operator-committed secret.
           </P>
@@ -38,18 +38,99 @@ export interface EpochState {
     snapshot_total_weight: string;
     snapshot_num_holders: number;
     snapshot_uri: string | null;
+    snapshot_bounds: SnapshotBounds | null;
+}
+
+export interface EpochRecord {
+    epoch: number;
+    start_time: string;
+    end_time: string;
+    total_rewards: string;
+    sponsor_rewards: string;
+    regular_pool_amount: string;
+    big_pool_amount: string;
+    base_yield: string;
+    yield_only_amount: string;
+    yield_only_minted: string;
+    treasury_fee: string;
+    keeper_fee: string;
+    unexplained_surplus: string;
+    total_inj_backing: string;
+    total_csinj_supply: string;
+    exchange_rate: string;
+    snapshot_merkle_root: string | null;
+    snapshot_total_weight: string;
+    snapshot_num_holders: number;
+    snapshot_uri: string | null;
+}
+
+export interface YieldStatsResponse {
+    num_epochs: number;
+    from_epoch: number | null;
+    to_epoch: number | null;
+    start_rate: string;
+    end_rate: string;
+    elapsed_seconds: number;
+    base_apr: string;
+    base_apy: string;
+}
+
+export interface SnapshotBounds {
+    csinj_supply: string;
+    excluded_addresses: string[];
+    excluded_balance: string;
+    yield_only_csinj: string;
+    sponsor_csinj: string;
+    max_weight: string;
+}
+
+export interface YieldOnlyPositionResponse {
+    address: string;
+    shares: string;
+    csinj_amount: string;
+    inj_value: string;
+}
+
+export interface YieldOnlyTotals {
+    shares: string;
+    csinj: string;
+}
+
+export type DonationTarget = 'regular_pool' | 'big_pool' | 'base_yield';
+
+export interface DonationTotals {
+    regular_pool: string;
+    big_pool: string;
+    base_yield: string;
+}
+
+export interface SponsorInfo {
+    address: string;
+    shares: string;
+    inj_amount: string;
+}
+
+export interface SponsorsResponse {
+    sponsors: SponsorInfo[];
+    total_shares: string;
+    total_sponsored: string;
 }
 
 export interface UnstakeRequest {
     inj_amount: string;
     csinj_burned: string;
-    unlock_time: string;
+    batch_id: number;
+    batch_shares: string;
     claimed: boolean;
 }
 
 export interface UnstakeRequestEntry {
     id: number;
     request: UnstakeRequest;
+    /** INJ claimable after the batch's share of any slashing */
+    adjusted_inj_amount: string;
+    /** Null while the request's unbonding batch has not been submitted yet */
+    unlock_time: string | null;
 }
 
 export interface DrawStateInfo {
@@ -62,6 +143,24 @@ export interface DrawStateInfo {
     last_big_draw_epoch: number | null;
 }
 
+export interface Coin {
+    denom: string;
+    amount: string;
+}
+
+export interface PrizeTier {
+    count: number;
+    share_bps: number;
+}
+
+export interface PrizeWinner {
+    /** Index of the tier in the draw's prize_tiers */
+    tier: number;
+    address: string;
+    amount: string;
+    winning_ticket: string;
+}
+
 export interface Draw {
     id: number;
     draw_type: "regular" | "big";
@@ -72,22 +171,40 @@ export interface Draw {
     drand_randomness: number[] | null;
     operator_secret: number[] | null;
     final_randomness: number[] | null;
+    /** Winner of the first prize */
     winner: string | null;
+    /** Total INJ reward, split across prize_tiers */
     reward_amount: string;
     created_at: string;
     revealed_at: string | null;
     reveal_deadline: string;
     merkle_root: string | null;
     total_weight: string | null;
+    /** Non-INJ prizes paid to the first prize winner alongside reward_amount */
+    bonus_prizes: Coin[];
+    /** Prize table, fixed at commit time */
+    prize_tiers: PrizeTier[];
+    /** Every prize of a revealed draw, tier by tier */
+    winners: PrizeWinner[];
+}
+
+export interface PendingTransfer {
+    new_address: string;
+    /** Null when the proposal does not expire */
+    expires_at: string | null;
 }
 
 export interface StakingHubConfig {
     admin: string;
     operator: string;
+    guardian: string | null;
+    pending_admin: PendingTransfer | null;
+    pending_operator: PendingTransfer | null;
     reward_distributor: string;
     drand_oracle: string;
     csinj_denom: string;
     validators: string[];
+    validator_weights: number[];
     epoch_duration_seconds: number;
     protocol_fee_bps: number;
     treasury: string;
@@ -96,11 +213,25 @@ export interface StakingHubConfig {
     big_pool_bps: number;
     min_epochs_regular: number;
     min_epochs_big: number;
+    timelock_seconds: number;
+    snapshot_tolerance_bps: number;
+    keeper_fee_bps: number;
+    snapshot_grace_seconds: number;
 }
 
 export interface StakerInfoResponse {
     address: string;
     stake_epoch: number | null;
+    excluded: boolean;
+}
+
+export interface TwabResponse {
+    address: string;
+    start: number;
+    end: number;
+    average_balance: string;
+    total_average_balance: string;
+    min_balance: string;
 }
 
 export interface UserWinsResponse {
@@ -113,10 +244,32 @@ export interface UserWinsResponse {
 export interface PoolBalancesResponse {
     regular_pool: string;
     big_pool: string;
+    /** Escrowed bonus prizes not yet attached to a draw */
+    regular_bonus: Coin[];
+    big_bonus: Coin[];
+}
+
+export interface BonusPrizeEntry {
+    target_epoch: number;
+    funds: Coin[];
+}
+
+export interface ExpectedValueResponse {
+    address: string;
+    snapshot_epoch: number | null;
+    weight: string;
+    total_weight: string;
+    regular_win_probability: string;
+    big_win_probability: string;
+    regular_expected_per_epoch: string;
+    big_expected_per_epoch: string;
+    expected_per_epoch: string;
 }
 
 export interface OracleConfig {
     admin: string;
+    guardian: string | null;
+    pending_admin: PendingTransfer | null;
     operators: string[];
     quicknet_pubkey: number[];
     chain_hash: string;
@@ -127,11 +280,26 @@ export interface OracleConfig {
 export interface DistributorConfig {
     admin: string;
     operator: string;
+    guardian: string | null;
+    pending_admin: PendingTransfer | null;
+    pending_operator: PendingTransfer | null;
     staking_hub: string;
     drand_oracle: string;
     reveal_deadline_seconds: number;
     epochs_between_regular: number;
     epochs_between_big: number;
+    challenge_period_seconds: number;
+    timelock_seconds: number;
+    regular_prize_tiers: PrizeTier[];
+    big_prize_tiers: PrizeTier[];
+}
+
+export interface PendingChange {
+    id: number;
+    /** Tagged ParamChange, e.g. { update_config: { ... } } */
+    change: Record<string, unknown>;
+    scheduled_at: string;
+    executable_at: string;
 }
 
 export interface StoredBeacon {
@@ -149,6 +317,15 @@ export interface SnapshotInfo {
     total_weight: string;
     num_holders: number;
     submitted_at: string;
+    challenge_deadline: string;
+    invalidated: boolean;
+}
+
+export interface SnapshotDispute {
+    epoch: number;
+    disputer: string;
+    min_balance: string;
+    opened_at: string;
 }
 
 // ---------- Generic query helper ----------
@@ -196,6 +373,56 @@ export async function fetchEpochState(): Promise<EpochState> {
     });
 }
 
+export async function fetchEpochRecord(epoch: number): Promise<EpochRecord> {
+    return queryContract<EpochRecord>(CONTRACTS.stakingHub, {
+        epoch_record: { epoch },
+    });
+}
+
+export async function fetchEpochHistory(
+    startAfter?: number,
+    limit?: number,
+): Promise<EpochRecord[]> {
+    return queryContract<EpochRecord[]>(CONTRACTS.stakingHub, {
+        epoch_history: { start_after: startAfter, limit },
+    });
+}
+
+export async function fetchYieldOnlyPosition(
+    address: string,
+): Promise<YieldOnlyPositionResponse> {
+    return queryContract<YieldOnlyPositionResponse>(CONTRACTS.stakingHub, {
+        yield_only_position: { address },
+    });
+}
+
+export async function fetchYieldOnlyTotals(): Promise<YieldOnlyTotals> {
+    return queryContract<YieldOnlyTotals>(CONTRACTS.stakingHub, {
+        yield_only_totals: {},
+    });
+}
+
+export async function fetchDonationTotals(): Promise<DonationTotals> {
+    return queryContract<DonationTotals>(CONTRACTS.stakingHub, {
+        donation_totals: {},
+    });
+}
+
+export async function fetchSponsors(
+    startAfter?: string,
+    limit?: number,
+): Promise<SponsorsResponse> {
+    return queryContract<SponsorsResponse>(CONTRACTS.stakingHub, {
+        sponsors: { start_after: startAfter, limit },
+    });
+}
+
+export async function fetchYieldStats(lookbackEpochs: number): Promise<YieldStatsResponse> {
+    return queryContract<YieldStatsResponse>(CONTRACTS.stakingHub, {
+        yield_stats: { lookback_epochs: lookbackEpochs },
+    });
+}
+
 export async function fetchUnstakeRequests(
     address: string,
     startAfter?: number,
@@ -218,6 +445,25 @@ export async function fetchStakerInfo(
     });
 }
 
+export async function fetchSnapshotExclusions(
+    startAfter?: string,
+    limit?: number,
+): Promise<string[]> {
+    return queryContract<string[]>(CONTRACTS.stakingHub, {
+        snapshot_exclusions: { start_after: startAfter, limit },
+    });
+}
+
+export async function fetchTwabBetween(
+    address: string,
+    start: number,
+    end: number,
+): Promise<TwabResponse> {
+    return queryContract<TwabResponse>(CONTRACTS.stakingHub, {
+        twab_between: { address, start, end },
+    });
+}
+
 // ---------- Reward Distributor Queries ----------
 export async function fetchDrawState(): Promise<DrawStateInfo> {
     return queryContract<DrawStateInfo>(CONTRACTS.rewardDistributor, {
@@ -231,6 +477,40 @@ export async function fetchPoolBalances(): Promise<PoolBalancesResponse> {
     });
 }
 
+export async function fetchBonusPrizes(
+    drawType: "regular" | "big",
+    startAfter?: number,
+    limit?: number,
+): Promise<BonusPrizeEntry[]> {
+    return queryContract<BonusPrizeEntry[]>(CONTRACTS.rewardDistributor, {
+        bonus_prizes: {
+            draw_type: drawType,
+            start_after: startAfter ?? null,
+            limit: limit ?? null,
+        },
+    });
+}
+
+/** Denoms accepted as bonus prizes; `amount` is the least one add_bonus_prize must send */
+export async function fetchBonusDenoms(): Promise<Coin[]> {
+    return queryContract<Coin[]>(CONTRACTS.rewardDistributor, {
+        bonus_denoms: {},
+    });
+}
+
+/** Bonus prizes won by `address` whose payout failed, claimable with claim_bonus_prize */
+export async function fetchUnclaimedBonusPrizes(address: string): Promise<Coin[]> {
+    return queryContract<Coin[]>(CONTRACTS.rewardDistributor, {
+        unclaimed_bonus_prizes: { address },
+    });
+}
+
+export async function fetchExpectedValue(address: string): Promise<ExpectedValueResponse> {
+    return queryContract<ExpectedValueResponse>(CONTRACTS.rewardDistributor, {
+        expected_value: { address },
+    });
+}
+
 export async function fetchDrawHistory(
     startAfter?: number,
     limit?: number,
@@ -278,6 +558,18 @@ export async function fetchDraw(drawId: number): Promise<Draw> {
     });
 }
 
+/** Total INJ `address` won in a revealed draw, across all of its prizes */
+export function prizeWonBy(draw: Draw, address: string): bigint {
+    return draw.winners
+        .filter((w) => w.address === address)
+        .reduce((sum, w) => sum + BigInt(w.amount), BigInt(0));
+}
+
+/** INJ paid to the first prize winner of a revealed draw */
+export function firstPrizeAmount(draw: Draw): string {
+    return draw.winners[0]?.amount ?? draw.reward_amount;
+}
+
 // ---------- Staking Hub Config ----------
 export async function fetchStakingHubConfig(): Promise<StakingHubConfig> {
     return queryContract<StakingHubConfig>(CONTRACTS.stakingHub, {
@@ -401,6 +693,16 @@ export async function fetchSnapshot(
     });
 }
 
+export async function fetchSnapshotDisputes(
+    epoch: number,
+    startAfter?: string,
+    limit?: number,
+): Promise<SnapshotDispute[]> {
+    return queryContract<SnapshotDispute[]>(CONTRACTS.rewardDistributor, {
+        snapshot_disputes: { epoch, start_after: startAfter ?? null, limit: limit ?? null },
+    });
+}
+
 // ---------- Execute message builders ----------
 export function buildStakeMsg(sender: string, amount: string) {
     return MsgExecuteContractCompat.fromJSON({
@@ -420,6 +722,71 @@ export function buildUnstakeMsg(sender: string, amount: string) {
     });
 }
 
+export function buildEnterYieldOnlyMsg(sender: string, amount: string) {
+    return MsgExecuteContractCompat.fromJSON({
+        sender,
+        contractAddress: CONTRACTS.stakingHub,
+        msg: { enter_yield_only: {} },
+        funds: { denom: getCsinjDenom(), amount },
+    });
+}
+
+export function buildExitYieldOnlyMsg(sender: string, shares?: string) {
+    return MsgExecuteContractCompat.fromJSON({
+        sender,
+        contractAddress: CONTRACTS.stakingHub,
+        msg: { exit_yield_only: { shares: shares ?? null } },
+    });
+}
+
+export function buildSponsorMsg(sender: string, amount: string) {
+    return MsgExecuteContractCompat.fromJSON({
+        sender,
+        contractAddress: CONTRACTS.stakingHub,
+        msg: { sponsor: {} },
+        funds: { denom: INJ_DENOM, amount },
+    });
+}
+
+export function buildDonateMsg(sender: string, target: DonationTarget, amount: string) {
+    return MsgExecuteContractCompat.fromJSON({
+        sender,
+        contractAddress: CONTRACTS.stakingHub,
+        msg: { donate: { target } },
+        funds: { denom: INJ_DENOM, amount },
+    });
+}
+
+export function buildWithdrawSponsorshipMsg(sender: string) {
+    return MsgExecuteContractCompat.fromJSON({
+        sender,
+        contractAddress: CONTRACTS.stakingHub,
+        msg: { withdraw_sponsorship: {} },
+    });
+}
+
+export function buildAddBonusPrizeMsg(
+    sender: string,
+    drawType: "regular" | "big",
+    funds: Coin[],
+    targetEpoch?: number,
+) {
+    return MsgExecuteContractCompat.fromJSON({
+        sender,
+        contractAddress: CONTRACTS.rewardDistributor,
+        msg: { add_bonus_prize: { draw_type: drawType, target_epoch: targetEpoch ?? null } },
+        funds,
+    });
+}
+
+export function buildClaimBonusPrizeMsg(sender: string, denom: string) {
+    return MsgExecuteContractCompat.fromJSON({
+        sender,
+        contractAddress: CONTRACTS.rewardDistributor,
+        msg: { claim_bonus_prize: { denom } },
+    });
+}
+
 export function buildClaimUnstakedMsg(sender: string, requestIds: number[]) {
     return MsgExecuteContractCompat.fromJSON({
         sender,
@@ -416,25 +416,28 @@ export const useStore = create<AppState>()(
                                         draw.draw_type === "big"
                                             ? "Big Jackpot"
                                             : "Regular Draw";
-                                    const rewardFormatted = formatInjString(
-                                        draw.reward_amount,
-                                    );
-                                    // Check if the connected user won
-                                    const userWon =
-                                        connectedAddr &&
-                                        draw.winner === connectedAddr;
-                                    if (userWon) {
+                                    // Check if the connected user won any prize
+                                    const userPrize = connectedAddr
+                                        ? contracts.prizeWonBy(draw, connectedAddr)
+                                        : BigInt(0);
+                                    if (userPrize > BigInt(0)) {
                                         set({ showConfetti: true });
                                         get().addToast({
                                             type: "success",
                                             title: `You won ${typeLabel} #${draw.id}!`,
-                                            message: `Congratulations! You won ${rewardFormatted} INJ!`,
+                                            message: `Congratulations! You won ${formatInjString(userPrize.toString())} INJ!`,
                                         });
                                     } else {
+                                        const firstPrize = formatInjString(
+                                            contracts.firstPrizeAmount(draw),
+                                        );
+                                        const others = draw.winners.length - 1;
                                         get().addToast({
                                             type: "success",
                                             title: `${typeLabel} #${draw.id} Revealed!`,
-                                            message: `Winner: ${draw.winner?.slice(0, 10)}... won ${rewardFormatted} INJ`,
+                                            message:
+                                                `Winner: ${draw.winner?.slice(0, 10)}... won ${firstPrize} INJ` +
+                                                (others > 0 ? ` (+${others} more prizes)` : ""),
                                         });
                                     }
                                 }
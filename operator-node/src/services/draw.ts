@@ -8,6 +8,10 @@ import { generateProof, findWinnerIndex, SnapshotEntry } from "./merkle";
 import { computeLeafHash } from "../utils/crypto";
 import { getCachedSnapshot, getEpochState, SnapshotCache } from "./epoch";
 import { fetchLatestDrandRound, submitSpecificRound, getStoredBeacon } from "./drand";
+import { getStakingHubConfig, queryStakerInfo } from "./snapshot";
+
+// Must match the reward distributor's MAX_PRIZE_REDRAWS
+const MAX_PRIZE_REDRAWS = 10;
 
 interface DrawStateInfo {
   next_draw_id: number;
@@ -29,6 +33,18 @@ interface DistributorConfig {
   epochs_between_big: number;
 }
 
+interface PrizeTier {
+  count: number;
+  share_bps: number;
+}
+
+interface PrizeWinner {
+  tier: number;
+  address: string;
+  amount: string;
+  winning_ticket: string;
+}
+
 interface Draw {
   id: number;
   draw_type: string;
@@ -46,6 +62,8 @@ interface Draw {
   reveal_deadline: string;
   merkle_root: string | null;
   total_weight: string | null;
+  prize_tiers: PrizeTier[];
+  winners: PrizeWinner[];
 }
 
 interface DrawHistoryResponse {
@@ -202,40 +220,80 @@ export async function revealDraw(drawId: number): Promise<string> {
   }
 
   const totalWeight = BigInt(snapshot.totalWeight);
-  const winningTicket = computeWinningTicket(drandRandomness, secret, totalWeight);
+  const numPrizes = draw.prize_tiers.reduce((sum, tier) => sum + tier.count, 0);
 
-  logger.info(`Draw ${drawId}: winning ticket = ${winningTicket} / ${totalWeight}`);
-
-  const winnerIndex = findWinnerIndex(snapshot.entries, winningTicket);
-  const winner = snapshot.entries[winnerIndex];
-
-  logger.info(`Draw ${drawId}: winner = ${winner.address} (index ${winnerIndex})`);
-
-  // Generate merkle proof
   const leaves = snapshot.entries.map((e: SnapshotEntry) =>
     computeLeafHash(e.address, BigInt(e.cumulative_start), BigInt(e.cumulative_end))
   );
 
-  const proof = generateProof(leaves, winnerIndex);
+  const hubConfig = await getStakingHubConfig();
+  const minEpochs =
+    draw.draw_type === "big" ? hubConfig.min_epochs_big : hubConfig.min_epochs_regular;
+
+  // One winner per prize, in prize table order, each with its own ticket and proof.
+  // A ticket landing on a holder that is no longer eligible is redrawn, and the
+  // contract expects that holder's leaf followed by the redrawn ticket's leaf.
+  const winners = [];
+  for (let slot = 0; slot < numPrizes; slot++) {
+    for (let redraw = 0; redraw <= MAX_PRIZE_REDRAWS; redraw++) {
+      const winningTicket = computeWinningTicket(
+        drandRandomness,
+        secret,
+        totalWeight,
+        slot,
+        redraw
+      );
+      const winnerIndex = findWinnerIndex(snapshot.entries, winningTicket);
+      const winner = snapshot.entries[winnerIndex];
+      const eligible = await isEligibleWinner(winner.address, draw.epoch, minEpochs);
+
+      logger.info(
+        `Draw ${drawId} prize ${slot} (redraw ${redraw}): ticket ${winningTicket} / ${totalWeight}, ` +
+          `winner = ${winner.address} (index ${winnerIndex})${eligible ? "" : ", ineligible"}`
+      );
+
+      winners.push({
+        address: winner.address,
+        cumulative_start: winner.cumulative_start,
+        cumulative_end: winner.cumulative_end,
+        merkle_proof: generateProof(leaves, winnerIndex),
+      });
+      if (eligible) {
+        break;
+      }
+    }
+  }
 
   const txHash = await executeContract(config.contracts.rewardDistributor, {
     reveal_draw: {
       draw_id: drawId,
       operator_secret_hex: secret.toString("hex"),
-      winner_address: winner.address,
-      winner_cumulative_start: winner.cumulative_start,
-      winner_cumulative_end: winner.cumulative_end,
-      merkle_proof: proof,
+      winners,
     },
   });
 
   pendingDrawSecrets.delete(drawId);
   saveSecretsToDisk();
-  logger.info(`Draw ${drawId} revealed! Winner: ${winner.address}, tx: ${txHash}`);
+  logger.info(`Draw ${drawId} revealed! ${numPrizes} prize(s), tx: ${txHash}`);
 
   return txHash;
 }
 
+/** Same check as the contract's assert_winner_eligible. */
+async function isEligibleWinner(
+  address: string,
+  drawEpoch: number,
+  minEpochs: number
+): Promise<boolean> {
+  const info = await queryStakerInfo(address);
+  if (info.excluded) {
+    return false;
+  }
+  const epochsStaked =
+    info.stake_epoch !== null && drawEpoch >= info.stake_epoch ? drawEpoch - info.stake_epoch : 0;
+  return epochsStaked >= minEpochs;
+}
+
 export async function expireDraw(drawId: number): Promise<string> {
   logger.info(`Expiring draw ${drawId} (past reveal deadline)`);
   const txHash = await executeContract(config.contracts.rewardDistributor, {
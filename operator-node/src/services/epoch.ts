@@ -27,14 +27,9 @@ export async function getEpochState(): Promise<EpochState> {
   return queryContract<EpochState>(config.contracts.stakingHub, { epoch_state: {} });
 }
 
-export async function claimRewards(): Promise<string> {
-  logger.info("Claiming staking rewards from validators...");
-  return executeContract(config.contracts.stakingHub, { claim_rewards: {} });
-}
-
-export async function distributeRewards(): Promise<string> {
-  logger.info("Distributing rewards and advancing epoch...");
-  return executeContract(config.contracts.stakingHub, { distribute_rewards: {} });
+export async function advanceEpoch(): Promise<string> {
+  logger.info("Claiming and distributing rewards, advancing epoch...");
+  return executeContract(config.contracts.stakingHub, { advance_epoch: {} });
 }
 
 export async function syncDelegations(): Promise<void> {
@@ -224,32 +219,30 @@ export async function checkAndAdvanceEpoch(): Promise<boolean> {
   const nowSeconds = Date.now() / 1000;
   const elapsed = nowSeconds - epochStartSeconds;
 
+  let advanced = false;
   if (elapsed < hubConfig.epoch_duration_seconds) {
     logger.debug(
       `Epoch ${epochState.current_epoch} has ${hubConfig.epoch_duration_seconds - elapsed}s remaining`
     );
-    return false;
-  }
-
-  logger.info(`Epoch ${epochState.current_epoch} is ready to advance`);
-
-  // Step 1: Sync delegations to catch any slashing before distributing
-  await syncDelegations();
+  } else {
+    logger.info(`Epoch ${epochState.current_epoch} is ready to advance`);
 
-  // Step 2: Claim staking rewards from all validators
-  // This sends WithdrawDelegatorReward msgs to claim rewards into the contract
-  await claimRewards();
+    // Step 1: Sync delegations to catch any slashing before distributing
+    await syncDelegations();
 
-  // Step 3: Distribute the claimed rewards and advance epoch
-  // Wait a moment for the claim tx to be processed
-  await new Promise((resolve) => setTimeout(resolve, 3000));
-  await distributeRewards();
+    // Step 2: Claim and distribute staking rewards and advance the epoch in one tx.
+    // Only the INJ the reward withdrawals bring in is distributed.
+    await advanceEpoch();
+    advanced = true;
+  }
 
-  // Step 4: Take snapshot if not yet taken
-  const updatedEpoch = await getEpochState();
-  if (!updatedEpoch.snapshot_finalized) {
+  // Step 3: Take the snapshot whenever the current epoch has none yet. A keeper may
+  // have advanced the epoch, so this doesn't depend on having advanced it here.
+  const currentEpoch = advanced ? await getEpochState() : epochState;
+  if (!currentEpoch.snapshot_finalized) {
+    logger.info(`Epoch ${currentEpoch.current_epoch} has no snapshot yet`);
     await takeSnapshot();
   }
 
-  return true;
+  return advanced;
 }
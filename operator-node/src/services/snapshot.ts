@@ -23,6 +23,7 @@ interface StakingHubConfig {
 interface StakerInfoResponse {
     address: string;
     stake_epoch: number | null;
+    excluded: boolean;
 }
 
 interface DenomHolder {
@@ -63,6 +64,10 @@ export async function filterEligibleHolders(
 
     for (const holder of holders) {
         const info = await queryStakerInfo(holder.address);
+        if (info.excluded) {
+            logger.info(`Excluding ${holder.address} from snapshot: on the hub's exclusion list`);
+            continue;
+        }
         if (info.stake_epoch === null) {
             // No stake epoch recorded — skip (shouldn't happen for a holder)
             logger.warn(`Holder ${holder.address} has no stake_epoch, excluding from snapshot`);
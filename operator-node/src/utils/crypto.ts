@@ -21,17 +21,37 @@ export function xorBuffers(a: Buffer, b: Buffer): Buffer {
   return result;
 }
 
+/**
+ * Winning ticket of prize `slot` of a draw, counting across all prize tiers.
+ * Slot 0 uses the final randomness directly; later slots hash it with the slot
+ * index (u32 big-endian), and redraws also hash in the redraw counter (u32
+ * big-endian), matching the contract's prize_ticket.
+ */
 export function computeWinningTicket(
   drandRandomness: Buffer,
   operatorSecret: Buffer,
-  totalWeight: bigint
+  totalWeight: bigint,
+  slot = 0,
+  redraw = 0
 ): bigint {
   const secretHash = sha256(operatorSecret);
   const finalRandomness = xorBuffers(drandRandomness, secretHash);
+  let source = finalRandomness;
+  if (slot > 0 || redraw > 0) {
+    const slotBytes = Buffer.alloc(4);
+    slotBytes.writeUInt32BE(slot);
+    const parts = [finalRandomness, slotBytes];
+    if (redraw > 0) {
+      const redrawBytes = Buffer.alloc(4);
+      redrawBytes.writeUInt32BE(redraw);
+      parts.push(redrawBytes);
+    }
+    source = sha256(Buffer.concat(parts));
+  }
   // u128 from first 16 bytes big-endian
   let value = BigInt(0);
   for (let i = 0; i < 16; i++) {
-    value = (value << BigInt(8)) | BigInt(finalRandomness[i]);
+    value = (value << BigInt(8)) | BigInt(source[i]);
   }
   return value % totalWeight;
 }